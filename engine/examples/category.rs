@@ -1,173 +1,215 @@
 use anyhow::Result;
+use versedb::collection::Collection;
 use versedb::database::Database;
+use versedb::tuple::{Element, Tuple};
 use versedb::yaml::YamlDatabase;
 
+/// Key for a category path such as `["products", "electronics", "laptop"]`.
+/// Tuple keys keep each level apart, so `user:1` never matches `user:10`.
+fn path(levels: &[&str]) -> Tuple {
+    levels
+        .iter()
+        .fold(Tuple::new(), |key, level| key.push(*level))
+}
+
+/// The category path of `key`, written with `:` between levels
+fn display(key: &Tuple) -> String {
+    let levels: Vec<_> = key
+        .elements()
+        .iter()
+        .map(|element| match element {
+            Element::String(level) => level.clone(),
+            other => format!("{:?}", other),
+        })
+        .collect();
+    levels.join(":")
+}
+
 #[cfg(not(target_arch = "wasm32"))]
 #[tokio::main]
 async fn main() -> Result<()> {
     // Create a new YAML database for variable-length category examples
-    let mut db = YamlDatabase::open("variable_categories.yaml").await?;
+    let db = YamlDatabase::open("variable_categories.yaml").await?;
+    let mut db: Collection<_, Tuple, String> = Collection::new(db, "categories")?;
     println!("Variable-length category database opened successfully!");
 
     // EXAMPLE 1: Different category depths - demonstrating variable length keys
     println!("\n=== VARIABLE LENGTH CATEGORY EXAMPLES ===");
 
     // One-level categories
-    db.add(b"products:apple", b"Red fruit").await?;
+    db.insert(&path(&["products", "apple"]), &"Red fruit".to_string())
+        .await?;
 
     // Two-level categories
-    db.add(b"products:electronics:laptop", b"Portable computer")
-        .await?;
+    db.insert(
+        &path(&["products", "electronics", "laptop"]),
+        &"Portable computer".to_string(),
+    )
+    .await?;
 
     // Three-level categories
-    db.add(
-        b"products:electronics:phones:smartphone",
-        b"Mobile device with apps",
+    db.insert(
+        &path(&["products", "electronics", "phones", "smartphone"]),
+        &"Mobile device with apps".to_string(),
     )
     .await?;
 
     // Four-level categories
-    db.add(
-        b"products:electronics:phones:accessories:case",
-        b"Phone protection",
+    db.insert(
+        &path(&["products", "electronics", "phones", "accessories", "case"]),
+        &"Phone protection".to_string(),
     )
     .await?;
 
     // Query different depths
     println!("Products (all levels):");
-    let all_products = db.select_range(b"products:", b"products:\xff").await?;
+    let all_products = db.scan_prefix(&path(&["products"])).await?;
     for (key, value) in all_products {
-        let key_str = String::from_utf8_lossy(&key);
-        let depth = key_str.matches(':').count();
-        println!(
-            "{} (depth: {}) -> {}",
-            key_str,
-            depth,
-            String::from_utf8_lossy(&value)
-        );
+        let depth = key.elements().len() - 1;
+        println!("{} (depth: {}) -> {}", display(&key), depth, value);
     }
 
     // EXAMPLE 2: Keys that might seem ambiguous but aren't due to exact matching
     println!("\n=== COLLISION AVOIDANCE EXAMPLES ===");
 
     // Similar looking keys that don't collide
-    db.add(b"user:1", b"User with ID 1").await?;
-    db.add(b"user:10", b"User with ID 10").await?;
-    db.add(b"user:100", b"User with ID 100").await?;
-
-    // Keys with shared prefixes
-    db.add(b"item:chair", b"Furniture to sit on").await?;
-    db.add(b"item:chair:office", b"Chair for office use")
+    db.insert(&path(&["user", "1"]), &"User with ID 1".to_string())
         .await?;
-    db.add(b"item:chair:dining", b"Chair for dining table")
+    db.insert(&path(&["user", "10"]), &"User with ID 10".to_string())
+        .await?;
+    db.insert(&path(&["user", "100"]), &"User with ID 100".to_string())
         .await?;
 
+    // Keys with shared prefixes
+    db.insert(
+        &path(&["item", "chair"]),
+        &"Furniture to sit on".to_string(),
+    )
+    .await?;
+    db.insert(
+        &path(&["item", "chair", "office"]),
+        &"Chair for office use".to_string(),
+    )
+    .await?;
+    db.insert(
+        &path(&["item", "chair", "dining"]),
+        &"Chair for dining table".to_string(),
+    )
+    .await?;
+
     // Edge case - empty value part
-    db.add(b"tag:important:", b"Items marked important with no subtype")
-        .await?;
-    db.add(b"tag:important:high", b"High importance items")
-        .await?;
+    db.insert(
+        &path(&["tag", "important", ""]),
+        &"Items marked important with no subtype".to_string(),
+    )
+    .await?;
+    db.insert(
+        &path(&["tag", "important", "high"]),
+        &"High importance items".to_string(),
+    )
+    .await?;
 
     // Verify exact matches work correctly
     println!("Exact matches for similar keys:");
     println!(
         "user:1 -> {}",
-        String::from_utf8_lossy(&db.select(b"user:1").await?.unwrap_or_default())
+        db.get(&path(&["user", "1"])).await?.unwrap_or_default()
     );
     println!(
         "user:10 -> {}",
-        String::from_utf8_lossy(&db.select(b"user:10").await?.unwrap_or_default())
+        db.get(&path(&["user", "10"])).await?.unwrap_or_default()
     );
     println!(
         "user:100 -> {}",
-        String::from_utf8_lossy(&db.select(b"user:100").await?.unwrap_or_default())
+        db.get(&path(&["user", "100"])).await?.unwrap_or_default()
     );
 
     // EXAMPLE 3: Hierarchical data with queries at different levels
     println!("\n=== HIERARCHICAL DATA QUERIES ===");
 
     // Location data with continent > country > city > district
-    db.add(
-        b"location:europe:france:paris:montmartre",
-        b"Artistic district in Paris",
+    db.insert(
+        &path(&["location", "europe", "france", "paris", "montmartre"]),
+        &"Artistic district in Paris".to_string(),
     )
     .await?;
-    db.add(
-        b"location:europe:france:paris:louvre",
-        b"Famous museum district",
+    db.insert(
+        &path(&["location", "europe", "france", "paris", "louvre"]),
+        &"Famous museum district".to_string(),
     )
     .await?;
-    db.add(
-        b"location:europe:france:nice",
-        b"City on the French Riviera",
+    db.insert(
+        &path(&["location", "europe", "france", "nice"]),
+        &"City on the French Riviera".to_string(),
+    )
+    .await?;
+    db.insert(
+        &path(&["location", "europe", "germany", "berlin"]),
+        &"Capital of Germany".to_string(),
+    )
+    .await?;
+    db.insert(
+        &path(&["location", "asia", "japan", "tokyo"]),
+        &"Capital of Japan".to_string(),
     )
     .await?;
-    db.add(b"location:europe:germany:berlin", b"Capital of Germany")
-        .await?;
-    db.add(b"location:asia:japan:tokyo", b"Capital of Japan")
-        .await?;
 
     // Query by continent
     println!("European locations:");
-    let europe_locs = db
-        .select_range(b"location:europe:", b"location:europe:\xff")
-        .await?;
+    let europe_locs = db.scan_prefix(&path(&["location", "europe"])).await?;
     for (key, value) in europe_locs {
-        println!(
-            "  {}: {}",
-            String::from_utf8_lossy(&key),
-            String::from_utf8_lossy(&value)
-        );
+        println!("  {}: {}", display(&key), value);
     }
 
     // Query by country
     println!("\nLocations in France:");
     let france_locs = db
-        .select_range(b"location:europe:france:", b"location:europe:france:\xff")
+        .scan_prefix(&path(&["location", "europe", "france"]))
         .await?;
     for (key, value) in france_locs {
-        println!(
-            "  {}: {}",
-            String::from_utf8_lossy(&key),
-            String::from_utf8_lossy(&value)
-        );
+        println!("  {}: {}", display(&key), value);
     }
 
     // Query by city
     println!("\nLocations in Paris:");
     let paris_locs = db
-        .select_range(
-            b"location:europe:france:paris:",
-            b"location:europe:france:paris:\xff",
-        )
+        .scan_prefix(&path(&["location", "europe", "france", "paris"]))
         .await?;
     for (key, value) in paris_locs {
-        println!(
-            "  {}: {}",
-            String::from_utf8_lossy(&key),
-            String::from_utf8_lossy(&value)
-        );
+        println!("  {}: {}", display(&key), value);
     }
 
     // EXAMPLE 4: Mixed data types with the same prefix
     println!("\n=== MIXED DATA TYPES WITH SAME PREFIX ===");
 
     // Different types of "user" data
-    db.add(b"user:profile:alex", b"Alex Smith, Designer")
-        .await?;
-    db.add(b"user:settings:alex", b"dark_mode=true").await?;
-    db.add(b"user:activity:alex:login", b"Last login: yesterday")
-        .await?;
-    db.add(b"user:activity:alex:purchase", b"Last purchase: 3 days ago")
-        .await?;
+    db.insert(
+        &path(&["user", "profile", "alex"]),
+        &"Alex Smith, Designer".to_string(),
+    )
+    .await?;
+    db.insert(
+        &path(&["user", "settings", "alex"]),
+        &"dark_mode=true".to_string(),
+    )
+    .await?;
+    db.insert(
+        &path(&["user", "activity", "alex", "login"]),
+        &"Last login: yesterday".to_string(),
+    )
+    .await?;
+    db.insert(
+        &path(&["user", "activity", "alex", "purchase"]),
+        &"Last purchase: 3 days ago".to_string(),
+    )
+    .await?;
 
     // Get all data for a user across different categories
     println!("All data for Alex:");
-    let alex_data = db.select_range(b"user:", b"user:\xff").await?;
+    let alex_data = db.scan_prefix(&path(&["user"])).await?;
     for (key, value) in alex_data {
-        let key_str = String::from_utf8_lossy(&key);
-        if key_str.contains(":alex") || key_str.contains(":alex:") {
-            println!("  {}: {}", key_str, String::from_utf8_lossy(&value));
+        if key.elements().get(2) == Some(&Element::from("alex")) {
+            println!("  {}: {}", display(&key), value);
         }
     }
 
@@ -175,28 +217,45 @@ async fn main() -> Result<()> {
     println!("\n=== TIME-BASED HIERARCHICAL DATA ===");
 
     // Logs with year:month:day:hour format
-    db.add(b"logs:2023:01:01:00", b"New Year system check")
-        .await?;
-    db.add(b"logs:2023:01:01:12", b"Noon status update").await?;
-    db.add(b"logs:2023:05:15:09", b"Morning error report")
-        .await?;
-    db.add(b"logs:2023:05:15:14", b"Afternoon warning").await?;
-    db.add(b"logs:2023:05:15:18", b"Evening system restart")
-        .await?;
+    db.insert(
+        &path(&["logs", "2023", "01", "01", "00"]),
+        &"New Year system check".to_string(),
+    )
+    .await?;
+    db.insert(
+        &path(&["logs", "2023", "01", "01", "12"]),
+        &"Noon status update".to_string(),
+    )
+    .await?;
+    db.insert(
+        &path(&["logs", "2023", "05", "15", "09"]),
+        &"Morning error report".to_string(),
+    )
+    .await?;
+    db.insert(
+        &path(&["logs", "2023", "05", "15", "14"]),
+        &"Afternoon warning".to_string(),
+    )
+    .await?;
+    db.insert(
+        &path(&["logs", "2023", "05", "15", "18"]),
+        &"Evening system restart".to_string(),
+    )
+    .await?;
 
     // Query a specific day's logs
     println!("Logs from May 15, 2023:");
-    let day_logs = db
-        .select_range(b"logs:2023:05:15:", b"logs:2023:05:15:\xff")
-        .await?;
+    let day_logs = db.scan_prefix(&path(&["logs", "2023", "05", "15"])).await?;
     for (key, value) in day_logs {
-        let key_str = String::from_utf8_lossy(&key);
-        let hour = key_str.split(':').nth(4).unwrap_or("unknown");
-        println!("  {}:00 - {}", hour, String::from_utf8_lossy(&value));
+        let hour = match key.elements().get(4) {
+            Some(Element::String(hour)) => hour.as_str(),
+            _ => "unknown",
+        };
+        println!("  {}:00 - {}", hour, value);
     }
 
     // Flush changes to disk
-    db.flush().await?;
+    db.into_inner().flush().await?;
     println!("\nVariable-length category database saved and closed successfully!");
 
     Ok(())
@@ -0,0 +1,131 @@
+#[cfg(not(target_arch = "wasm32"))]
+use std::error::Error;
+#[cfg(not(target_arch = "wasm32"))]
+use std::time::Instant;
+#[cfg(not(target_arch = "wasm32"))]
+use versedb::client::connect;
+#[cfg(not(target_arch = "wasm32"))]
+use versedb::database::Database;
+#[cfg(not(target_arch = "wasm32"))]
+use versedb::server::{serve, serve_with_workers};
+#[cfg(not(target_arch = "wasm32"))]
+use versedb::sled::SledDatabase;
+
+/// Groups of records the clients scan, one prefix per request
+#[cfg(not(target_arch = "wasm32"))]
+const GROUPS: usize = 100;
+#[cfg(not(target_arch = "wasm32"))]
+const RECORDS_PER_GROUP: usize = 50;
+#[cfg(not(target_arch = "wasm32"))]
+const REQUESTS_PER_CONNECTION: usize = 500;
+
+/// Scan `REQUESTS_PER_CONNECTION` prefixes over one connection of its own
+#[cfg(not(target_arch = "wasm32"))]
+fn run_client(addr: &str, offset: usize) -> Result<(), Box<dyn Error>> {
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()?;
+    let local = tokio::task::LocalSet::new();
+    local.block_on(&runtime, async {
+        let client = connect(addr).await?;
+        for request in 0..REQUESTS_PER_CONNECTION {
+            let group = (offset + request) % GROUPS;
+            let prefix = format!("group:{:03}:", group);
+            let records = client.scan_prefix(prefix.as_bytes()).await?;
+            assert_eq!(records.len(), RECORDS_PER_GROUP);
+        }
+        Ok(())
+    })
+}
+
+/// Measure the requests per second a server sustains as connections are
+/// added, against what one connection gets. Reads share the store and run on
+/// the blocking pool, so throughput should grow with the connections until
+/// the cores are busy. Pass a worker count to spread the connections over a
+/// pool of RPC threads as well, and a minimum speedup to fail unless the most
+/// connections reach it.
+#[cfg(not(target_arch = "wasm32"))]
+fn main() -> Result<(), Box<dyn Error>> {
+    let mut args = std::env::args().skip(1);
+    let workers: usize = match args.next() {
+        Some(workers) => workers.parse()?,
+        None => 1,
+    };
+    let min_speedup: Option<f64> = args.next().map(|speedup| speedup.parse()).transpose()?;
+    let temp_dir = tempfile::tempdir()?;
+    let path = temp_dir.path().to_str().unwrap().to_string();
+    let runtime = tokio::runtime::Runtime::new()?;
+    let db = runtime.block_on(async {
+        let mut db = SledDatabase::open(&path).await?;
+        let value = vec![b'v'; 1024];
+        for group in 0..GROUPS {
+            for record in 0..RECORDS_PER_GROUP {
+                let key = format!("group:{:03}:{:03}", group, record);
+                db.add(key.as_bytes(), &value).await?;
+            }
+        }
+        Ok::<_, anyhow::Error>(db)
+    })?;
+
+    let listener = std::net::TcpListener::bind("127.0.0.1:0")?;
+    let addr = listener.local_addr()?.to_string();
+    listener.set_nonblocking(true)?;
+    // The RPC system runs on the thread calling `serve`
+    std::thread::spawn(move || {
+        runtime.block_on(async move {
+            let listener = tokio::net::TcpListener::from_std(listener)?;
+            if workers > 1 {
+                serve_with_workers(listener, db, workers).await
+            } else {
+                serve(listener, db).await
+            }
+        })
+    });
+    println!(
+        "Scanning {} records of 1 KiB per request, {} requests per connection, {} workers",
+        RECORDS_PER_GROUP, REQUESTS_PER_CONNECTION, workers
+    );
+
+    let mut single = None;
+    let mut speedup = 1.0;
+    for connections in [1, 2, 4, 8, 16] {
+        let started = Instant::now();
+        std::thread::scope(|scope| {
+            let clients: Vec<_> = (0..connections)
+                .map(|i| {
+                    let addr = &addr;
+                    scope.spawn(move || run_client(addr, i * 7).map_err(|e| e.to_string()))
+                })
+                .collect();
+            for client in clients {
+                client.join().unwrap()?;
+            }
+            Ok::<_, String>(())
+        })?;
+        let elapsed = started.elapsed().as_secs_f64();
+        let requests = connections * REQUESTS_PER_CONNECTION;
+        let throughput = requests as f64 / elapsed;
+        let single = *single.get_or_insert(throughput);
+        speedup = throughput / single;
+        println!(
+            "{:>2} connections: {:>8.0} requests/s, {:>5.2}x one connection",
+            connections, throughput, speedup
+        );
+    }
+
+    if let Some(min_speedup) = min_speedup
+        && speedup < min_speedup
+    {
+        return Err(format!(
+            "16 connections reached {:.2}x the throughput of one, below {:.2}x",
+            speedup, min_speedup
+        )
+        .into());
+    }
+    Ok(())
+}
+
+#[cfg(target_arch = "wasm32")]
+fn main() {
+    // Empty main function for wasm32 target
+}
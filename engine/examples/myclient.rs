@@ -27,8 +27,12 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             println!("Added key-value pair");
 
             // Select the value
-            let retrieved = client.select(key).await?;
-            println!("Retrieved value: {:?}", String::from_utf8_lossy(&retrieved));
+            match client.select(key).await? {
+                Some(retrieved) => {
+                    println!("Retrieved value: {:?}", String::from_utf8_lossy(&retrieved))
+                }
+                None => println!("Key not found"),
+            }
 
             // Test select_range functionality
             // Add multiple key-value pairs for range test
@@ -3,12 +3,12 @@ use serde::{Deserialize, Serialize};
 #[cfg(not(target_arch = "wasm32"))]
 use std::error::Error;
 #[cfg(not(target_arch = "wasm32"))]
+use versedb::collection::Collection;
+#[cfg(not(target_arch = "wasm32"))]
 use versedb::database::Database;
 #[cfg(not(target_arch = "wasm32"))]
 use versedb::sled::SledDatabase;
 
-const PREFIX_CATEGORY: &str = "category";
-
 #[derive(Serialize, Deserialize)]
 struct CategoryItem {
     id: u32,
@@ -20,7 +20,8 @@ struct CategoryItem {
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn Error>> {
     // Create a new database
-    let mut db = SledDatabase::open("category_db").await?;
+    let db = SledDatabase::open("category_db").await?;
+    let mut items: Collection<_, u32, CategoryItem> = Collection::new(db, "mycategory")?;
 
     // Add 100 items
     println!("Adding 100 items to mycategory...");
@@ -31,29 +32,21 @@ async fn main() -> Result<(), Box<dyn Error>> {
             data: format!("Item data {}", i),
         };
 
-        let key = format!("{}_mycategory_{:010}", PREFIX_CATEGORY, i);
-        let value = serde_json::to_vec(&item)?;
-
-        db.add(key.as_bytes(), &value).await?;
+        items.insert(&i, &item).await?;
         println!("Added item {}", i);
     }
 
     // Select all items using range
     println!("\nSelecting all items using range:");
-    let prefix = format!("{}_mycategory_", PREFIX_CATEGORY);
-    let mut end_key = prefix.clone().into_bytes();
-    end_key.push(0xff);
-
-    let pairs = db.select_range(prefix.as_bytes(), &end_key).await?;
+    let pairs = items.range(..).await?;
     println!("Found {} items", pairs.len());
 
     // Display first 5 items as sample
-    for (i, (key, value)) in pairs.iter().enumerate() {
-        let item: CategoryItem = serde_json::from_slice(&value)?;
+    for (i, (key, item)) in pairs.iter().enumerate() {
         println!(
             "Item {}: Key={}, ID={}, Timestamp={}, Data={}",
             i + 1,
-            String::from_utf8_lossy(key),
+            key,
             item.id,
             item.timestamp,
             item.data
@@ -61,7 +54,7 @@ async fn main() -> Result<(), Box<dyn Error>> {
     }
     use std::io::Read;
     // Clean up
-    db.close().await?;
+    items.into_inner().close().await?;
     Ok(())
 }
 
@@ -5,6 +5,20 @@ import asyncio
 import capnp
 import versedb_capnp
 
+ERROR_PREFIX = "versedb-error "
+
+
+def error_code(error):
+    """Name of the `ErrorCode` a server failed a call with, and its message,
+    or None for an exception that doesn't carry one."""
+    _, found, reason = error.description.partition(ERROR_PREFIX)
+    code, separator, message = reason.partition(": ")
+    if not found or not separator or not code.isdigit():
+        return None
+    enumerants = versedb_capnp.ErrorCode.schema.enumerants
+    names = {value: name for name, value in enumerants.items()}
+    return names.get(int(code), "other"), message
+
 
 async def main():
     parser = argparse.ArgumentParser(
@@ -41,6 +55,13 @@ async def main():
     result = await versedb.select(key)
     print(f"Selected value: {result.value}")
 
+    # Test error codes
+    print("\nTesting increment on a non-counter value...")
+    try:
+        await versedb.increment(key, 1)
+    except capnp.KjException as error:
+        print(f"Server failed the call: {error_code(error) or error.description}")
+
     # Test selectRange
     print("\nTesting selectRange...")
     range = versedb_capnp.KeyRange.new_message()
@@ -69,7 +90,7 @@ async def main():
     # Verify removal
     print("\nVerifying removal...")
     result = await versedb.select(key)
-    print(f"Value after removal: {result.value}")
+    print(f"Value after removal: {result.value if result.found else 'not found'}")
 
 
 if __name__ == "__main__":
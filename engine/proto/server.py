@@ -7,6 +7,14 @@ import versedb_capnp
 from typing import Dict
 
 
+class VersedbError(Exception):
+    """Failure sent to clients as `versedb-error <code>: <message>`, the
+    code being the number of an `ErrorCode`."""
+
+    def __init__(self, code, message):
+        super().__init__(f"versedb-error {code}: {message}")
+
+
 class VersedbImpl(versedb_capnp.Versedb.Server):
     """Implementation of the Versedb Cap'n Proto interface."""
 
@@ -24,7 +32,7 @@ class VersedbImpl(versedb_capnp.Versedb.Server):
         if key in self.store:
             _context.results.value = self.store[key]
         else:
-            _context.results.value = b""
+            _context.results.found = False
 
     async def remove(self, key, _context, **kwargs):
         """Remove a key-value pair from the store."""
@@ -74,6 +82,19 @@ class VersedbImpl(versedb_capnp.Versedb.Server):
             pairs[i].key = key
             pairs[i].value = value
 
+    async def increment(self, key, delta, _context, **kwargs):
+        """Add delta to the counter stored under key."""
+        print(f"🔢 Incrementing counter")
+        try:
+            value = int(self.store.get(key, b"0").decode()) + delta
+        except (UnicodeDecodeError, ValueError):
+            raise VersedbError(
+                versedb_capnp.ErrorCode.invalidArgument,
+                f"value is not a counter: {self.store[key]!r}",
+            )
+        self.store[key] = str(value).encode()
+        _context.results.value = value
+
     async def helloworld(self, input, _context, **kwargs):
         """Simple hello world method."""
         print(f"👋 Hello world request with input: {input}")
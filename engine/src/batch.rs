@@ -0,0 +1,161 @@
+use crate::database::Result;
+use std::collections::BTreeMap;
+
+/// A single operation recorded in a `WriteBatch`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BatchOp {
+    /// Add or overwrite a key-value pair
+    Put { key: Vec<u8>, value: Vec<u8> },
+    /// Remove a single key
+    Delete { key: Vec<u8> },
+    /// Remove every key within the range [start, end)
+    DeleteRange { start: Vec<u8>, end: Vec<u8> },
+}
+
+/// A backend-neutral list of writes applied atomically by `Database::write_batch`.
+/// Operations are applied in the order they were recorded.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct WriteBatch {
+    ops: Vec<BatchOp>,
+}
+
+impl WriteBatch {
+    /// Create an empty batch
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record a key-value pair to add
+    pub fn put(&mut self, key: &[u8], value: &[u8]) -> &mut Self {
+        self.ops.push(BatchOp::Put {
+            key: key.to_vec(),
+            value: value.to_vec(),
+        });
+        self
+    }
+
+    /// Record a key to remove
+    pub fn delete(&mut self, key: &[u8]) -> &mut Self {
+        self.ops.push(BatchOp::Delete { key: key.to_vec() });
+        self
+    }
+
+    /// Record a range [start, end) to remove
+    pub fn delete_range(&mut self, start: &[u8], end: &[u8]) -> &mut Self {
+        self.ops.push(BatchOp::DeleteRange {
+            start: start.to_vec(),
+            end: end.to_vec(),
+        });
+        self
+    }
+
+    /// Number of recorded operations
+    pub fn len(&self) -> usize {
+        self.ops.len()
+    }
+
+    /// Whether the batch has no operations
+    pub fn is_empty(&self) -> bool {
+        self.ops.is_empty()
+    }
+
+    /// The recorded operations, in order
+    pub fn ops(&self) -> &[BatchOp] {
+        &self.ops
+    }
+
+    /// Keys the batch writes a value to
+    pub(crate) fn put_keys(&self) -> Vec<Vec<u8>> {
+        self.ops
+            .iter()
+            .filter_map(|op| match op {
+                BatchOp::Put { key, .. } => Some(key.clone()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// The final per-key effect of the batch on `data`, `None` marking a removal
+    pub(crate) fn changes_in_map<V>(
+        &self,
+        data: &BTreeMap<Vec<u8>, V>,
+    ) -> Result<BTreeMap<Vec<u8>, Option<Vec<u8>>>> {
+        self.clone().resolve(|start, end| {
+            Ok(data
+                .range(start.to_vec()..end.to_vec())
+                .map(|(key, _)| key.clone())
+                .collect())
+        })
+    }
+
+    /// Apply the batch to an in-memory map. Every value is converted before the
+    /// map is touched, so a failing `convert` leaves `data` unchanged.
+    pub(crate) fn apply_to_map<V>(
+        self,
+        data: &mut BTreeMap<Vec<u8>, V>,
+        convert: impl Fn(&[u8]) -> Result<V>,
+    ) -> Result<()> {
+        let mut values = Vec::new();
+        for op in &self.ops {
+            if let BatchOp::Put { value, .. } = op {
+                values.push(convert(value)?);
+            }
+        }
+
+        let mut values = values.into_iter();
+        for op in self.ops {
+            match op {
+                BatchOp::Put { key, .. } => {
+                    data.insert(key, values.next().unwrap());
+                }
+                BatchOp::Delete { key } => {
+                    data.remove(&key);
+                }
+                BatchOp::DeleteRange { start, end } => {
+                    if start < end {
+                        let keys: Vec<Vec<u8>> =
+                            data.range(start..end).map(|(k, _)| k.clone()).collect();
+                        for key in keys {
+                            data.remove(&key);
+                        }
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Flatten the batch into its final per-key effect (`None` marks a removal),
+    /// expanding range deletes with `keys_in_range` for backends that can only
+    /// apply point writes atomically.
+    pub(crate) fn resolve(
+        self,
+        keys_in_range: impl Fn(&[u8], &[u8]) -> Result<Vec<Vec<u8>>>,
+    ) -> Result<BTreeMap<Vec<u8>, Option<Vec<u8>>>> {
+        let mut writes = BTreeMap::new();
+        for op in self.ops {
+            match op {
+                BatchOp::Put { key, value } => {
+                    writes.insert(key, Some(value));
+                }
+                BatchOp::Delete { key } => {
+                    writes.insert(key, None);
+                }
+                BatchOp::DeleteRange { start, end } => {
+                    if start >= end {
+                        continue;
+                    }
+                    // Earlier puts in this batch are not in the store yet
+                    for (_, value) in writes.range_mut(start.clone()..end.clone()) {
+                        *value = None;
+                    }
+                    for key in keys_in_range(&start, &end)? {
+                        writes.insert(key, None);
+                    }
+                }
+            }
+        }
+        Ok(writes)
+    }
+}
@@ -13,6 +13,7 @@ use fake::{Fake, Faker};
 use std::io::{self, Write};
 #[cfg(not(target_arch = "wasm32"))]
 use versedb::client::connect;
+use versedb::tuple::Tuple;
 
 #[derive(Parser)]
 #[command(author, version, about, long_about = None)]
@@ -32,7 +33,10 @@ async fn print_menu() {
     println!("5. Remove range");
     println!("6. Hello world");
     println!("7. Flush");
+    println!("8. Scan prefix");
+    println!("9. Remove prefix");
     println!("11. Add many key-value pairs");
+    println!("12. Select numbered range of a category");
     println!("0. Exit");
     print!("\nEnter your choice: ");
     io::stdout().flush().unwrap();
@@ -74,8 +78,10 @@ async fn main() -> anyhow::Result<()> {
                     }
                     "3" => {
                         let key = get_input("Enter key to select: ").await;
-                        let result = client.select(key.as_bytes()).await?;
-                        println!("Value: {}", String::from_utf8_lossy(&result));
+                        match client.select(key.as_bytes()).await? {
+                            Some(value) => println!("Value: {}", String::from_utf8_lossy(&value)),
+                            None => println!("Key not found"),
+                        }
                     }
                     "4" => {
                         let start_key = get_input("Enter start key: ").await;
@@ -117,6 +123,31 @@ async fn main() -> anyhow::Result<()> {
                         client.flush().await?;
                         println!("Database flushed successfully!");
                     }
+                    "8" => {
+                        let prefix = get_input("Enter prefix: ").await;
+                        let results = client.scan_prefix(prefix.as_bytes()).await?;
+                        println!("\nPrefix results:");
+                        for (k, v) in results {
+                            println!(
+                                "Key: {}, Value: {}",
+                                String::from_utf8_lossy(&k),
+                                String::from_utf8_lossy(&v)
+                            );
+                        }
+                    }
+                    "9" => {
+                        let prefix = get_input("Enter prefix: ").await;
+                        let removed = client.remove_prefix(prefix.as_bytes()).await?;
+                        println!("\nRemoved prefix results:");
+                        for (k, v) in removed {
+                            println!(
+                                "Key: {}, Value: {}",
+                                String::from_utf8_lossy(&k),
+                                String::from_utf8_lossy(&v)
+                            );
+                        }
+                        println!("Prefix removed successfully!");
+                    }
                     "11" => {
                         let category = get_input("Enter category: ").await;
                         let start_num = get_input("Enter start number: ")
@@ -137,17 +168,42 @@ async fn main() -> anyhow::Result<()> {
                             let utc_time = Utc::now().to_rfc3339();
                             let local_time = Local::now().to_rfc3339();
 
-                            let key = format!("{}:{:010}", category, i);
+                            let key = Tuple::new().push(category.as_str()).push(i);
                             let value = format!(
                                 "name:{}|email:{}|company:{}|desc:{}|UTC:{}|LOCAL:{}",
                                 name, email, company, description, utc_time, local_time
                             );
 
-                            client.add(key.as_bytes(), value.as_bytes()).await?;
+                            client.add(&key.pack(), value.as_bytes()).await?;
                             println!("Added - Key: {}, Value: {}", key, value);
                         }
                         println!("Multiple key-value pairs with fake data added successfully!");
                     }
+                    "12" => {
+                        let category = get_input("Enter category: ").await;
+                        let start_num = get_input("Enter start number: ")
+                            .await
+                            .parse::<i32>()
+                            .unwrap_or(0);
+                        let end_num = get_input("Enter end number: ")
+                            .await
+                            .parse::<i32>()
+                            .unwrap_or(10);
+
+                        let start_key = Tuple::new().push(category.as_str()).push(start_num);
+                        let end_key = Tuple::new().push(category.as_str()).push(end_num);
+                        let range_result = client
+                            .select_range(&start_key.pack(), &end_key.range().1)
+                            .await?;
+                        println!("\nRange results:");
+                        for (k, v) in range_result {
+                            let key = match Tuple::unpack(&k) {
+                                Ok(key) => key.to_string(),
+                                Err(_) => String::from_utf8_lossy(&k).into_owned(),
+                            };
+                            println!("Key: {}, Value: {}", key, String::from_utf8_lossy(&v));
+                        }
+                    }
                     "0" => {
                         println!("Goodbye!");
                         break;
@@ -1,16 +1,16 @@
 use clap::Parser;
-use versedb::csv::CsvDatabase;
+use versedb::csv::{CsvDatabase, CsvOptions};
 use versedb::database::Database;
-use versedb::json::JsonDatabase;
+use versedb::json::{JsonDatabase, JsonOptions};
 use versedb::memory::MemoryDatabase;
 #[cfg(not(target_arch = "wasm32"))]
 use versedb::sled::SledDatabase;
 #[cfg(not(target_arch = "wasm32"))]
 use versedb::sqlite::SqliteDatabase;
-use versedb::yaml::YamlDatabase;
+use versedb::yaml::{YamlDatabase, YamlOptions};
 
 #[cfg(not(target_arch = "wasm32"))]
-use versedb::server::run_server;
+use versedb::server::{run_server, run_server_with_workers};
 
 #[derive(Parser)]
 #[command(author, version, about, long_about = None)]
@@ -24,37 +24,79 @@ struct Args {
 
     #[arg(long, default_value = "data.csv")]
     dbpath: String,
+
+    /// Serve a csv, json or yaml file without writing to it, sharing it with
+    /// other read-only servers
+    #[arg(long)]
+    read_only: bool,
+
+    /// Threads serving connections; with more than one, connections are
+    /// spread over a pool of them
+    #[arg(long, default_value_t = 1)]
+    workers: usize,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+async fn serve<T: Database + Clone + Send + Sync + 'static>(
+    args: &Args,
+    db: T,
+) -> anyhow::Result<()> {
+    if args.workers > 1 {
+        run_server_with_workers(&args.address, db, args.workers).await
+    } else {
+        run_server(&args.address, db).await
+    }
 }
 
 #[tokio::main]
 #[cfg(not(target_arch = "wasm32"))]
 async fn main() -> anyhow::Result<()> {
     let args = Args::parse();
+    if args.read_only && !matches!(args.dbtype.as_str(), "csv" | "json" | "yaml") {
+        eprintln!("--read-only is only supported for csv, json and yaml");
+        std::process::exit(1);
+    }
+    if args.workers == 0 {
+        eprintln!("--workers must be at least 1");
+        std::process::exit(1);
+    }
 
     match args.dbtype.as_str() {
         "csv" => {
-            let db = CsvDatabase::open(&args.dbpath).await?;
-            run_server(&args.address, db).await?;
+            let options = CsvOptions {
+                read_only: args.read_only,
+                ..CsvOptions::default()
+            };
+            let db = CsvDatabase::open_with(&args.dbpath, options).await?;
+            serve(&args, db).await?;
         }
         "json" => {
-            let db = JsonDatabase::open(&args.dbpath).await?;
-            run_server(&args.address, db).await?;
+            let options = JsonOptions {
+                read_only: args.read_only,
+                ..JsonOptions::default()
+            };
+            let db = JsonDatabase::open_with(&args.dbpath, options).await?;
+            serve(&args, db).await?;
         }
         "sqlite" => {
             let db = SqliteDatabase::open(&args.dbpath).await?;
-            run_server(&args.address, db).await?;
+            serve(&args, db).await?;
         }
         "yaml" => {
-            let db = YamlDatabase::open(&args.dbpath).await?;
-            run_server(&args.address, db).await?;
+            let options = YamlOptions {
+                read_only: args.read_only,
+                ..YamlOptions::default()
+            };
+            let db = YamlDatabase::open_with(&args.dbpath, options).await?;
+            serve(&args, db).await?;
         }
         "sled" => {
             let db = SledDatabase::open(&args.dbpath).await?;
-            run_server(&args.address, db).await?;
+            serve(&args, db).await?;
         }
         "memory" => {
             let db = MemoryDatabase::open(&args.dbpath).await?;
-            run_server(&args.address, db).await?;
+            serve(&args, db).await?;
         }
         _ => {
             eprintln!("Unsupported database type: {}", args.dbtype);
@@ -1,17 +1,27 @@
-use crate::versedb_capnp::versedb;
+use crate::database::{
+    BatchOp, CompareAndSwapError, DatabaseError, ScanOptions, WatchEvent, WriteBatch,
+};
+use crate::versedb_capnp::{snapshot, versedb, watch_event, watch_handle, watcher};
 use anyhow;
 use capnp::Error;
 use capnp::capability::Promise;
-use capnp_rpc::{RpcSystem, rpc_twoparty_capnp, twoparty};
+use capnp_rpc::{RpcSystem, pry, rpc_twoparty_capnp, twoparty};
 use futures::AsyncReadExt;
 use futures::AsyncWriteExt;
+use futures::channel::mpsc::{self, UnboundedReceiver, UnboundedSender};
+use futures::{Stream, StreamExt};
 use std::fmt;
 use std::net::ToSocketAddrs;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::Duration;
 use tokio::io::{self, AsyncBufReadExt, AsyncWriteExt as _, BufReader};
 use tokio_util::compat::TokioAsyncReadCompatExt;
 
 #[derive(Debug)]
 pub enum ClientError {
+    /// The server failed the call with a `DatabaseError`
+    Database(DatabaseError),
     CapnpError(Error),
     Utf8Error(std::str::Utf8Error),
 }
@@ -19,6 +29,7 @@ pub enum ClientError {
 impl fmt::Display for ClientError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
+            ClientError::Database(e) => write!(f, "{}", e),
             ClientError::CapnpError(e) => write!(f, "CapnP error: {}", e),
             ClientError::Utf8Error(e) => write!(f, "UTF-8 error: {}", e),
         }
@@ -28,6 +39,7 @@ impl fmt::Display for ClientError {
 impl std::error::Error for ClientError {
     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
         match self {
+            ClientError::Database(e) => Some(e),
             ClientError::CapnpError(e) => Some(e),
             ClientError::Utf8Error(e) => Some(e),
         }
@@ -36,7 +48,10 @@ impl std::error::Error for ClientError {
 
 impl From<Error> for ClientError {
     fn from(err: Error) -> Self {
-        ClientError::CapnpError(err)
+        match DatabaseError::from_rpc(&err) {
+            Some(e) => ClientError::Database(e),
+            None => ClientError::CapnpError(err),
+        }
     }
 }
 
@@ -50,6 +65,88 @@ pub struct VerseDbClient {
     client: versedb::Client,
 }
 
+/// View the server keeps for one `snapshot` call; dropping this releases it
+pub struct SnapshotClient {
+    client: snapshot::Client,
+}
+
+impl SnapshotClient {
+    pub async fn select(&self, key: &[u8]) -> Result<Option<Vec<u8>>, ClientError> {
+        let mut request = self.client.select_request();
+        request.get().set_key(key);
+        let response = request.send().promise.await?;
+        let response = response.get()?;
+        if !response.get_found() {
+            return Ok(None);
+        }
+        Ok(Some(response.get_value()?.to_vec()))
+    }
+
+    pub async fn select_range(
+        &self,
+        start: &[u8],
+        end: &[u8],
+    ) -> Result<Vec<(Vec<u8>, Vec<u8>)>, ClientError> {
+        let mut request = self.client.select_range_request();
+        {
+            let mut range = request.get().init_range();
+            range.set_start(start);
+            range.set_end(end);
+        }
+        let response = request.send().promise.await?;
+        let pairs = response.get()?.get_pairs()?;
+        let mut result = Vec::new();
+        for i in 0..pairs.len() {
+            let pair = pairs.get(i);
+            result.push((pair.get_key()?.to_vec(), pair.get_value()?.to_vec()));
+        }
+        Ok(result)
+    }
+}
+
+// Receives the events the server forwards for one `watch_prefix` call
+struct EventForwarder {
+    events: UnboundedSender<WatchEvent>,
+}
+
+impl watcher::Server for EventForwarder {
+    fn event(
+        &mut self,
+        params: watcher::EventParams,
+        _results: watcher::EventResults,
+    ) -> Promise<(), Error> {
+        let event = pry!(pry!(params.get()).get_event());
+        let key = pry!(event.get_key()).to_vec();
+        let event = match pry!(event.which()) {
+            watch_event::Put(value) => WatchEvent::Put {
+                key,
+                value: pry!(value).to_vec(),
+            },
+            watch_event::Delete(()) => WatchEvent::Delete { key },
+        };
+
+        // Failing the call tells the server the subscription was dropped
+        if self.events.unbounded_send(event).is_err() {
+            return Promise::err(Error::failed("watch subscription dropped".to_string()));
+        }
+        Promise::ok(())
+    }
+}
+
+pub struct WatchSubscription {
+    events: UnboundedReceiver<WatchEvent>,
+    // Dropping the handle tells the server to stop forwarding events
+    _handle: watch_handle::Client,
+}
+
+impl Stream for WatchSubscription {
+    type Item = WatchEvent;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<WatchEvent>> {
+        self.events.poll_next_unpin(cx)
+    }
+}
+
 pub async fn connect(addr: &str) -> anyhow::Result<VerseDbClient> {
     let addr = addr
         .to_socket_addrs()?
@@ -89,14 +186,18 @@ impl VerseDbClient {
         Ok(())
     }
 
-    pub async fn select(&self, key: &[u8]) -> Result<Vec<u8>, ClientError> {
+    pub async fn select(&self, key: &[u8]) -> Result<Option<Vec<u8>>, ClientError> {
         let mut request = self.client.select_request();
         {
             let mut params = request.get();
             params.set_key(key);
         }
         let response = request.send().promise.await?;
-        Ok(response.get()?.get_value()?.to_vec())
+        let response = response.get()?;
+        if !response.get_found() {
+            return Ok(None);
+        }
+        Ok(Some(response.get_value()?.to_vec()))
     }
 
     pub async fn remove(&self, key: &[u8]) -> Result<(), ClientError> {
@@ -114,12 +215,40 @@ impl VerseDbClient {
         start: &[u8],
         end: &[u8],
     ) -> Result<Vec<(Vec<u8>, Vec<u8>)>, ClientError> {
+        self.select_range_with(start, end, &ScanOptions::default())
+            .await
+    }
+
+    pub async fn select_range_with(
+        &self,
+        start: &[u8],
+        end: &[u8],
+        options: &ScanOptions,
+    ) -> Result<Vec<(Vec<u8>, Vec<u8>)>, ClientError> {
+        // A zero limit means "no limit" on the wire
+        if options.limit == Some(0) {
+            return Ok(Vec::new());
+        }
+
         let mut request = self.client.select_range_request();
         {
             let mut params = request.get();
-            let mut range = params.init_range();
+            let mut range = params.reborrow().init_range();
             range.set_start(start);
             range.set_end(end);
+
+            let mut wire_options = params.init_options();
+            wire_options.set_reverse(options.reverse);
+            wire_options.set_limit(
+                options
+                    .limit
+                    .map_or(0, |limit| u32::try_from(limit).unwrap_or(u32::MAX)),
+            );
+            wire_options.set_start_exclusive(!options.start_inclusive);
+            wire_options.set_end_inclusive(options.end_inclusive);
+            if let Some(after) = &options.after {
+                wire_options.set_after(after);
+            }
         }
         let response = request.send().promise.await?;
         let pairs = response.get()?.get_pairs()?;
@@ -153,6 +282,192 @@ impl VerseDbClient {
         Ok(result)
     }
 
+    pub async fn scan_prefix(&self, prefix: &[u8]) -> Result<Vec<(Vec<u8>, Vec<u8>)>, ClientError> {
+        let mut request = self.client.scan_prefix_request();
+        {
+            let mut params = request.get();
+            params.set_prefix(prefix);
+        }
+        let response = request.send().promise.await?;
+        let pairs = response.get()?.get_pairs()?;
+        let mut result = Vec::new();
+        for i in 0..pairs.len() {
+            let pair = pairs.get(i);
+            result.push((pair.get_key()?.to_vec(), pair.get_value()?.to_vec()));
+        }
+        Ok(result)
+    }
+
+    pub async fn remove_prefix(
+        &self,
+        prefix: &[u8],
+    ) -> Result<Vec<(Vec<u8>, Vec<u8>)>, ClientError> {
+        let mut request = self.client.remove_prefix_request();
+        {
+            let mut params = request.get();
+            params.set_prefix(prefix);
+        }
+        let response = request.send().promise.await?;
+        let pairs = response.get()?.get_pairs()?;
+        let mut result = Vec::new();
+        for i in 0..pairs.len() {
+            let pair = pairs.get(i);
+            result.push((pair.get_key()?.to_vec(), pair.get_value()?.to_vec()));
+        }
+        Ok(result)
+    }
+
+    pub async fn compare_and_swap(
+        &self,
+        key: &[u8],
+        expected: Option<&[u8]>,
+        new: Option<&[u8]>,
+    ) -> Result<std::result::Result<(), CompareAndSwapError>, ClientError> {
+        let mut request = self.client.compare_and_swap_request();
+        {
+            let mut params = request.get();
+            params.set_key(key);
+            if let Some(expected) = expected {
+                params.set_expected(expected);
+            }
+            if let Some(new) = new {
+                params.set_new(new);
+            }
+        }
+        let response = request.send().promise.await?;
+        let response = response.get()?;
+        if response.get_swapped() {
+            return Ok(Ok(()));
+        }
+
+        let current = if response.has_current() {
+            Some(response.get_current()?.to_vec())
+        } else {
+            None
+        };
+        Ok(Err(CompareAndSwapError { current }))
+    }
+
+    pub async fn write_batch(&self, batch: &WriteBatch) -> Result<(), ClientError> {
+        let mut request = self.client.write_batch_request();
+        {
+            let mut ops = request.get().init_ops(batch.len() as u32);
+            for (i, op) in batch.ops().iter().enumerate() {
+                let mut builder = ops.reborrow().get(i as u32);
+                match op {
+                    BatchOp::Put { key, value } => {
+                        let mut pair = builder.init_put();
+                        pair.set_key(key);
+                        pair.set_value(value);
+                    }
+                    BatchOp::Delete { key } => builder.set_delete(key),
+                    BatchOp::DeleteRange { start, end } => {
+                        let mut range = builder.init_delete_range();
+                        range.set_start(start);
+                        range.set_end(end);
+                    }
+                }
+            }
+        }
+        request.send().promise.await?;
+        Ok(())
+    }
+
+    pub async fn increment(&self, key: &[u8], delta: i64) -> Result<i64, ClientError> {
+        let mut request = self.client.increment_request();
+        {
+            let mut params = request.get();
+            params.set_key(key);
+            params.set_delta(delta);
+        }
+        let response = request.send().promise.await?;
+        Ok(response.get()?.get_value())
+    }
+
+    pub async fn add_with_ttl(
+        &self,
+        key: &[u8],
+        value: &[u8],
+        ttl: Duration,
+    ) -> Result<(), ClientError> {
+        let mut request = self.client.add_with_ttl_request();
+        {
+            let mut params = request.get();
+            params.set_key(key);
+            params.set_value(value);
+            params.set_ttl_millis(ttl.as_millis() as u64);
+        }
+        request.send().promise.await?;
+        Ok(())
+    }
+
+    pub async fn ttl(&self, key: &[u8]) -> Result<Option<Duration>, ClientError> {
+        let mut request = self.client.ttl_request();
+        request.get().set_key(key);
+        let response = request.send().promise.await?;
+        let response = response.get()?;
+        if !response.get_has_ttl() {
+            return Ok(None);
+        }
+        Ok(Some(Duration::from_millis(response.get_remaining_millis())))
+    }
+
+    pub async fn watch_prefix(&self, prefix: &[u8]) -> Result<WatchSubscription, ClientError> {
+        let (sender, events) = mpsc::unbounded();
+        let mut request = self.client.watch_prefix_request();
+        {
+            let mut params = request.get();
+            params.set_prefix(prefix);
+            params.set_watcher(capnp_rpc::new_client(EventForwarder { events: sender }));
+        }
+        let response = request.send().promise.await?;
+        let handle = response.get()?.get_handle()?;
+        Ok(WatchSubscription {
+            events,
+            _handle: handle,
+        })
+    }
+
+    pub async fn purge_expired(&self) -> Result<usize, ClientError> {
+        let request = self.client.purge_expired_request();
+        let response = request.send().promise.await?;
+        Ok(response.get()?.get_purged() as usize)
+    }
+
+    pub async fn snapshot(&self) -> Result<SnapshotClient, ClientError> {
+        let request = self.client.snapshot_request();
+        let response = request.send().promise.await?;
+        Ok(SnapshotClient {
+            client: response.get()?.get_snapshot()?,
+        })
+    }
+
+    /// Client for the tree `name`, creating it if needed
+    pub async fn open_tree(&self, name: &str) -> Result<VerseDbClient, ClientError> {
+        let mut request = self.client.open_tree_request();
+        request.get().set_name(name);
+        let response = request.send().promise.await?;
+        Ok(VerseDbClient::new(response.get()?.get_tree()?))
+    }
+
+    pub async fn list_trees(&self) -> Result<Vec<String>, ClientError> {
+        let request = self.client.list_trees_request();
+        let response = request.send().promise.await?;
+        let names = response.get()?.get_names()?;
+        let mut result = Vec::new();
+        for name in names.iter() {
+            result.push(name?.to_str()?.to_string());
+        }
+        Ok(result)
+    }
+
+    pub async fn drop_tree(&self, name: &str) -> Result<bool, ClientError> {
+        let mut request = self.client.drop_tree_request();
+        request.get().set_name(name);
+        let response = request.send().promise.await?;
+        Ok(response.get()?.get_existed())
+    }
+
     pub async fn helloworld(&self, input: &str) -> Result<String, ClientError> {
         let mut request = self.client.helloworld_request();
         {
@@ -180,6 +495,8 @@ async fn print_menu() {
     println!("5. Remove range");
     println!("6. Hello world");
     println!("7. Flush");
+    println!("8. Scan prefix");
+    println!("9. Remove prefix");
     println!("0. Exit");
     print!("\nEnter your choice: ");
     io::stdout().flush().await.unwrap();
@@ -221,8 +538,10 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                     }
                     "3" => {
                         let key = get_input("Enter key to select: ").await;
-                        let result = client.select(key.as_bytes()).await?;
-                        println!("Value: {}", String::from_utf8_lossy(&result));
+                        match client.select(key.as_bytes()).await? {
+                            Some(value) => println!("Value: {}", String::from_utf8_lossy(&value)),
+                            None => println!("Key not found"),
+                        }
                     }
                     "4" => {
                         let start_key = get_input("Enter start key: ").await;
@@ -264,6 +583,31 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                         client.flush().await?;
                         println!("Database flushed successfully!");
                     }
+                    "8" => {
+                        let prefix = get_input("Enter prefix: ").await;
+                        let results = client.scan_prefix(prefix.as_bytes()).await?;
+                        println!("\nPrefix results:");
+                        for (k, v) in results {
+                            println!(
+                                "Key: {}, Value: {}",
+                                String::from_utf8_lossy(&k),
+                                String::from_utf8_lossy(&v)
+                            );
+                        }
+                    }
+                    "9" => {
+                        let prefix = get_input("Enter prefix: ").await;
+                        let removed = client.remove_prefix(prefix.as_bytes()).await?;
+                        println!("\nRemoved prefix results:");
+                        for (k, v) in removed {
+                            println!(
+                                "Key: {}, Value: {}",
+                                String::from_utf8_lossy(&k),
+                                String::from_utf8_lossy(&v)
+                            );
+                        }
+                        println!("Prefix removed successfully!");
+                    }
                     "0" => {
                         println!("Goodbye!");
                         break;
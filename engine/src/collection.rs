@@ -0,0 +1,251 @@
+use crate::database::{Database, DatabaseError, Result};
+use crate::scan::{next_key, prefix_successor};
+use anyhow::{anyhow, bail};
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use std::marker::PhantomData;
+use std::ops::{Bound, RangeBounds};
+
+/// Keys of a `Collection`, encoded so that byte order matches the order of
+/// the keys themselves and range scans come back sorted
+pub trait OrderedKey: Sized {
+    /// Encode the key into bytes that sort like the key
+    fn encode_key(&self) -> Vec<u8>;
+
+    /// Decode a key written by `encode_key`
+    fn decode_key(bytes: &[u8]) -> Result<Self>;
+}
+
+impl OrderedKey for String {
+    fn encode_key(&self) -> Vec<u8> {
+        self.as_bytes().to_vec()
+    }
+
+    fn decode_key(bytes: &[u8]) -> Result<Self> {
+        Ok(String::from_utf8(bytes.to_vec())?)
+    }
+}
+
+impl OrderedKey for Vec<u8> {
+    fn encode_key(&self) -> Vec<u8> {
+        self.clone()
+    }
+
+    fn decode_key(bytes: &[u8]) -> Result<Self> {
+        Ok(bytes.to_vec())
+    }
+}
+
+/// Big-endian bytes sort like the unsigned numbers they hold
+macro_rules! unsigned_key {
+    ($($ty:ty),*) => {$(
+        impl OrderedKey for $ty {
+            fn encode_key(&self) -> Vec<u8> {
+                self.to_be_bytes().to_vec()
+            }
+
+            fn decode_key(bytes: &[u8]) -> Result<Self> {
+                let array = bytes.try_into().map_err(|_| {
+                    anyhow!("invalid {} key of {} bytes", stringify!($ty), bytes.len())
+                })?;
+                Ok(<$ty>::from_be_bytes(array))
+            }
+        }
+    )*};
+}
+
+/// Flipping the sign bit moves negative numbers below positive ones, after
+/// which big-endian bytes sort like the numbers
+macro_rules! signed_key {
+    ($($ty:ty => $unsigned:ty),*) => {$(
+        impl OrderedKey for $ty {
+            fn encode_key(&self) -> Vec<u8> {
+                let flipped = (*self as $unsigned) ^ (1 << (<$unsigned>::BITS - 1));
+                flipped.to_be_bytes().to_vec()
+            }
+
+            fn decode_key(bytes: &[u8]) -> Result<Self> {
+                let flipped = <$unsigned>::decode_key(bytes)?;
+                Ok((flipped ^ (1 << (<$unsigned>::BITS - 1))) as $ty)
+            }
+        }
+    )*};
+}
+
+unsigned_key!(u8, u16, u32, u64, u128);
+signed_key!(i8 => u8, i16 => u16, i32 => u32, i64 => u64, i128 => u128);
+
+/// Serialization of the values stored in a `Collection`
+pub trait Codec<V> {
+    /// Serialize `value` into the bytes to store
+    fn encode(value: &V) -> Result<Vec<u8>>;
+
+    /// Deserialize a value written by `encode`
+    fn decode(bytes: &[u8]) -> Result<V>;
+}
+
+/// Values stored as JSON text
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Json;
+
+impl<V: Serialize + DeserializeOwned> Codec<V> for Json {
+    fn encode(value: &V) -> Result<Vec<u8>> {
+        Ok(serde_json::to_vec(value)?)
+    }
+
+    fn decode(bytes: &[u8]) -> Result<V> {
+        Ok(serde_json::from_slice(bytes)?)
+    }
+}
+
+/// Values stored in bincode's compact binary format
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Bincode;
+
+impl<V: Serialize + DeserializeOwned> Codec<V> for Bincode {
+    fn encode(value: &V) -> Result<Vec<u8>> {
+        Ok(bincode::serde::encode_to_vec(
+            value,
+            bincode::config::standard(),
+        )?)
+    }
+
+    fn decode(bytes: &[u8]) -> Result<V> {
+        let (value, read) = bincode::serde::decode_from_slice(bytes, bincode::config::standard())?;
+        if read != bytes.len() {
+            bail!("{} trailing bytes after bincode value", bytes.len() - read);
+        }
+        Ok(value)
+    }
+}
+
+/// Values stored as self-describing FlexBuffers
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Flexbuffers;
+
+impl<V: Serialize + DeserializeOwned> Codec<V> for Flexbuffers {
+    fn encode(value: &V) -> Result<Vec<u8>> {
+        Ok(flexbuffers::to_vec(value)?)
+    }
+
+    fn decode(bytes: &[u8]) -> Result<V> {
+        Ok(flexbuffers::from_slice(bytes)?)
+    }
+}
+
+/// Typed view of the entries of a `Database` under one name: keys of type
+/// `K` map to values of type `V`, serialized with the codec `C`.
+/// Entries are stored under the name followed by a zero byte, so collections
+/// with different names can share a database as long as no name holds a
+/// zero byte itself.
+pub struct Collection<D, K, V, C = Json> {
+    db: D,
+    prefix: Vec<u8>,
+    _key: PhantomData<fn() -> K>,
+    _value: PhantomData<fn() -> V>,
+    _codec: PhantomData<C>,
+}
+
+impl<D, K, V, C> Collection<D, K, V, C>
+where
+    D: Database,
+    K: OrderedKey,
+    C: Codec<V>,
+{
+    /// Use the entries named `name` within `db`. Names can't contain a zero
+    /// byte, which ends the name in stored keys.
+    pub fn new(db: D, name: &str) -> Result<Self> {
+        if name.contains('\0') {
+            bail!(DatabaseError::InvalidArgument(format!(
+                "collection name {:?} contains a zero byte",
+                name
+            )));
+        }
+        let mut prefix = name.as_bytes().to_vec();
+        prefix.push(0);
+        Ok(Self {
+            db,
+            prefix,
+            _key: PhantomData,
+            _value: PhantomData,
+            _codec: PhantomData,
+        })
+    }
+
+    /// The database the collection reads and writes
+    pub fn database(&self) -> &D {
+        &self.db
+    }
+
+    /// Give back the database the collection was created with
+    pub fn into_inner(self) -> D {
+        self.db
+    }
+
+    fn storage_key(&self, key: &K) -> Vec<u8> {
+        let mut storage_key = self.prefix.clone();
+        storage_key.extend_from_slice(&key.encode_key());
+        storage_key
+    }
+
+    /// Store `value` under `key`, replacing any previous value
+    pub async fn insert(&mut self, key: &K, value: &V) -> Result<()> {
+        let value = C::encode(value)?;
+        self.db.add(&self.storage_key(key), &value).await
+    }
+
+    /// The value stored under `key`, if any
+    pub async fn get(&self, key: &K) -> Result<Option<V>> {
+        match self.db.select(&self.storage_key(key)).await? {
+            Some(value) => Ok(Some(C::decode(&value)?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Remove the value stored under `key`
+    pub async fn remove(&mut self, key: &K) -> Result<()> {
+        self.db.remove(&self.storage_key(key)).await
+    }
+
+    /// Every entry whose key falls in `range`, in key order
+    pub async fn range(&self, range: impl RangeBounds<K>) -> Result<Vec<(K, V)>> {
+        let start = match range.start_bound() {
+            Bound::Included(key) => self.storage_key(key),
+            Bound::Excluded(key) => next_key(&self.storage_key(key)),
+            Bound::Unbounded => self.prefix.clone(),
+        };
+        let end = match range.end_bound() {
+            Bound::Included(key) => next_key(&self.storage_key(key)),
+            Bound::Excluded(key) => self.storage_key(key),
+            // The prefix ends in a zero byte, so it always has a successor
+            Bound::Unbounded => prefix_successor(&self.prefix).unwrap(),
+        };
+        if start >= end {
+            return Ok(Vec::new());
+        }
+
+        self.db
+            .select_range(&start, &end)
+            .await?
+            .into_iter()
+            .map(|(key, value)| {
+                let key = K::decode_key(&key[self.prefix.len()..])?;
+                Ok((key, C::decode(&value)?))
+            })
+            .collect()
+    }
+
+    /// Every entry whose encoded key starts with the encoding of `prefix`, in
+    /// key order; with `Tuple` keys, every tuple extending `prefix`
+    pub async fn scan_prefix(&self, prefix: &K) -> Result<Vec<(K, V)>> {
+        self.db
+            .scan_prefix(&self.storage_key(prefix))
+            .await?
+            .into_iter()
+            .map(|(key, value)| {
+                let key = K::decode_key(&key[self.prefix.len()..])?;
+                Ok((key, C::decode(&value)?))
+            })
+            .collect()
+    }
+}
@@ -1,82 +1,428 @@
-use super::database::{Database, Result};
+use super::batch::{BatchOp, WriteBatch};
+use super::database::{
+    CompareAndSwapError, Database, DatabaseError, KvStream, MergeOperator, Result, ScanOptions,
+    Transaction,
+};
+use super::durable::{recover_file, remove_file, write_file};
+use super::lock::FileLock;
+use super::merge::{increment_in, merge_in};
+use super::scan::{chunked_scan, prefix_bounds, select_from_map};
+use super::snapshot::{MapSnapshot, Snapshot};
+use super::transaction::{OverlayTransaction, compare_and_swap_in};
+use super::tree::{TreeRegistry, check_tree_name, tree_file_path, tree_files};
+use super::ttl::{Expiry, deadline_after, now_millis, remaining_until};
+use super::wal::{self, SharedLog, WalOptions};
+use super::watch::{ChangeBroadcaster, WatchStream};
+use anyhow::bail;
 use async_trait::async_trait;
+use futures::future;
 use std::collections::BTreeMap;
 use std::error::Error;
-use std::fs::{File, OpenOptions};
-use std::io::{BufRead, BufReader, Write};
+use std::fs;
 use std::path::Path;
-use std::sync::Mutex;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::Duration;
+
+/// How keys and values are written as CSV fields
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum CsvEncoding {
+    /// As text, which only holds UTF-8: writing anything else fails
+    #[default]
+    Text,
+    /// As lowercase hexadecimal
+    Hex,
+    /// As standard, padded base64
+    Base64,
+}
+
+impl CsvEncoding {
+    fn encode(self, bytes: &[u8]) -> Result<String> {
+        match self {
+            CsvEncoding::Text => String::from_utf8(bytes.to_vec()).map_err(|_| {
+                DatabaseError::InvalidArgument(format!(
+                    "{:?} is not UTF-8, so it can only be stored with the hex or base64 encoding",
+                    String::from_utf8_lossy(bytes)
+                ))
+                .into()
+            }),
+            CsvEncoding::Hex => Ok(hex::encode(bytes)),
+            CsvEncoding::Base64 => Ok(base64_encode(bytes)),
+        }
+    }
+
+    fn decode(self, field: &str) -> Result<Vec<u8>> {
+        match self {
+            CsvEncoding::Text => Ok(field.as_bytes().to_vec()),
+            CsvEncoding::Hex => Ok(hex::decode(field)?),
+            CsvEncoding::Base64 => base64_decode(field),
+        }
+    }
+}
+
+/// Layout of the file behind a `CsvDatabase`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CsvOptions {
+    /// Whether the file starts with a `key,value` header row
+    pub header: bool,
+    pub encoding: CsvEncoding,
+    /// Log every write ahead of applying it, instead of only writing the
+    /// file on `flush`
+    pub wal: Option<WalOptions>,
+    /// Share the lock on the file with other read-only handles, even in
+    /// other processes, and refuse every write
+    pub read_only: bool,
+}
+
+const BASE64_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+fn base64_encode(bytes: &[u8]) -> String {
+    let mut encoded = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let group = chunk.iter().enumerate().fold(0u32, |group, (i, byte)| {
+            group | ((*byte as u32) << (16 - 8 * i))
+        });
+        for i in 0..4 {
+            if i <= chunk.len() {
+                encoded.push(BASE64_ALPHABET[((group >> (18 - 6 * i)) & 0x3f) as usize] as char);
+            } else {
+                encoded.push('=');
+            }
+        }
+    }
+    encoded
+}
+
+fn base64_decode(encoded: &str) -> Result<Vec<u8>> {
+    if !encoded.len().is_multiple_of(4) {
+        bail!(DatabaseError::Corruption(format!(
+            "invalid base64 length {}",
+            encoded.len()
+        )));
+    }
+    let mut bytes = Vec::with_capacity(encoded.len() / 4 * 3);
+    let chunks = encoded.len() / 4;
+    for (n, chunk) in encoded.as_bytes().chunks(4).enumerate() {
+        // Only the last group may be padded
+        let padding = chunk.iter().rev().take_while(|&&c| c == b'=').count();
+        if padding > 2 || (padding > 0 && n + 1 < chunks) {
+            bail!(DatabaseError::Corruption(
+                "invalid base64 padding".to_string()
+            ));
+        }
+        let mut group = 0u32;
+        for (i, &c) in chunk[..4 - padding].iter().enumerate() {
+            let digit = BASE64_ALPHABET
+                .iter()
+                .position(|&symbol| symbol == c)
+                .ok_or_else(|| {
+                    DatabaseError::Corruption(format!("invalid base64 character {:?}", c as char))
+                })?;
+            group |= (digit as u32) << (18 - 6 * i);
+        }
+        bytes.extend_from_slice(&group.to_be_bytes()[1..4 - padding]);
+    }
+    Ok(bytes)
+}
+
+/// Split RFC 4180 text into records of fields. Quoted fields may hold commas,
+/// line breaks and doubled quotes; records end with CRLF or a bare LF.
+fn parse_records(contents: &str) -> Result<Vec<Vec<String>>> {
+    let mut records = Vec::new();
+    let mut record = Vec::new();
+    let mut chars = contents.chars().peekable();
+    let mut line = 1;
+    while chars.peek().is_some() {
+        let mut field = String::new();
+        if chars.next_if_eq(&'"').is_some() {
+            loop {
+                match chars.next() {
+                    Some('"') if chars.next_if_eq(&'"').is_some() => field.push('"'),
+                    Some('"') => break,
+                    Some(c) => {
+                        if c == '\n' {
+                            line += 1;
+                        }
+                        field.push(c);
+                    }
+                    None => bail!(DatabaseError::Corruption(format!(
+                        "unterminated quoted field on line {}",
+                        line
+                    ))),
+                }
+            }
+        } else {
+            while let Some(c) = chars.next_if(|&c| !matches!(c, ',' | '\r' | '\n')) {
+                if c == '"' {
+                    bail!(DatabaseError::Corruption(format!(
+                        "quote in an unquoted field on line {}",
+                        line
+                    )));
+                }
+                field.push(c);
+            }
+        }
+        record.push(field);
+
+        match chars.next() {
+            Some(',') => {
+                // A trailing comma leaves an empty last field
+                if chars.peek().is_none() {
+                    record.push(String::new());
+                }
+                continue;
+            }
+            Some('\r') if chars.next_if_eq(&'\n').is_some() => {}
+            Some('\n') | None => {}
+            Some(c) => bail!(DatabaseError::Corruption(format!(
+                "unexpected {:?} on line {}",
+                c, line
+            ))),
+        }
+        records.push(std::mem::take(&mut record));
+        line += 1;
+    }
+    if !record.is_empty() {
+        records.push(record);
+    }
+    Ok(records)
+}
+
+/// Split a file written before fields were quoted: one `key,value` record
+/// per line, cut at the first comma, with quotes taken as plain text. Lines
+/// without a comma were never readable and are dropped.
+fn parse_legacy(contents: &str) -> Vec<Vec<String>> {
+    contents
+        .lines()
+        .filter_map(|line| line.split_once(','))
+        .map(|(key, value)| vec![key.to_string(), value.to_string()])
+        .collect()
+}
+
+/// Whether every record after the first `skip` is a key and a value, or blank
+fn well_formed(records: &[Vec<String>], skip: usize) -> bool {
+    records
+        .iter()
+        .skip(skip)
+        .all(|record| record.len() == 2 || (record.len() == 1 && record[0].is_empty()))
+}
+
+/// Append `field` to a record, quoted if it holds a comma, quote or line break
+fn write_field(out: &mut String, field: &str) {
+    if field.contains([',', '"', '\r', '\n']) {
+        out.push('"');
+        out.push_str(&field.replace('"', "\"\""));
+        out.push('"');
+    } else {
+        out.push_str(field);
+    }
+}
 
 pub struct CsvDatabase {
     path: String,
-    data: Mutex<BTreeMap<Vec<u8>, Vec<u8>>>,
+    options: CsvOptions,
+    /// Shared with the other handles on the same tree
+    data: Arc<Mutex<BTreeMap<Vec<u8>, Vec<u8>>>>,
+    /// Shared with the other handles on the same tree
+    wal: SharedLog,
+    /// Held until every handle on the file, clones included, is dropped
+    lock: Arc<FileLock>,
+    merge_operator: Option<MergeOperator>,
+    /// Deadlines of entries added with a TTL, kept in memory only: they are
+    /// not written to the file and don't survive reopening it
+    expiry: Arc<Mutex<Expiry>>,
+    watchers: ChangeBroadcaster,
+    trees: TreeRegistry<CsvDatabase>,
 }
 
 impl Clone for CsvDatabase {
+    /// Another handle on the same tree, sharing its data, log and file, so
+    /// writes through either one reach the file together
     fn clone(&self) -> Self {
         Self {
-            path: self.path.clone(),
-            data: Mutex::new(self.data.lock().unwrap().clone()),
+            merge_operator: self.merge_operator.clone(),
+            ..self.share(&self.trees)
         }
     }
 }
 
-#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
-#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
-impl Database for CsvDatabase {
-    async fn open(path: &str) -> Result<Self> {
-        let mut data = BTreeMap::new();
+impl CsvDatabase {
+    /// A new handle on the same tree as `self`, within the database owning `trees`
+    fn share(&self, trees: &TreeRegistry<CsvDatabase>) -> Self {
+        Self {
+            path: self.path.clone(),
+            options: self.options,
+            data: Arc::clone(&self.data),
+            wal: self.wal.clone(),
+            lock: Arc::clone(&self.lock),
+            merge_operator: None,
+            expiry: Arc::clone(&self.expiry),
+            watchers: self.watchers.clone(),
+            trees: Arc::clone(trees),
+        }
+    }
+
+    /// Drop every entry whose TTL has run out, returning how many were removed
+    fn evict_expired(&self, data: &mut BTreeMap<Vec<u8>, Vec<u8>>) -> usize {
+        let expired = self.expiry.lock().unwrap().take_expired(now_millis());
+        expired
+            .iter()
+            .filter(|key| data.remove(key.as_slice()).is_some())
+            .inspect(|key| self.watchers.publish(key, None))
+            .count()
+    }
+
+    /// Lock the data for an operation, so expired entries are never observed
+    fn lock_data(&self) -> MutexGuard<'_, BTreeMap<Vec<u8>, Vec<u8>>> {
+        let mut data = self.data.lock().unwrap();
+        self.evict_expired(&mut data);
+        data
+    }
+
+    /// Write every entry to the file, which then holds everything logged
+    fn persist(&self, data: &BTreeMap<Vec<u8>, Vec<u8>>) -> Result<()> {
+        self.lock.check_held()?;
+        // Encode everything before touching the file, so a value the encoding
+        // can't hold leaves the previous generation in place
+        let mut contents = String::new();
+        if self.options.header {
+            contents.push_str("key,value\r\n");
+        }
+        for (key, value) in data.iter() {
+            write_field(&mut contents, &self.options.encoding.encode(key)?);
+            contents.push(',');
+            write_field(&mut contents, &self.options.encoding.encode(value)?);
+            contents.push_str("\r\n");
+        }
+
+        write_file(Path::new(&self.path), contents.as_bytes())?;
+        wal::checkpoint(&self.wal, Path::new(&self.path))
+    }
+
+    /// Check a write may be made and log it ahead in WAL mode. Writes that
+    /// change nothing are let through in read-only mode.
+    fn log_write(&self, batch: &WriteBatch) -> Result<()> {
+        if self.options.read_only && !batch.is_empty() {
+            bail!(DatabaseError::ReadOnly(
+                "the database was opened read-only".to_string()
+            ));
+        }
+        if !batch.is_empty() {
+            self.lock.check_held()?;
+        }
+        // Refuse what text can't hold now rather than on the next flush
+        if self.options.encoding == CsvEncoding::Text {
+            for op in batch.ops() {
+                if let BatchOp::Put { key, value } = op {
+                    CsvEncoding::Text.encode(key)?;
+                    CsvEncoding::Text.encode(value)?;
+                }
+            }
+        }
+        wal::append(&self.wal, batch)
+    }
 
-        if Path::new(path).exists() {
-            let file = File::open(path)?;
-            let reader = BufReader::new(file);
+    /// Fold the write-ahead log into the file once it has grown long enough,
+    /// without failing the write that made it due
+    fn compact_if_due(&self, data: &BTreeMap<Vec<u8>, Vec<u8>>) {
+        wal::compact_if_due(&self.wal, Path::new(&self.path), || self.persist(data));
+    }
 
-            for line in reader.lines() {
-                let line = line?;
-                let parts: Vec<&str> = line.split(',').collect();
-                if parts.len() == 2 {
-                    data.insert(parts[0].as_bytes().to_vec(), parts[1].as_bytes().to_vec());
+    /// Open the file at `path`, laid out as `options` describes
+    pub async fn open_with(path: &str, options: CsvOptions) -> Result<Self> {
+        let lock = FileLock::acquire(Path::new(path), options.read_only)?;
+        let source = recover_file(Path::new(path), !options.read_only)?;
+        let mut data = BTreeMap::new();
+
+        if source.exists() {
+            let contents = fs::read_to_string(&source)?;
+            let skip = usize::from(options.header);
+            let records = match parse_records(&contents) {
+                Ok(records) if well_formed(&records, skip) => records,
+                // Text files from before quoting was added hold raw fields,
+                // which are read the way they were written; the next flush
+                // rewrites the file quoted
+                _ if options.encoding == CsvEncoding::Text => parse_legacy(&contents),
+                records => records?,
+            };
+            for (i, record) in records.into_iter().enumerate().skip(skip) {
+                match record.as_slice() {
+                    [key, value] => {
+                        let key = options.encoding.decode(key)?;
+                        data.insert(key, options.encoding.decode(value)?);
+                    }
+                    // Blank lines hold a single empty field
+                    [field] if field.is_empty() => {}
+                    _ => bail!(DatabaseError::Corruption(format!(
+                        "record {} of {} has {} fields instead of 2",
+                        i + 1,
+                        path,
+                        record.len()
+                    ))),
                 }
             }
         }
+        let wal = wal::recover(
+            Path::new(path),
+            &mut data,
+            options.wal.filter(|_| !options.read_only),
+            |value| Ok(value.to_vec()),
+        )?;
 
         Ok(Self {
             path: path.to_string(),
-            data: Mutex::new(data),
+            options,
+            data: Arc::new(Mutex::new(data)),
+            wal,
+            lock,
+            merge_operator: None,
+            expiry: Arc::default(),
+            watchers: ChangeBroadcaster::default(),
+            trees: TreeRegistry::default(),
         })
     }
+}
 
-    async fn close(&mut self) -> Result<()> {
-        let mut file = OpenOptions::new()
-            .write(true)
-            .create(true)
-            .truncate(true)
-            .open(&self.path)?;
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl Database for CsvDatabase {
+    async fn open(path: &str) -> Result<Self> {
+        Self::open_with(path, CsvOptions::default()).await
+    }
 
-        let data = self.data.lock().unwrap();
-        for (key, value) in data.iter() {
-            let key_str = String::from_utf8_lossy(key);
-            let value_str = String::from_utf8_lossy(value);
-            writeln!(file, "{},{}", key_str, value_str)?;
+    async fn close(&mut self) -> Result<()> {
+        if !self.lock.is_held() {
+            return Ok(());
         }
-
+        self.flush().await?;
+        // Let the file be opened again, from this process or another one
+        self.lock.release(self.options.read_only);
         Ok(())
     }
 
     async fn add(&mut self, key: &[u8], value: &[u8]) -> Result<()> {
-        self.data
-            .lock()
-            .unwrap()
-            .insert(key.to_vec(), value.to_vec());
+        let mut data = self.lock_data();
+        self.log_write(WriteBatch::new().put(key, value))?;
+        self.expiry.lock().unwrap().clear(key);
+        self.watchers.publish(key, Some(value));
+        data.insert(key.to_vec(), value.to_vec());
+        self.compact_if_due(&data);
         Ok(())
     }
 
     async fn select(&self, key: &[u8]) -> Result<Option<Vec<u8>>> {
-        Ok(self.data.lock().unwrap().get(key).cloned())
+        Ok(self.lock_data().get(key).cloned())
     }
 
     async fn remove(&mut self, key: &[u8]) -> Result<()> {
-        self.data.lock().unwrap().remove(key);
+        let mut data = self.lock_data();
+        if !data.contains_key(key) {
+            return Ok(());
+        }
+        self.log_write(WriteBatch::new().delete(key))?;
+        data.remove(key);
+        self.watchers.publish(key, None);
+        self.compact_if_due(&data);
         Ok(())
     }
 
@@ -84,7 +430,7 @@ impl Database for CsvDatabase {
         let mut result = Vec::new();
         let start_vec = start.to_vec();
         let end_vec = end.to_vec();
-        let data = self.data.lock().unwrap();
+        let data = self.lock_data();
         for (key, value) in data.iter() {
             if key >= &start_vec && key < &end_vec {
                 result.push((key.clone(), value.clone()));
@@ -97,7 +443,7 @@ impl Database for CsvDatabase {
         let mut result = Vec::new();
         let start_vec = start.to_vec();
         let end_vec = end.to_vec();
-        let mut data = self.data.lock().unwrap();
+        let mut data = self.lock_data();
 
         // Collect keys to remove and their values
         let keys_to_remove: Vec<Vec<u8>> = data
@@ -108,31 +454,224 @@ impl Database for CsvDatabase {
                 key.clone()
             })
             .collect();
+        self.log_write(&wal::removal_batch(&keys_to_remove))?;
 
         // Remove the collected keys
         for key in keys_to_remove {
             data.remove(&key);
         }
+        self.watchers.publish_removed(&result);
+        self.compact_if_due(&data);
 
         Ok(result)
     }
 
+    async fn scan_prefix(&self, prefix: &[u8]) -> Result<Vec<(Vec<u8>, Vec<u8>)>> {
+        let data = self.lock_data();
+        Ok(data
+            .range(prefix_bounds(prefix))
+            .map(|(key, value)| (key.clone(), value.clone()))
+            .collect())
+    }
+
+    async fn remove_prefix(&self, prefix: &[u8]) -> Result<Vec<(Vec<u8>, Vec<u8>)>> {
+        let mut data = self.lock_data();
+        let keys: Vec<Vec<u8>> = data
+            .range(prefix_bounds(prefix))
+            .map(|(key, _)| key.clone())
+            .collect();
+        self.log_write(&wal::removal_batch(&keys))?;
+        let removed: Vec<(Vec<u8>, Vec<u8>)> = keys
+            .into_iter()
+            .filter_map(|key| data.remove_entry(&key))
+            .collect();
+        self.watchers.publish_removed(&removed);
+        self.compact_if_due(&data);
+        Ok(removed)
+    }
+
     async fn flush(&mut self) -> Result<()> {
-        let mut file = OpenOptions::new()
-            .write(true)
-            .create(true)
-            .truncate(true)
-            .open(&self.path)?;
+        // Nothing can have changed in read-only mode
+        if self.options.read_only {
+            return Ok(());
+        }
+        self.persist(&self.lock_data())
+    }
 
-        let data = self.data.lock().unwrap();
-        for (key, value) in data.iter() {
-            let key_str = String::from_utf8_lossy(key);
-            let value_str = String::from_utf8_lossy(value);
-            writeln!(file, "{},{}", key_str, value_str)?;
+    async fn transaction<F, R>(&mut self, f: F) -> Result<R>
+    where
+        F: Fn(&mut dyn Transaction) -> Result<R> + Send,
+        R: Send,
+    {
+        let mut data = self.lock_data();
+        let read = |key: &[u8]| Ok(data.get(key).cloned());
+        let mut tx = OverlayTransaction::new(&read);
+        let result = f(&mut tx)?;
+        let writes = tx.into_writes();
+        self.log_write(&wal::writes_batch(&writes))?;
+
+        // Only touch the map once the closure has succeeded
+        let mut expiry = self.expiry.lock().unwrap();
+        for (key, value) in writes {
+            match value {
+                Some(value) => {
+                    expiry.clear(&key);
+                    self.watchers.publish(&key, Some(&value));
+                    data.insert(key, value)
+                }
+                None => {
+                    self.watchers.publish(&key, None);
+                    data.remove(&key)
+                }
+            };
         }
+        self.compact_if_due(&data);
 
+        Ok(result)
+    }
+
+    async fn compare_and_swap(
+        &mut self,
+        key: &[u8],
+        expected: Option<&[u8]>,
+        new: Option<&[u8]>,
+    ) -> Result<std::result::Result<(), CompareAndSwapError>> {
+        self.transaction(|tx| compare_and_swap_in(tx, key, expected, new))
+            .await
+    }
+
+    async fn increment(&mut self, key: &[u8], delta: i64) -> Result<i64> {
+        self.transaction(|tx| increment_in(tx, key, delta)).await
+    }
+
+    fn set_merge_operator<F>(&mut self, operator: F)
+    where
+        F: Fn(&[u8], Option<&[u8]>, &[u8]) -> Vec<u8> + Send + Sync + 'static,
+    {
+        self.merge_operator = Some(Arc::new(operator));
+    }
+
+    async fn merge(&mut self, key: &[u8], operand: &[u8]) -> Result<()> {
+        let operator = self.merge_operator.clone();
+        self.transaction(|tx| merge_in(tx, operator.as_ref(), key, operand))
+            .await
+    }
+
+    async fn add_with_ttl(&mut self, key: &[u8], value: &[u8], ttl: Duration) -> Result<()> {
+        let mut data = self.lock_data();
+        self.log_write(WriteBatch::new().put(key, value))?;
+        self.expiry.lock().unwrap().set(key, deadline_after(ttl));
+        self.watchers.publish(key, Some(value));
+        data.insert(key.to_vec(), value.to_vec());
+        self.compact_if_due(&data);
         Ok(())
     }
+
+    async fn ttl(&self, key: &[u8]) -> Result<Option<Duration>> {
+        if !self.lock_data().contains_key(key) {
+            return Ok(None);
+        }
+        let deadline = self.expiry.lock().unwrap().deadline(key);
+        Ok(deadline.and_then(remaining_until))
+    }
+
+    async fn purge_expired(&self) -> Result<usize> {
+        let mut data = self.data.lock().unwrap();
+        Ok(self.evict_expired(&mut data))
+    }
+
+    async fn open_tree(&self, name: &str) -> Result<Self> {
+        check_tree_name(name)?;
+        let opened = self
+            .trees
+            .lock()
+            .unwrap()
+            .get(name)
+            .map(|tree| tree.share(&self.trees));
+        if let Some(tree) = opened {
+            return Ok(tree);
+        }
+
+        // Each tree lives in a file of its own next to this one, written when
+        // its handle is flushed
+        let path = tree_file_path(&self.path, name);
+        let tree = Self::open_with(&path.to_string_lossy(), self.options).await?;
+        let mut trees = self.trees.lock().unwrap();
+        let tree = trees.entry(name.to_string()).or_insert(tree);
+        Ok(tree.share(&self.trees))
+    }
+
+    async fn list_trees(&self) -> Result<Vec<String>> {
+        let mut names = tree_files(&self.path)?;
+        names.extend(self.trees.lock().unwrap().keys().cloned());
+        names.sort();
+        names.dedup();
+        Ok(names)
+    }
+
+    async fn drop_tree(&self, name: &str) -> Result<bool> {
+        check_tree_name(name)?;
+        if self.options.read_only {
+            bail!(DatabaseError::ReadOnly(
+                "the database was opened read-only".to_string()
+            ));
+        }
+        let opened = self.trees.lock().unwrap().remove(name).is_some();
+        let stored = remove_file(&tree_file_path(&self.path, name))?;
+        Ok(opened || stored)
+    }
+
+    async fn write_batch(&mut self, batch: WriteBatch) -> Result<()> {
+        let written = batch.put_keys();
+        let mut data = self.lock_data();
+        let changes = if self.watchers.is_watched() {
+            batch.changes_in_map(&data)?
+        } else {
+            BTreeMap::new()
+        };
+        self.log_write(&batch)?;
+        batch.apply_to_map(&mut data, |value| Ok(value.to_vec()))?;
+
+        let mut expiry = self.expiry.lock().unwrap();
+        for key in written {
+            expiry.clear(&key);
+        }
+        self.watchers.publish_all(&changes);
+        self.compact_if_due(&data);
+        Ok(())
+    }
+
+    async fn select_range_with(
+        &self,
+        start: &[u8],
+        end: &[u8],
+        options: &ScanOptions,
+    ) -> Result<Vec<(Vec<u8>, Vec<u8>)>> {
+        let data = self.lock_data();
+        Ok(select_from_map(&data, start, end, options, |value| {
+            Some(value.clone())
+        }))
+    }
+
+    fn scan(&self, start: &[u8], end: &[u8]) -> KvStream<'_> {
+        Box::pin(chunked_scan(start, end, move |from, end, limit| {
+            let data = self.lock_data();
+            let chunk = data
+                .range(from..end)
+                .take(limit)
+                .map(|(key, value)| (key.clone(), value.clone()))
+                .collect();
+            future::ready(Ok(chunk))
+        }))
+    }
+
+    async fn snapshot(&self) -> Result<Box<dyn Snapshot>> {
+        Ok(Box::new(MapSnapshot::new(self.lock_data().clone())))
+    }
+
+    fn watch_prefix(&self, prefix: &[u8]) -> WatchStream {
+        self.watchers.subscribe(prefix)
+    }
 }
 
 // SAFETY: CsvDatabase is safe to share between threads because data access is protected by Mutex
@@ -1,5 +1,13 @@
+pub use crate::batch::{BatchOp, WriteBatch};
+pub use crate::error::DatabaseError;
+pub use crate::merge::MergeOperator;
+pub use crate::scan::{KvStream, ScanOptions};
+pub use crate::snapshot::Snapshot;
+pub use crate::transaction::{CompareAndSwapError, Transaction};
+pub use crate::watch::{WatchEvent, WatchStream};
 pub use anyhow::Result;
 use async_trait::async_trait;
+use std::time::Duration;
 
 #[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 #[cfg_attr(not(target_arch = "wasm32"), async_trait)]
@@ -28,6 +36,91 @@ pub trait Database: Send + Sync + Clone {
     /// Remove key-value pairs within a range [start, end)
     async fn remove_range(&self, start: &[u8], end: &[u8]) -> Result<Vec<(Vec<u8>, Vec<u8>)>>;
 
+    /// Select every key-value pair whose key starts with `prefix`, in key order
+    async fn scan_prefix(&self, prefix: &[u8]) -> Result<Vec<(Vec<u8>, Vec<u8>)>>;
+
+    /// Remove every key-value pair whose key starts with `prefix`
+    async fn remove_prefix(&self, prefix: &[u8]) -> Result<Vec<(Vec<u8>, Vec<u8>)>>;
+
+    /// Select key-value pairs within a range, applying the direction, bound
+    /// inclusivity, limit and resume key from `options`
+    async fn select_range_with(
+        &self,
+        start: &[u8],
+        end: &[u8],
+        options: &ScanOptions,
+    ) -> Result<Vec<(Vec<u8>, Vec<u8>)>>;
+
+    /// Stream key-value pairs within a range [start, end) in key order
+    /// without loading the whole range into memory
+    fn scan(&self, start: &[u8], end: &[u8]) -> KvStream<'_>;
+
+    /// Take a read-only view of the database as it is now, unaffected by
+    /// writes made afterwards. Backends without a native snapshot copy the
+    /// whole keyspace, so this is not meant for hot paths.
+    async fn snapshot(&self) -> Result<Box<dyn Snapshot>>;
+
+    /// Stream every put or delete of a key starting with `prefix` made from now on,
+    /// in the order they were applied. Only writes through this handle or clones
+    /// sharing its data are seen, except on sled, which reports every writer.
+    fn watch_prefix(&self, prefix: &[u8]) -> WatchStream;
+
     /// Flush any pending writes to disk to ensure data durability
     async fn flush(&mut self) -> Result<()>;
+
+    /// Run `f` as a single atomic unit: if it returns `Ok` every write it made
+    /// is committed together, otherwise none of them are applied.
+    /// The closure may be invoked more than once if the backend retries on conflict.
+    async fn transaction<F, R>(&mut self, f: F) -> Result<R>
+    where
+        F: Fn(&mut dyn Transaction) -> Result<R> + Send,
+        R: Send;
+
+    /// Atomically replace the value under `key` with `new` if it currently
+    /// equals `expected`, where `None` means absent on either side.
+    /// On a mismatch nothing is written and the current value is returned.
+    async fn compare_and_swap(
+        &mut self,
+        key: &[u8],
+        expected: Option<&[u8]>,
+        new: Option<&[u8]>,
+    ) -> Result<std::result::Result<(), CompareAndSwapError>>;
+
+    /// Apply every operation in `batch` atomically, in the order they were recorded
+    async fn write_batch(&mut self, batch: WriteBatch) -> Result<()>;
+
+    /// Atomically add `delta` to the counter under `key` and return the new value.
+    /// An absent key counts as 0; a value that isn't a counter is an error.
+    async fn increment(&mut self, key: &[u8], delta: i64) -> Result<i64>;
+
+    /// Register the operator used by `merge`, replacing any previous one.
+    /// Operators are not persisted and must be registered again after `open`.
+    fn set_merge_operator<F>(&mut self, operator: F)
+    where
+        F: Fn(&[u8], Option<&[u8]>, &[u8]) -> Vec<u8> + Send + Sync + 'static;
+
+    /// Combine `operand` with the value under `key` using the registered merge operator
+    async fn merge(&mut self, key: &[u8], operand: &[u8]) -> Result<()>;
+
+    /// Add a key-value pair that expires after `ttl`. Expired entries are hidden
+    /// from reads and dropped lazily; writing the key again through any other
+    /// method makes it permanent.
+    async fn add_with_ttl(&mut self, key: &[u8], value: &[u8], ttl: Duration) -> Result<()>;
+
+    /// Time left before `key` expires, `None` if it is absent or never expires
+    async fn ttl(&self, key: &[u8]) -> Result<Option<Duration>>;
+
+    /// Remove every expired entry, returning how many were dropped
+    async fn purge_expired(&self) -> Result<usize>;
+
+    /// Open the keyspace `name` within this database, creating it if needed.
+    /// The handle has the whole `Database` API, scoped to that keyspace.
+    async fn open_tree(&self, name: &str) -> Result<Self>;
+
+    /// Names of the keyspaces created with `open_tree`, in order
+    async fn list_trees(&self) -> Result<Vec<String>>;
+
+    /// Delete the keyspace `name` with everything in it, returning whether it
+    /// existed. Handles already open on it must not be used afterwards.
+    async fn drop_tree(&self, name: &str) -> Result<bool>;
 }
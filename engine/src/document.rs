@@ -0,0 +1,156 @@
+use crate::database::Result;
+use anyhow::{anyhow, bail};
+use serde_json::{Map, Value};
+use std::cmp::Ordering;
+use std::str::FromStr;
+
+/// How a `Filter` compares a document field with its value
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Comparison {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+/// Condition on one field of a JSON document, such as `/age >= 30`.
+/// Numbers compare by value, strings by their bytes and booleans with false
+/// first; any values can be tested for equality. Missing fields never match,
+/// nor do fields whose type can't be ordered against the value.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Filter {
+    /// JSON pointer (RFC 6901) to the field
+    pub pointer: String,
+    pub comparison: Comparison,
+    pub value: Value,
+}
+
+impl Filter {
+    pub fn new(pointer: &str, comparison: Comparison, value: impl Into<Value>) -> Self {
+        Self {
+            pointer: pointer.to_string(),
+            comparison,
+            value: value.into(),
+        }
+    }
+
+    /// Whether `document` satisfies the filter
+    pub fn matches(&self, document: &Value) -> bool {
+        let Some(field) = document.pointer(&self.pointer) else {
+            return false;
+        };
+        let ordering = match self.comparison {
+            Comparison::Eq => return equal(field, &self.value),
+            Comparison::Ne => return !equal(field, &self.value),
+            _ => compare(field, &self.value),
+        };
+        match (self.comparison, ordering) {
+            (Comparison::Lt, Some(ordering)) => ordering == Ordering::Less,
+            (Comparison::Le, Some(ordering)) => ordering != Ordering::Greater,
+            (Comparison::Gt, Some(ordering)) => ordering == Ordering::Greater,
+            (Comparison::Ge, Some(ordering)) => ordering != Ordering::Less,
+            _ => false,
+        }
+    }
+}
+
+/// Parses `<field> <op> <value>`, where the field is a JSON pointer or a
+/// dotted path like `address.city`, the op one of `==`, `!=`, `<`, `<=`, `>`,
+/// `>=`, and the value JSON or else a bare string: `age >= 30`,
+/// `/email == "ann@example.com"`, `city != Paris`.
+impl FromStr for Filter {
+    type Err = anyhow::Error;
+
+    fn from_str(filter: &str) -> Result<Self> {
+        const OPERATORS: [(&str, Comparison); 6] = [
+            ("==", Comparison::Eq),
+            ("!=", Comparison::Ne),
+            ("<=", Comparison::Le),
+            (">=", Comparison::Ge),
+            ("<", Comparison::Lt),
+            (">", Comparison::Gt),
+        ];
+        let (position, operator, comparison) = OPERATORS
+            .iter()
+            .filter_map(|(operator, comparison)| {
+                filter
+                    .find(operator)
+                    .map(|position| (position, *operator, *comparison))
+            })
+            // Of operators found at the same place the longest wins, so `<=`
+            // isn't read as `<`
+            .min_by_key(|(position, operator, _)| (*position, usize::MAX - operator.len()))
+            .ok_or_else(|| anyhow!("no comparison in filter {:?}", filter))?;
+
+        let field = filter[..position].trim();
+        let value = filter[position + operator.len()..].trim();
+        if field.is_empty() || value.is_empty() {
+            bail!("invalid filter {:?}", filter);
+        }
+        let value =
+            serde_json::from_str(value).unwrap_or_else(|_| Value::String(value.to_string()));
+        Ok(Self {
+            pointer: field_pointer(field),
+            comparison,
+            value,
+        })
+    }
+}
+
+/// JSON pointer for a field given as a pointer or a dotted path
+fn field_pointer(field: &str) -> String {
+    if field.starts_with('/') {
+        return field.to_string();
+    }
+    field
+        .split('.')
+        .map(|segment| format!("/{}", segment.replace('~', "~0").replace('/', "~1")))
+        .collect()
+}
+
+/// Whether two JSON values are equal, numbers comparing by value
+fn equal(left: &Value, right: &Value) -> bool {
+    match (left, right) {
+        (Value::Number(_), Value::Number(_)) => compare(left, right) == Some(Ordering::Equal),
+        _ => left == right,
+    }
+}
+
+/// Order of two numbers, strings or booleans, `None` for other values or
+/// values of different types
+fn compare(left: &Value, right: &Value) -> Option<Ordering> {
+    match (left, right) {
+        (Value::Number(left), Value::Number(right)) => match (left.as_i64(), right.as_i64()) {
+            (Some(left), Some(right)) => Some(left.cmp(&right)),
+            _ => left.as_f64()?.partial_cmp(&right.as_f64()?),
+        },
+        (Value::String(left), Value::String(right)) => Some(left.cmp(right)),
+        (Value::Bool(left), Value::Bool(right)) => Some(left.cmp(right)),
+        _ => None,
+    }
+}
+
+/// Apply a JSON merge patch (RFC 7396) to `target`: members of an object patch
+/// are merged in recursively, `null` members are removed, and any other patch
+/// replaces the target
+pub fn merge_patch(target: &mut Value, patch: &Value) {
+    let Value::Object(patch) = patch else {
+        *target = patch.clone();
+        return;
+    };
+    if !target.is_object() {
+        *target = Value::Object(Map::new());
+    }
+    let Value::Object(target) = target else {
+        unreachable!()
+    };
+    for (name, value) in patch {
+        if value.is_null() {
+            target.remove(name);
+        } else {
+            merge_patch(target.entry(name.as_str()).or_insert(Value::Null), value);
+        }
+    }
+}
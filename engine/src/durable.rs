@@ -0,0 +1,101 @@
+use crate::database::Result;
+use crate::wal::wal_path;
+use std::ffi::OsString;
+use std::fs::{self, File};
+use std::io::{ErrorKind, Write};
+use std::path::{Path, PathBuf};
+
+/// `path` with `suffix` appended to its file name: `data.json.tmp`
+pub(crate) fn sibling(path: &Path, suffix: &str) -> PathBuf {
+    let mut file_name = path.file_name().map(OsString::from).unwrap_or_default();
+    file_name.push(suffix);
+    path.with_file_name(file_name)
+}
+
+/// File a new generation is written to before it replaces `path`
+pub(crate) fn temp_path(path: &Path) -> PathBuf {
+    sibling(path, ".tmp")
+}
+
+/// File keeping the generation `path` held before its last rewrite
+pub(crate) fn backup_path(path: &Path) -> PathBuf {
+    sibling(path, ".bak")
+}
+
+/// Make sure renames within the directory of `path` are on disk
+#[cfg(unix)]
+fn sync_directory(path: &Path) -> Result<()> {
+    let directory = match path.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => parent,
+        _ => Path::new("."),
+    };
+    File::open(directory)?.sync_all()?;
+    Ok(())
+}
+
+#[cfg(not(unix))]
+fn sync_directory(_path: &Path) -> Result<()> {
+    Ok(())
+}
+
+fn remove_if_exists(path: &Path) -> Result<bool> {
+    match fs::remove_file(path) {
+        Ok(()) => Ok(true),
+        Err(e) if e.kind() == ErrorKind::NotFound => Ok(false),
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// Replace the contents of `path` without ever leaving it half written.
+/// The new generation is written and synced to the temp file first; the
+/// current file then becomes the backup and the temp file takes its place.
+pub(crate) fn write_file(path: &Path, contents: &[u8]) -> Result<()> {
+    let temp = temp_path(path);
+    let mut file = File::create(&temp)?;
+    file.write_all(contents)?;
+    file.sync_all()?;
+    drop(file);
+
+    if path.exists() {
+        let backup = backup_path(path);
+        // Renaming onto an existing file fails on some platforms
+        remove_if_exists(&backup)?;
+        fs::rename(path, &backup)?;
+    }
+    fs::rename(&temp, path)?;
+    sync_directory(path)
+}
+
+/// Undo the traces of a `write_file` cut short by a crash, to run before
+/// reading `path`, and return the file to read. Without `path` but with both
+/// a temp file and a backup, the crash came between the two renames, after
+/// the temp file was complete, so it is moved into place. Any other temp file
+/// may be partial and is removed, leaving the last generation written in
+/// full. Only the `writer`, which holds the exclusive lock, may touch the
+/// files; other handles are pointed at the complete generation instead.
+pub(crate) fn recover_file(path: &Path, writer: bool) -> Result<PathBuf> {
+    let temp = temp_path(path);
+    if !temp.exists() {
+        return Ok(path.to_path_buf());
+    }
+    let renamed = !path.exists() && backup_path(path).exists();
+    if !writer {
+        return Ok(if renamed { temp } else { path.to_path_buf() });
+    }
+    if renamed {
+        fs::rename(&temp, path)?;
+    } else {
+        fs::remove_file(&temp)?;
+    }
+    sync_directory(path)?;
+    Ok(path.to_path_buf())
+}
+
+/// Delete `path` along with its temp file, backup and write-ahead log,
+/// returning whether `path` existed
+pub(crate) fn remove_file(path: &Path) -> Result<bool> {
+    remove_if_exists(&temp_path(path))?;
+    remove_if_exists(&backup_path(path))?;
+    remove_if_exists(&wal_path(path))?;
+    remove_if_exists(path)
+}
@@ -0,0 +1,118 @@
+use std::fmt;
+use std::io;
+
+/// Kind of failure a database operation ran into, for callers that handle
+/// some of them differently. Backends raise it inside their `anyhow` errors;
+/// `DatabaseError::of` finds it again, and the RPC protocol carries it, so a
+/// remote store fails the same way as a local one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DatabaseError {
+    /// Something the operation needs, such as an index, does not exist
+    NotFound(String),
+    /// Another handle got in the way, such as a process holding the lock
+    Conflict(String),
+    /// The request can't be carried out as given, such as incrementing a
+    /// value that isn't a counter
+    InvalidArgument(String),
+    /// Reading or writing the underlying storage failed
+    Io(String),
+    /// Stored data could not be decoded
+    Corruption(String),
+    /// A write to a database opened read-only
+    ReadOnly(String),
+    /// The backend does not offer the operation
+    Unsupported(String),
+    /// Any other failure
+    Other(String),
+}
+
+impl DatabaseError {
+    /// Kind of `error`: the `DatabaseError` it was raised with, an I/O error
+    /// it was caused by, or `Other`
+    pub fn of(error: &anyhow::Error) -> DatabaseError {
+        for cause in error.chain() {
+            if let Some(e) = cause.downcast_ref::<DatabaseError>() {
+                return e.clone();
+            }
+            if let Some(e) = cause.downcast_ref::<io::Error>() {
+                return match e.kind() {
+                    io::ErrorKind::NotFound => DatabaseError::NotFound(error.to_string()),
+                    _ => DatabaseError::Io(error.to_string()),
+                };
+            }
+        }
+        DatabaseError::Other(error.to_string())
+    }
+
+    pub fn message(&self) -> &str {
+        match self {
+            DatabaseError::NotFound(message)
+            | DatabaseError::Conflict(message)
+            | DatabaseError::InvalidArgument(message)
+            | DatabaseError::Io(message)
+            | DatabaseError::Corruption(message)
+            | DatabaseError::ReadOnly(message)
+            | DatabaseError::Unsupported(message)
+            | DatabaseError::Other(message) => message,
+        }
+    }
+}
+
+impl fmt::Display for DatabaseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message())
+    }
+}
+
+impl std::error::Error for DatabaseError {}
+
+/// Start of the reason of an RPC exception raised with a `DatabaseError`
+#[cfg(not(target_arch = "wasm32"))]
+const REASON_PREFIX: &str = "versedb-error ";
+
+#[cfg(not(target_arch = "wasm32"))]
+impl DatabaseError {
+    /// Exception answering a call that failed with `self`, its reason
+    /// `versedb-error <code>: <message>`
+    pub(crate) fn to_rpc(&self) -> capnp::Error {
+        use crate::versedb_capnp::ErrorCode;
+
+        let code = match self {
+            DatabaseError::NotFound(_) => ErrorCode::NotFound,
+            DatabaseError::Conflict(_) => ErrorCode::Conflict,
+            DatabaseError::InvalidArgument(_) => ErrorCode::InvalidArgument,
+            DatabaseError::Io(_) => ErrorCode::Io,
+            DatabaseError::Corruption(_) => ErrorCode::Corruption,
+            DatabaseError::ReadOnly(_) => ErrorCode::ReadOnly,
+            DatabaseError::Unsupported(_) => ErrorCode::Unsupported,
+            DatabaseError::Other(_) => ErrorCode::Other,
+        };
+        capnp::Error::failed(format!(
+            "{}{}: {}",
+            REASON_PREFIX,
+            u16::from(code),
+            self.message()
+        ))
+    }
+
+    /// The `DatabaseError` a server raised `error` with, if it came from one.
+    /// A code this side doesn't know reads as `Other`.
+    pub(crate) fn from_rpc(error: &capnp::Error) -> Option<DatabaseError> {
+        use crate::versedb_capnp::ErrorCode;
+
+        // The receiving side puts its own words in front of the reason
+        let (_, reason) = error.extra.split_once(REASON_PREFIX)?;
+        let (code, message) = reason.split_once(": ")?;
+        let message = message.to_string();
+        Some(match ErrorCode::try_from(code.parse::<u16>().ok()?) {
+            Ok(ErrorCode::NotFound) => DatabaseError::NotFound(message),
+            Ok(ErrorCode::Conflict) => DatabaseError::Conflict(message),
+            Ok(ErrorCode::InvalidArgument) => DatabaseError::InvalidArgument(message),
+            Ok(ErrorCode::Io) => DatabaseError::Io(message),
+            Ok(ErrorCode::Corruption) => DatabaseError::Corruption(message),
+            Ok(ErrorCode::ReadOnly) => DatabaseError::ReadOnly(message),
+            Ok(ErrorCode::Unsupported) => DatabaseError::Unsupported(message),
+            Ok(ErrorCode::Other) | Err(_) => DatabaseError::Other(message),
+        })
+    }
+}
@@ -1,14 +1,30 @@
-use crate::database::{Database, Result};
+use crate::batch::{BatchOp, WriteBatch};
+use crate::database::{
+    CompareAndSwapError, Database, DatabaseError, KvStream, MergeOperator, Result, ScanOptions,
+    Transaction,
+};
+use crate::merge::{add_to_counter, encode_counter};
+use crate::scan::{chunked_scan, prefix_successor};
+use crate::snapshot::{MapSnapshot, Snapshot};
+use crate::transaction::OverlayTransaction;
+use crate::tree::{TreeRegistry, check_tree_name};
+use crate::ttl::{Expiry, deadline_after, fingerprint, now_millis, remaining_until};
+use crate::watch::{ChangeBroadcaster, WatchStream};
 use async_trait::async_trait;
 use js_sys::{Promise, Uint8Array};
+use std::cell::RefCell;
+use std::collections::BTreeMap;
 use std::error::Error;
 use std::fmt;
+use std::rc::Rc;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
 use wasm_bindgen::JsCast;
 use wasm_bindgen::JsValue;
 use wasm_bindgen::closure::Closure;
 use web_sys::{
-    DomException, DomStringList, IdbDatabase, IdbFactory, IdbObjectStore, IdbOpenDbRequest,
-    IdbRequest, IdbTransactionMode,
+    DomException, DomStringList, IdbCursorDirection, IdbCursorWithValue, IdbDatabase, IdbFactory,
+    IdbObjectStore, IdbOpenDbRequest, IdbRequest, IdbTransaction, IdbTransactionMode,
 };
 
 #[derive(Debug)]
@@ -38,105 +54,414 @@ impl From<JsValue> for JsError {
     }
 }
 
-#[derive(Debug, Clone)]
+/// Deadlines of entries added with a TTL, with a fingerprint of the value
+/// each was set for so entries overwritten since are left alone
+#[derive(Debug, Default)]
+struct TtlState {
+    expiry: Expiry,
+    fingerprints: BTreeMap<Vec<u8>, u64>,
+}
+
+/// Object store holding the entries outside any named tree
+const ROOT_STORE: &str = "store";
+
+/// Object stores prefixed with this hold the named trees, one per tree
+const TREE_STORE_PREFIX: &str = "tree:";
+
+/// State shared by every handle on a named tree
+#[derive(Clone, Default)]
+struct TreeState {
+    ttl: Arc<Mutex<TtlState>>,
+    watchers: ChangeBroadcaster,
+}
+
+#[derive(Clone)]
 pub struct IdbDatabaseWrapper {
-    db: IdbDatabase,
+    /// Shared by every handle, since creating or dropping a tree replaces the
+    /// connection with one opened at the next version
+    db: Arc<Mutex<IdbDatabase>>,
+    /// Object store holding this handle's entries
+    store: String,
+    merge_operator: Option<MergeOperator>,
+    /// Kept in memory only, so TTLs don't survive reopening the database
+    ttl: Arc<Mutex<TtlState>>,
+    watchers: ChangeBroadcaster,
+    trees: TreeRegistry<TreeState>,
+}
+
+impl fmt::Debug for IdbDatabaseWrapper {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("IdbDatabaseWrapper")
+            .field("db", &self.connection())
+            .field("store", &self.store)
+            .finish_non_exhaustive()
+    }
 }
 
 // SAFETY: IdbDatabase is safe to send between threads in WebAssembly
 unsafe impl Send for IdbDatabaseWrapper {}
 unsafe impl Sync for IdbDatabaseWrapper {}
 
-#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
-#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
-impl Database for IdbDatabaseWrapper {
-    async fn open(path: &str) -> Result<Self> {
-        let window = web_sys::window().ok_or_else(|| anyhow::anyhow!("Failed to get window"))?;
-        let factory: IdbFactory = window
-            .indexed_db()
-            .map_err(|e| JsError::from(e))?
-            .ok_or_else(|| anyhow::anyhow!("Failed to get IndexedDB"))?;
-
-        let open_request: IdbOpenDbRequest = factory
-            .open_with_u32(path, 1)
-            .map_err(|e| JsError::from(e))?;
+/// Resolve with the result of a single request once it succeeds
+async fn request_result(request: &IdbRequest) -> Result<JsValue> {
+    let promise = Promise::new(&mut |resolve, reject| {
+        let request_success = request.clone();
+        let on_success = Closure::<dyn FnMut(web_sys::Event)>::new(move |_| {
+            resolve
+                .call1(&JsValue::undefined(), &request_success.result().unwrap())
+                .unwrap();
+        });
 
-        // Create store if it doesn't exist
-        let callback = Closure::<dyn FnMut(web_sys::Event)>::new(move |event: web_sys::Event| {
-            let target = event.target().unwrap();
-            let open_request: IdbOpenDbRequest = target.dyn_into().unwrap();
-            let db = open_request
-                .result()
-                .unwrap()
-                .dyn_into::<IdbDatabase>()
+        let request_error = request.clone();
+        let on_error = Closure::<dyn FnMut(web_sys::Event)>::new(move |_| {
+            let error = request_error.error().unwrap();
+            reject
+                .call1(&JsValue::undefined(), &JsValue::from(error))
                 .unwrap();
+        });
 
-            let store_names: DomStringList = db.object_store_names();
-            let mut found = false;
-            for i in 0..store_names.length() {
-                if let Some(name) = store_names.get(i) {
-                    if name == "store" {
-                        found = true;
-                        break;
-                    }
-                }
-            }
-            if !found {
-                db.create_object_store("store").unwrap();
-            }
+        request.set_onsuccess(Some(on_success.as_ref().unchecked_ref()));
+        request.set_onerror(Some(on_error.as_ref().unchecked_ref()));
+
+        on_success.forget();
+        on_error.forget();
+    });
+
+    let result = wasm_bindgen_futures::JsFuture::from(promise)
+        .await
+        .map_err(|e| JsError::from(e))?;
+    Ok(result)
+}
+
+/// Resolve once a readwrite transaction commits, or fail if it aborts
+async fn wait_for_transaction(tx: &IdbTransaction) -> Result<()> {
+    let promise = Promise::new(&mut |resolve, reject| {
+        let on_complete = Closure::<dyn FnMut(web_sys::Event)>::new(move |_| {
+            resolve
+                .call1(&JsValue::undefined(), &JsValue::undefined())
+                .unwrap();
+        });
+
+        let tx_error = tx.clone();
+        let on_abort = Closure::<dyn FnMut(web_sys::Event)>::new(move |_| {
+            let error = tx_error.error().map(JsValue::from).unwrap_or(JsValue::NULL);
+            reject.call1(&JsValue::undefined(), &error).unwrap();
         });
 
-        open_request.set_onupgradeneeded(Some(callback.as_ref().unchecked_ref()));
-        callback.forget(); // Prevent closure from being dropped
+        tx.set_oncomplete(Some(on_complete.as_ref().unchecked_ref()));
+        tx.set_onabort(Some(on_abort.as_ref().unchecked_ref()));
+        on_complete.forget();
+        on_abort.forget();
+    });
 
-        // Convert the request to a Promise and await it
-        let promise = Promise::new(&mut |resolve, _reject| {
-            let request_success = open_request.clone();
+    wasm_bindgen_futures::JsFuture::from(promise)
+        .await
+        .map_err(|e| JsError::from(e))?;
+    Ok(())
+}
+
+/// Whether `db` has an object store called `name`
+fn has_object_store(db: &IdbDatabase, name: &str) -> bool {
+    let store_names: DomStringList = db.object_store_names();
+    (0..store_names.length()).any(|i| store_names.get(i).as_deref() == Some(name))
+}
+
+/// Open a connection to the database `path`, at `version` or else the current
+/// one, running `upgrade` if that creates the database or moves it to a new
+/// version
+async fn open_connection(
+    path: &str,
+    version: Option<u32>,
+    upgrade: impl Fn(&IdbDatabase) + 'static,
+) -> Result<IdbDatabase> {
+    let window = web_sys::window().ok_or_else(|| anyhow::anyhow!("Failed to get window"))?;
+    let factory: IdbFactory = window
+        .indexed_db()
+        .map_err(|e| JsError::from(e))?
+        .ok_or_else(|| anyhow::anyhow!("Failed to get IndexedDB"))?;
+
+    let open_request: IdbOpenDbRequest = match version {
+        Some(version) => factory.open_with_u32(path, version),
+        None => factory.open(path),
+    }
+    .map_err(|e| JsError::from(e))?;
+
+    // Object stores can only be created or deleted during an upgrade
+    let callback = Closure::<dyn FnMut(web_sys::Event)>::new(move |event: web_sys::Event| {
+        let target = event.target().unwrap();
+        let open_request: IdbOpenDbRequest = target.dyn_into().unwrap();
+        let db = open_request
+            .result()
+            .unwrap()
+            .dyn_into::<IdbDatabase>()
+            .unwrap();
+        upgrade(&db);
+    });
+
+    open_request.set_onupgradeneeded(Some(callback.as_ref().unchecked_ref()));
+    callback.forget(); // Prevent closure from being dropped
+
+    let db = request_result(&open_request)
+        .await?
+        .dyn_into::<IdbDatabase>()
+        .map_err(|e| JsError::from(e))?;
+    Ok(db)
+}
+
+impl IdbDatabaseWrapper {
+    /// The connection currently shared by every handle on the database
+    fn connection(&self) -> IdbDatabase {
+        self.db.lock().unwrap().clone()
+    }
+
+    /// Replace the shared connection with one at the next version, running
+    /// `upgrade` to create or delete object stores
+    async fn upgrade(&self, upgrade: impl Fn(&IdbDatabase) + 'static) -> Result<()> {
+        let db = self.connection();
+        let name = db.name();
+        let version = db.version() as u32 + 1;
+        // The upgrade waits for every other connection to close
+        db.close();
+        let upgraded = open_connection(&name, Some(version), upgrade).await?;
+        *self.db.lock().unwrap() = upgraded;
+        Ok(())
+    }
+
+    /// Read up to `limit` entries within [start, end) with a cursor, walking
+    /// backwards from `end` when `reverse` is set. A missing `end` reads to the
+    /// last key.
+    /// IndexedDB commits a transaction as soon as it has no pending requests,
+    /// so a cursor can't stay open while the caller awaits other work; `scan`
+    /// instead opens a fresh cursor for each chunk.
+    async fn fetch_chunk(
+        &self,
+        start: Vec<u8>,
+        end: Option<Vec<u8>>,
+        limit: usize,
+        reverse: bool,
+    ) -> Result<Vec<(Vec<u8>, Vec<u8>)>> {
+        self.evict_expired().await?;
+
+        let tx = self
+            .connection()
+            .transaction_with_str_sequence_and_mode(
+                &js_sys::Array::of1(&JsValue::from_str(&self.store)),
+                IdbTransactionMode::Readonly,
+            )
+            .map_err(|e| JsError::from(e))?;
+
+        let store = tx.object_store(&self.store).map_err(|e| JsError::from(e))?;
+
+        let start_key = Uint8Array::from(start.as_slice());
+        let key_range = match end {
+            Some(end) => web_sys::IdbKeyRange::bound_with_lower_open_and_upper_open(
+                &start_key.into(),
+                &Uint8Array::from(end.as_slice()).into(),
+                false, // lower_open: false (inclusive)
+                true,  // upper_open: true (exclusive)
+            ),
+            None => web_sys::IdbKeyRange::lower_bound(&start_key.into()),
+        }
+        .map_err(|e| JsError::from(e))?;
+
+        let direction = if reverse {
+            IdbCursorDirection::Prev
+        } else {
+            IdbCursorDirection::Next
+        };
+        let request: IdbRequest = store
+            .open_cursor_with_range_and_direction(&key_range.into(), direction)
+            .map_err(|e| JsError::from(e))?;
+
+        let items = Rc::new(RefCell::new(Vec::new()));
+        let promise = Promise::new(&mut |resolve, reject| {
+            let request_success = request.clone();
+            let items = Rc::clone(&items);
             let on_success = Closure::<dyn FnMut(web_sys::Event)>::new(move |_| {
-                resolve
-                    .call1(&JsValue::undefined(), &request_success.result().unwrap())
-                    .unwrap();
+                let cursor = request_success
+                    .result()
+                    .ok()
+                    .and_then(|result| result.dyn_into::<IdbCursorWithValue>().ok());
+
+                // A null result means the cursor ran past the end of the range
+                let Some(cursor) = cursor else {
+                    resolve
+                        .call1(&JsValue::undefined(), &JsValue::undefined())
+                        .unwrap();
+                    return;
+                };
+
+                let key = Uint8Array::new(&cursor.key().unwrap()).to_vec();
+                let value = Uint8Array::new(&cursor.value().unwrap()).to_vec();
+                items.borrow_mut().push((key, value));
+
+                if items.borrow().len() >= limit {
+                    resolve
+                        .call1(&JsValue::undefined(), &JsValue::undefined())
+                        .unwrap();
+                } else {
+                    cursor.continue_().unwrap();
+                }
             });
 
-            let request_error = open_request.clone();
+            let request_error = request.clone();
             let on_error = Closure::<dyn FnMut(web_sys::Event)>::new(move |_| {
                 let error = request_error.error().unwrap();
-                _reject
+                reject
                     .call1(&JsValue::undefined(), &JsValue::from(error))
                     .unwrap();
             });
 
-            open_request.set_onsuccess(Some(on_success.as_ref().unchecked_ref()));
-            open_request.set_onerror(Some(on_error.as_ref().unchecked_ref()));
+            request.set_onsuccess(Some(on_success.as_ref().unchecked_ref()));
+            request.set_onerror(Some(on_error.as_ref().unchecked_ref()));
 
             on_success.forget();
             on_error.forget();
         });
 
-        let db = wasm_bindgen_futures::JsFuture::from(promise)
+        wasm_bindgen_futures::JsFuture::from(promise)
             .await
-            .map_err(|e| JsError::from(e))?
-            .dyn_into::<IdbDatabase>()
             .map_err(|e| JsError::from(e))?;
 
-        Ok(IdbDatabaseWrapper { db })
+        Ok(items.take())
+    }
+
+    /// Delete every entry whose TTL has run out, returning how many were removed
+    async fn evict_expired(&self) -> Result<usize> {
+        let expired: Vec<(Vec<u8>, Option<u64>)> = {
+            let mut state = self.ttl.lock().unwrap();
+            let keys = state.expiry.take_expired(now_millis());
+            keys.into_iter()
+                .map(|key| {
+                    let hash = state.fingerprints.remove(&key);
+                    (key, hash)
+                })
+                .collect()
+        };
+        if expired.is_empty() {
+            return Ok(0);
+        }
+
+        let tx = self
+            .connection()
+            .transaction_with_str_sequence_and_mode(
+                &js_sys::Array::of1(&JsValue::from_str(&self.store)),
+                IdbTransactionMode::Readwrite,
+            )
+            .map_err(|e| JsError::from(e))?;
+
+        let store = tx.object_store(&self.store).map_err(|e| JsError::from(e))?;
+
+        let mut evicted = Vec::new();
+        for (key, hash) in expired {
+            let key_js: JsValue = Uint8Array::from(key.as_slice()).into();
+            let request = store.get(&key_js).map_err(|e| JsError::from(e))?;
+            let value = request_result(&request).await?;
+            if value.is_undefined() {
+                continue;
+            }
+            if Some(fingerprint(&Uint8Array::new(&value).to_vec())) == hash {
+                store.delete(&key_js).map_err(|e| JsError::from(e))?;
+                evicted.push(key);
+            }
+        }
+
+        wait_for_transaction(&tx).await?;
+        for key in &evicted {
+            self.watchers.publish(key, None);
+        }
+        Ok(evicted.len())
+    }
+
+    /// Make `keys` permanent again, as every write does
+    fn clear_ttl<'a>(&self, keys: impl IntoIterator<Item = &'a [u8]>) {
+        let mut state = self.ttl.lock().unwrap();
+        for key in keys {
+            state.expiry.clear(key);
+            state.fingerprints.remove(key);
+        }
+    }
+
+    /// Replace the value under `key` with `update(current)` and return it,
+    /// reading and writing within one readwrite transaction
+    async fn update_value(
+        &self,
+        key: &[u8],
+        update: impl FnOnce(Option<&[u8]>) -> Result<Vec<u8>>,
+    ) -> Result<Vec<u8>> {
+        self.evict_expired().await?;
+
+        let tx = self
+            .connection()
+            .transaction_with_str_sequence_and_mode(
+                &js_sys::Array::of1(&JsValue::from_str(&self.store)),
+                IdbTransactionMode::Readwrite,
+            )
+            .map_err(|e| JsError::from(e))?;
+
+        let store = tx.object_store(&self.store).map_err(|e| JsError::from(e))?;
+        let key_js: JsValue = Uint8Array::from(key).into();
+
+        let request = store.get(&key_js).map_err(|e| JsError::from(e))?;
+        let value = request_result(&request).await?;
+        let current = if value.is_undefined() {
+            None
+        } else {
+            Some(Uint8Array::new(&value).to_vec())
+        };
+
+        let updated = update(current.as_deref())?;
+        store
+            .put_with_key(&Uint8Array::from(updated.as_slice()).into(), &key_js)
+            .map_err(|e| JsError::from(e))?;
+
+        wait_for_transaction(&tx).await?;
+        self.clear_ttl([key]);
+        self.watchers.publish(key, Some(&updated));
+        Ok(updated)
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl Database for IdbDatabaseWrapper {
+    async fn open(path: &str) -> Result<Self> {
+        // Opening without a version picks up whatever trees were created since
+        // the database was first opened
+        let db = open_connection(path, None, |db| {
+            if !has_object_store(db, ROOT_STORE) {
+                db.create_object_store(ROOT_STORE).unwrap();
+            }
+        })
+        .await?;
+
+        Ok(IdbDatabaseWrapper {
+            db: Arc::new(Mutex::new(db)),
+            store: ROOT_STORE.to_string(),
+            merge_operator: None,
+            ttl: Arc::default(),
+            watchers: ChangeBroadcaster::default(),
+            trees: TreeRegistry::default(),
+        })
     }
 
     async fn close(&mut self) -> Result<()> {
-        self.db.close();
+        self.connection().close();
         Ok(())
     }
 
     async fn add(&mut self, key: &[u8], value: &[u8]) -> Result<()> {
+        self.clear_ttl([key]);
+
         let tx = self
-            .db
+            .connection()
             .transaction_with_str_sequence_and_mode(
-                &js_sys::Array::of1(&JsValue::from_str("store")),
+                &js_sys::Array::of1(&JsValue::from_str(&self.store)),
                 IdbTransactionMode::Readwrite,
             )
             .map_err(|e| JsError::from(e))?;
 
-        let store = tx.object_store("store").map_err(|e| JsError::from(e))?;
+        let store = tx.object_store(&self.store).map_err(|e| JsError::from(e))?;
 
         let key_js = Uint8Array::from(key);
         let value_js = Uint8Array::from(value);
@@ -170,19 +495,22 @@ impl Database for IdbDatabaseWrapper {
             .await
             .map_err(|e| JsError::from(e))?;
 
+        self.watchers.publish(key, Some(value));
         Ok(())
     }
 
     async fn select(&self, key: &[u8]) -> Result<Option<Vec<u8>>> {
+        self.evict_expired().await?;
+
         let tx = self
-            .db
+            .connection()
             .transaction_with_str_sequence_and_mode(
-                &js_sys::Array::of1(&JsValue::from_str("store")),
+                &js_sys::Array::of1(&JsValue::from_str(&self.store)),
                 IdbTransactionMode::Readonly,
             )
             .map_err(|e| JsError::from(e))?;
 
-        let store = tx.object_store("store").map_err(|e| JsError::from(e))?;
+        let store = tx.object_store(&self.store).map_err(|e| JsError::from(e))?;
 
         let key_js = Uint8Array::from(key);
         let request: IdbRequest = store.get(&key_js.into()).map_err(|e| JsError::from(e))?;
@@ -226,14 +554,14 @@ impl Database for IdbDatabaseWrapper {
 
     async fn remove(&mut self, key: &[u8]) -> Result<()> {
         let tx = self
-            .db
+            .connection()
             .transaction_with_str_sequence_and_mode(
-                &js_sys::Array::of1(&JsValue::from_str("store")),
+                &js_sys::Array::of1(&JsValue::from_str(&self.store)),
                 IdbTransactionMode::Readwrite,
             )
             .map_err(|e| JsError::from(e))?;
 
-        let store = tx.object_store("store").map_err(|e| JsError::from(e))?;
+        let store = tx.object_store(&self.store).map_err(|e| JsError::from(e))?;
 
         let key_js = Uint8Array::from(key);
         let request: IdbRequest = store.delete(&key_js.into()).map_err(|e| JsError::from(e))?;
@@ -264,19 +592,22 @@ impl Database for IdbDatabaseWrapper {
             .await
             .map_err(|e| JsError::from(e))?;
 
+        self.watchers.publish(key, None);
         Ok(())
     }
 
     async fn select_range(&self, start: &[u8], end: &[u8]) -> Result<Vec<(Vec<u8>, Vec<u8>)>> {
+        self.evict_expired().await?;
+
         let tx = self
-            .db
+            .connection()
             .transaction_with_str_sequence_and_mode(
-                &js_sys::Array::of1(&JsValue::from_str("store")),
+                &js_sys::Array::of1(&JsValue::from_str(&self.store)),
                 IdbTransactionMode::Readonly,
             )
             .map_err(|e| JsError::from(e))?;
 
-        let store = tx.object_store("store").map_err(|e| JsError::from(e))?;
+        let store = tx.object_store(&self.store).map_err(|e| JsError::from(e))?;
 
         // Create a key range that is inclusive of start but exclusive of end
         let start_key = Uint8Array::from(start);
@@ -384,14 +715,14 @@ impl Database for IdbDatabaseWrapper {
         // Only create transaction if there are entries to remove
         if !entries.is_empty() {
             let tx = self
-                .db
+                .connection()
                 .transaction_with_str_sequence_and_mode(
-                    &js_sys::Array::of1(&JsValue::from_str("store")),
+                    &js_sys::Array::of1(&JsValue::from_str(&self.store)),
                     IdbTransactionMode::Readwrite,
                 )
                 .map_err(|e| JsError::from(e))?;
 
-            let store = tx.object_store("store").map_err(|e| JsError::from(e))?;
+            let store = tx.object_store(&self.store).map_err(|e| JsError::from(e))?;
 
             // Delete each key in the range
             for (key, _) in &entries {
@@ -446,6 +777,7 @@ impl Database for IdbDatabaseWrapper {
                 .map_err(|e| JsError::from(e))?;
         }
 
+        self.watchers.publish_removed(&entries);
         Ok(entries)
     }
 
@@ -453,4 +785,364 @@ impl Database for IdbDatabaseWrapper {
         // IndexedDB automatically persists data, no explicit flush needed
         Ok(())
     }
+
+    async fn transaction<F, R>(&mut self, f: F) -> Result<R>
+    where
+        F: Fn(&mut dyn Transaction) -> Result<R> + Send,
+        R: Send,
+    {
+        // IndexedDB requests are asynchronous, so reads can't be served from
+        // inside the synchronous closure; writes are buffered and then issued
+        // on a single readwrite transaction, which IndexedDB commits atomically
+        let read = |_: &[u8]| -> Result<Option<Vec<u8>>> {
+            Err(DatabaseError::Unsupported(
+                "reads inside a transaction are not supported by IndexedDB".to_string(),
+            )
+            .into())
+        };
+        let mut tx = OverlayTransaction::new(&read);
+        let result = f(&mut tx)?;
+        let writes = tx.into_writes();
+
+        if !writes.is_empty() {
+            let tx = self
+                .connection()
+                .transaction_with_str_sequence_and_mode(
+                    &js_sys::Array::of1(&JsValue::from_str(&self.store)),
+                    IdbTransactionMode::Readwrite,
+                )
+                .map_err(|e| JsError::from(e))?;
+
+            let store = tx.object_store(&self.store).map_err(|e| JsError::from(e))?;
+
+            for (key, value) in &writes {
+                let key_js = Uint8Array::from(key.as_slice());
+                match value {
+                    Some(value) => {
+                        let value_js = Uint8Array::from(value.as_slice());
+                        store
+                            .put_with_key(&value_js.into(), &key_js.into())
+                            .map_err(|e| JsError::from(e))?;
+                    }
+                    None => {
+                        store.delete(&key_js.into()).map_err(|e| JsError::from(e))?;
+                    }
+                }
+            }
+
+            wait_for_transaction(&tx).await?;
+            self.clear_ttl(writes.keys().map(Vec::as_slice));
+            self.watchers.publish_all(&writes);
+        }
+
+        Ok(result)
+    }
+
+    async fn compare_and_swap(
+        &mut self,
+        key: &[u8],
+        expected: Option<&[u8]>,
+        new: Option<&[u8]>,
+    ) -> Result<std::result::Result<(), CompareAndSwapError>> {
+        self.evict_expired().await?;
+
+        // Readwrite transactions on a store never overlap, so reading and
+        // writing within one makes the swap atomic
+        let tx = self
+            .connection()
+            .transaction_with_str_sequence_and_mode(
+                &js_sys::Array::of1(&JsValue::from_str(&self.store)),
+                IdbTransactionMode::Readwrite,
+            )
+            .map_err(|e| JsError::from(e))?;
+
+        let store = tx.object_store(&self.store).map_err(|e| JsError::from(e))?;
+        let key_js: JsValue = Uint8Array::from(key).into();
+
+        let request = store.get(&key_js).map_err(|e| JsError::from(e))?;
+        let value = request_result(&request).await?;
+        let current = if value.is_undefined() {
+            None
+        } else {
+            Some(Uint8Array::new(&value).to_vec())
+        };
+
+        if current.as_deref() != expected {
+            return Ok(Err(CompareAndSwapError { current }));
+        }
+
+        match new {
+            Some(value) => {
+                let value_js = Uint8Array::from(value);
+                store
+                    .put_with_key(&value_js.into(), &key_js)
+                    .map_err(|e| JsError::from(e))?;
+            }
+            None => {
+                store.delete(&key_js).map_err(|e| JsError::from(e))?;
+            }
+        }
+
+        wait_for_transaction(&tx).await?;
+        self.clear_ttl([key]);
+        self.watchers.publish(key, new);
+        Ok(Ok(()))
+    }
+
+    async fn increment(&mut self, key: &[u8], delta: i64) -> Result<i64> {
+        let mut result = 0;
+        self.update_value(key, |current| {
+            result = add_to_counter(current, delta)?;
+            Ok(encode_counter(result))
+        })
+        .await?;
+        Ok(result)
+    }
+
+    fn set_merge_operator<F>(&mut self, operator: F)
+    where
+        F: Fn(&[u8], Option<&[u8]>, &[u8]) -> Vec<u8> + Send + Sync + 'static,
+    {
+        self.merge_operator = Some(Arc::new(operator));
+    }
+
+    async fn merge(&mut self, key: &[u8], operand: &[u8]) -> Result<()> {
+        let Some(operator) = self.merge_operator.clone() else {
+            anyhow::bail!(DatabaseError::InvalidArgument(
+                "no merge operator registered".to_string()
+            ));
+        };
+        self.update_value(key, |current| Ok(operator(key, current, operand)))
+            .await?;
+        Ok(())
+    }
+
+    async fn add_with_ttl(&mut self, key: &[u8], value: &[u8], ttl: Duration) -> Result<()> {
+        self.add(key, value).await?;
+        let mut state = self.ttl.lock().unwrap();
+        state.expiry.set(key, deadline_after(ttl));
+        state.fingerprints.insert(key.to_vec(), fingerprint(value));
+        Ok(())
+    }
+
+    async fn ttl(&self, key: &[u8]) -> Result<Option<Duration>> {
+        let Some(value) = self.select(key).await? else {
+            return Ok(None);
+        };
+        let state = self.ttl.lock().unwrap();
+        if state.fingerprints.get(key) != Some(&fingerprint(&value)) {
+            return Ok(None);
+        }
+        Ok(state.expiry.deadline(key).and_then(remaining_until))
+    }
+
+    async fn purge_expired(&self) -> Result<usize> {
+        self.evict_expired().await
+    }
+
+    async fn open_tree(&self, name: &str) -> Result<Self> {
+        check_tree_name(name)?;
+        let store = format!("{}{}", TREE_STORE_PREFIX, name);
+        if !has_object_store(&self.connection(), &store) {
+            let created = store.clone();
+            self.upgrade(move |db| {
+                if !has_object_store(db, &created) {
+                    db.create_object_store(&created).unwrap();
+                }
+            })
+            .await?;
+        }
+
+        let tree = self
+            .trees
+            .lock()
+            .unwrap()
+            .entry(name.to_string())
+            .or_default()
+            .clone();
+        Ok(IdbDatabaseWrapper {
+            db: Arc::clone(&self.db),
+            store,
+            merge_operator: None,
+            ttl: tree.ttl,
+            watchers: tree.watchers,
+            trees: Arc::clone(&self.trees),
+        })
+    }
+
+    async fn list_trees(&self) -> Result<Vec<String>> {
+        let store_names = self.connection().object_store_names();
+        let mut names: Vec<String> = (0..store_names.length())
+            .filter_map(|i| store_names.get(i))
+            .filter_map(|store| Some(store.strip_prefix(TREE_STORE_PREFIX)?.to_string()))
+            .collect();
+        names.sort();
+        Ok(names)
+    }
+
+    async fn drop_tree(&self, name: &str) -> Result<bool> {
+        check_tree_name(name)?;
+        let store = format!("{}{}", TREE_STORE_PREFIX, name);
+        let existed = has_object_store(&self.connection(), &store);
+        if existed {
+            self.upgrade(move |db| {
+                if has_object_store(db, &store) {
+                    db.delete_object_store(&store).unwrap();
+                }
+            })
+            .await?;
+        }
+
+        self.trees.lock().unwrap().remove(name);
+        Ok(existed)
+    }
+
+    async fn write_batch(&mut self, batch: WriteBatch) -> Result<()> {
+        if batch.is_empty() {
+            return Ok(());
+        }
+
+        // Range deletes are reported key by key, so read the keys they cover
+        // before the batch is applied
+        let changes = if self.watchers.is_watched() {
+            let mut covered = BTreeMap::new();
+            for op in batch.ops() {
+                let BatchOp::DeleteRange { start, end } = op else {
+                    continue;
+                };
+                if start >= end {
+                    continue;
+                }
+                let entries = self
+                    .fetch_chunk(start.clone(), Some(end.clone()), usize::MAX, false)
+                    .await?;
+                let keys: Vec<Vec<u8>> = entries.into_iter().map(|(key, _)| key).collect();
+                covered.insert((start.clone(), end.clone()), keys);
+            }
+            batch.clone().resolve(|start, end| {
+                Ok(covered
+                    .get(&(start.to_vec(), end.to_vec()))
+                    .cloned()
+                    .unwrap_or_default())
+            })?
+        } else {
+            BTreeMap::new()
+        };
+
+        let tx = self
+            .connection()
+            .transaction_with_str_sequence_and_mode(
+                &js_sys::Array::of1(&JsValue::from_str(&self.store)),
+                IdbTransactionMode::Readwrite,
+            )
+            .map_err(|e| JsError::from(e))?;
+
+        let store = tx.object_store(&self.store).map_err(|e| JsError::from(e))?;
+
+        // Requests on one transaction run in order and commit together
+        for op in batch.ops() {
+            match op {
+                BatchOp::Put { key, value } => {
+                    let key_js = Uint8Array::from(key.as_slice());
+                    let value_js = Uint8Array::from(value.as_slice());
+                    store
+                        .put_with_key(&value_js.into(), &key_js.into())
+                        .map_err(|e| JsError::from(e))?;
+                }
+                BatchOp::Delete { key } => {
+                    let key_js = Uint8Array::from(key.as_slice());
+                    store.delete(&key_js.into()).map_err(|e| JsError::from(e))?;
+                }
+                BatchOp::DeleteRange { start, end } => {
+                    if start >= end {
+                        continue;
+                    }
+                    let key_range = web_sys::IdbKeyRange::bound_with_lower_open_and_upper_open(
+                        &Uint8Array::from(start.as_slice()).into(),
+                        &Uint8Array::from(end.as_slice()).into(),
+                        false,
+                        true,
+                    )
+                    .map_err(|e| JsError::from(e))?;
+                    store
+                        .delete(&key_range.into())
+                        .map_err(|e| JsError::from(e))?;
+                }
+            }
+        }
+
+        wait_for_transaction(&tx).await?;
+        // Keys a range delete removed stay without a value until a later
+        // write, which clears their TTL then
+        self.clear_ttl(batch.ops().iter().filter_map(|op| match op {
+            BatchOp::Put { key, .. } | BatchOp::Delete { key } => Some(key.as_slice()),
+            BatchOp::DeleteRange { .. } => None,
+        }));
+        self.watchers.publish_all(&changes);
+        Ok(())
+    }
+
+    async fn scan_prefix(&self, prefix: &[u8]) -> Result<Vec<(Vec<u8>, Vec<u8>)>> {
+        self.fetch_chunk(prefix.to_vec(), prefix_successor(prefix), usize::MAX, false)
+            .await
+    }
+
+    async fn remove_prefix(&self, prefix: &[u8]) -> Result<Vec<(Vec<u8>, Vec<u8>)>> {
+        let entries = self.scan_prefix(prefix).await?;
+
+        if !entries.is_empty() {
+            let tx = self
+                .connection()
+                .transaction_with_str_sequence_and_mode(
+                    &js_sys::Array::of1(&JsValue::from_str(&self.store)),
+                    IdbTransactionMode::Readwrite,
+                )
+                .map_err(|e| JsError::from(e))?;
+
+            let store = tx.object_store(&self.store).map_err(|e| JsError::from(e))?;
+            for (key, _) in &entries {
+                let key_js = Uint8Array::from(key.as_slice());
+                store.delete(&key_js.into()).map_err(|e| JsError::from(e))?;
+            }
+
+            wait_for_transaction(&tx).await?;
+        }
+
+        self.watchers.publish_removed(&entries);
+        Ok(entries)
+    }
+
+    async fn select_range_with(
+        &self,
+        start: &[u8],
+        end: &[u8],
+        options: &ScanOptions,
+    ) -> Result<Vec<(Vec<u8>, Vec<u8>)>> {
+        let (lower, upper) = options.bounds(start, end);
+        if lower >= upper {
+            return Ok(Vec::new());
+        }
+
+        self.fetch_chunk(lower, Some(upper), options.max_entries(), options.reverse)
+            .await
+    }
+
+    fn scan(&self, start: &[u8], end: &[u8]) -> KvStream<'_> {
+        Box::pin(chunked_scan(start, end, move |from, end, limit| {
+            self.fetch_chunk(from, Some(end), limit, false)
+        }))
+    }
+
+    async fn snapshot(&self) -> Result<Box<dyn Snapshot>> {
+        // A single readonly transaction sees one consistent state, but commits
+        // once idle, so copy everything out while it is open
+        let entries = self
+            .fetch_chunk(Vec::new(), None, usize::MAX, false)
+            .await?;
+        Ok(Box::new(MapSnapshot::new(entries.into_iter().collect())))
+    }
+
+    fn watch_prefix(&self, prefix: &[u8]) -> WatchStream {
+        self.watchers.subscribe(prefix)
+    }
 }
@@ -0,0 +1,274 @@
+use crate::batch::WriteBatch;
+use crate::database::{Database, DatabaseError, Result};
+use crate::scan::prefix_successor;
+use crate::transaction::Transaction;
+use crate::tuple::{Element, Tuple};
+use anyhow::bail;
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::ops::{Bound, RangeBounds};
+use std::sync::Arc;
+
+/// First byte of every index entry. Primary keys may not start with it, so
+/// the records are exactly the keys below it.
+const INDEX_SPACE: u8 = 0xff;
+
+/// Computes the values a record is indexed under from its key and value
+type Extractor = Arc<dyn Fn(&[u8], &[u8]) -> Vec<Element> + Send + Sync>;
+
+/// A `Database` with secondary indexes kept up to date by its writes.
+/// Each index maps the values its extractor returns for a record to that
+/// record's key, and is written in the same transaction as the record.
+/// Writes made directly on the database bypass the indexes; `rebuild_index`
+/// brings an index back in line with the records.
+pub struct IndexedDatabase<D> {
+    db: D,
+    indexes: BTreeMap<String, Extractor>,
+}
+
+impl<D: Database> IndexedDatabase<D> {
+    /// Index the records of `db`, with no indexes registered yet
+    pub fn new(db: D) -> Self {
+        Self {
+            db,
+            indexes: BTreeMap::new(),
+        }
+    }
+
+    /// The database holding the records and index entries
+    pub fn database(&self) -> &D {
+        &self.db
+    }
+
+    /// Give back the database the indexes were created over
+    pub fn into_inner(self) -> D {
+        self.db
+    }
+
+    /// Register the index `name`, replacing any previous one of that name.
+    /// `extractor` returns every value a record is found under, none to leave
+    /// it out of the index. Indexes are not persisted and must be registered
+    /// again after `open`; records written meanwhile need `rebuild_index`.
+    pub fn register_index<F>(&mut self, name: &str, extractor: F)
+    where
+        F: Fn(&[u8], &[u8]) -> Vec<Element> + Send + Sync + 'static,
+    {
+        self.indexes.insert(name.to_string(), Arc::new(extractor));
+    }
+
+    /// Names of the registered indexes, in order
+    pub fn index_names(&self) -> Vec<String> {
+        self.indexes.keys().cloned().collect()
+    }
+
+    fn extractor(&self, name: &str) -> Result<&Extractor> {
+        self.indexes
+            .get(name)
+            .ok_or_else(|| DatabaseError::NotFound(format!("no index named {:?}", name)).into())
+    }
+
+    /// Add a record, updating the entries of every index in the same transaction
+    pub async fn add(&mut self, key: &[u8], value: &[u8]) -> Result<()> {
+        if key.first() == Some(&INDEX_SPACE) {
+            bail!(DatabaseError::InvalidArgument(format!(
+                "keys starting with {:#04x} are reserved for indexes",
+                INDEX_SPACE
+            )));
+        }
+        let indexes = &self.indexes;
+        self.db
+            .transaction(|tx| {
+                remove_entries(tx, indexes, key)?;
+                for (name, extractor) in indexes {
+                    for entry in index_entries(name, extractor, key, value) {
+                        tx.put(&entry, &[])?;
+                    }
+                }
+                tx.put(key, value)
+            })
+            .await
+    }
+
+    /// Select a record by key
+    pub async fn select(&self, key: &[u8]) -> Result<Option<Vec<u8>>> {
+        self.db.select(key).await
+    }
+
+    /// Remove a record along with its index entries
+    pub async fn remove(&mut self, key: &[u8]) -> Result<()> {
+        let indexes = &self.indexes;
+        self.db
+            .transaction(|tx| {
+                remove_entries(tx, indexes, key)?;
+                tx.delete(key)
+            })
+            .await
+    }
+
+    /// Every record the index `name` holds under `value`, in key order
+    pub async fn find_by_index(
+        &self,
+        name: &str,
+        value: impl Into<Element>,
+    ) -> Result<Vec<(Vec<u8>, Vec<u8>)>> {
+        let extractor = self.extractor(name)?;
+        let prefix = index_key(&Tuple::new().push(name).push(value));
+        // The packed tuple holds a type code below 0xff, so it has a successor
+        let end = prefix_successor(&prefix).unwrap();
+        self.lookup(name, extractor, &prefix, &end).await
+    }
+
+    /// Every record the index `name` holds under a value within `range`,
+    /// ordered by value and then by key. A record found under several
+    /// values in the range is returned once for each.
+    pub async fn range_by_index(
+        &self,
+        name: &str,
+        range: impl RangeBounds<Element>,
+    ) -> Result<Vec<(Vec<u8>, Vec<u8>)>> {
+        let extractor = self.extractor(name)?;
+        let bound = |value: &Element| Tuple::new().push(name).push(value.clone()).range();
+        let (index_start, index_end) = Tuple::new().push(name).range();
+        let start = match range.start_bound() {
+            Bound::Included(value) => Tuple::new().push(name).push(value.clone()).pack(),
+            Bound::Excluded(value) => bound(value).1,
+            Bound::Unbounded => index_start,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(value) => bound(value).1,
+            Bound::Excluded(value) => Tuple::new().push(name).push(value.clone()).pack(),
+            Bound::Unbounded => index_end,
+        };
+        self.lookup(
+            name,
+            extractor,
+            &index_key_bytes(&start),
+            &index_key_bytes(&end),
+        )
+        .await
+    }
+
+    /// Records referenced by the entries of the index `name` within
+    /// [start, end). Entries and records are read one after the other, so
+    /// each record is checked to still be indexed under its entry; stale
+    /// entries, such as those left by a record written since or by writes
+    /// that bypassed the indexes, are skipped.
+    async fn lookup(
+        &self,
+        name: &str,
+        extractor: &Extractor,
+        start: &[u8],
+        end: &[u8],
+    ) -> Result<Vec<(Vec<u8>, Vec<u8>)>> {
+        let mut records = Vec::new();
+        for (entry, _) in self.db.select_range(start, end).await? {
+            let key = entry_record_key(&entry)?;
+            let value = self.db.select(&key).await?;
+            if let Some(value) =
+                value.filter(|value| index_entries(name, extractor, &key, value).contains(&entry))
+            {
+                records.push((key, value));
+            }
+        }
+        Ok(records)
+    }
+
+    /// Recompute the index `name` from every record, replacing its entries
+    /// in one batch, and return the number of entries written
+    pub async fn rebuild_index(&mut self, name: &str) -> Result<usize> {
+        let extractor = self.extractor(name)?;
+        let (start, end) = Tuple::new().push(name).range();
+        let mut batch = WriteBatch::new();
+        batch.delete_range(&index_key_bytes(&start), &index_key_bytes(&end));
+
+        let mut written = 0;
+        for (key, value) in self.db.select_range(&[], &[INDEX_SPACE]).await? {
+            for entry in index_entries(name, extractor, &key, &value) {
+                batch.put(&entry, &[]);
+                written += 1;
+            }
+        }
+        self.db.write_batch(batch).await?;
+        Ok(written)
+    }
+}
+
+/// Key of an index entry: the index space byte, then the packed tuple
+fn index_key(tuple: &Tuple) -> Vec<u8> {
+    index_key_bytes(&tuple.pack())
+}
+
+fn index_key_bytes(packed: &[u8]) -> Vec<u8> {
+    let mut key = Vec::with_capacity(packed.len() + 1);
+    key.push(INDEX_SPACE);
+    key.extend_from_slice(packed);
+    key
+}
+
+/// Entries of the index `name` for one record: `(name, value, key)` for every
+/// value the extractor returns
+fn index_entries(name: &str, extractor: &Extractor, key: &[u8], value: &[u8]) -> Vec<Vec<u8>> {
+    extractor(key, value)
+        .into_iter()
+        .map(|indexed| index_key(&Tuple::new().push(name).push(indexed).push(key)))
+        .collect()
+}
+
+/// Delete the entries every index holds for the record currently under `key`
+fn remove_entries(
+    tx: &mut dyn Transaction,
+    indexes: &BTreeMap<String, Extractor>,
+    key: &[u8],
+) -> Result<()> {
+    if let Some(old) = tx.get(key)? {
+        for (name, extractor) in indexes {
+            for entry in index_entries(name, extractor, key, &old) {
+                tx.delete(&entry)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Record key stored as the last element of an index entry
+fn entry_record_key(entry: &[u8]) -> Result<Vec<u8>> {
+    let tuple = Tuple::unpack(&entry[1..])?;
+    match tuple.into_elements().pop() {
+        Some(Element::Bytes(key)) => Ok(key),
+        _ => bail!(DatabaseError::Corruption(
+            "malformed index entry".to_string()
+        )),
+    }
+}
+
+/// Extractor indexing JSON values by the field at `pointer` (RFC 6901, like
+/// `/email`). Strings, numbers, booleans and null are indexed as they are and
+/// each of those in an array separately; records that aren't JSON or lack
+/// the field are left out.
+pub fn json_field(pointer: &str) -> impl Fn(&[u8], &[u8]) -> Vec<Element> + Send + Sync + 'static {
+    let pointer = pointer.to_string();
+    move |_, value| {
+        let Ok(document) = serde_json::from_slice::<Value>(value) else {
+            return Vec::new();
+        };
+        match document.pointer(&pointer) {
+            Some(Value::Array(items)) => items.iter().filter_map(json_element).collect(),
+            Some(field) => json_element(field).into_iter().collect(),
+            None => Vec::new(),
+        }
+    }
+}
+
+fn json_element(value: &Value) -> Option<Element> {
+    match value {
+        Value::Null => Some(Element::Null),
+        Value::Bool(bool) => Some(Element::Bool(*bool)),
+        Value::Number(number) => match (number.as_i64(), number.as_u64()) {
+            (Some(int), _) => Some(Element::Int(int.into())),
+            (_, Some(int)) => Some(Element::Int(int.into())),
+            _ => number.as_f64().map(Element::Double),
+        },
+        Value::String(string) => Some(Element::String(string.clone())),
+        Value::Array(_) | Value::Object(_) => None,
+    }
+}
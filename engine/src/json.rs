@@ -1,36 +1,150 @@
-use super::database::{Database, Result};
+use super::batch::{BatchOp, WriteBatch};
+use super::database::{
+    CompareAndSwapError, Database, DatabaseError, KvStream, MergeOperator, Result, ScanOptions,
+    Transaction,
+};
+use super::document::{Filter, merge_patch};
+use super::durable::{recover_file, remove_file, write_file};
+use super::lock::FileLock;
+use super::merge::{increment_in, merge_in};
+use super::scan::{chunked_scan, prefix_bounds, select_from_map};
+use super::snapshot::{MapSnapshot, Snapshot};
+use super::transaction::{OverlayTransaction, compare_and_swap_in};
+use super::tree::{TreeRegistry, check_tree_name, tree_file_path, tree_files};
+use super::ttl::{Expiry, deadline_after, now_millis, remaining_until};
+use super::wal::{self, SharedLog, WalOptions};
+use super::watch::{ChangeBroadcaster, WatchStream};
+use anyhow::bail;
 use async_trait::async_trait;
+use futures::future;
 use serde_json::{Map, Value};
 use std::collections::BTreeMap;
 use std::error::Error;
 use std::fs;
 use std::path::Path;
-use std::sync::Mutex;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::Duration;
+
+/// How a `JsonDatabase` stores its values and writes
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct JsonOptions {
+    /// Whether values are JSON documents rather than plain strings
+    pub documents: bool,
+    /// Log every write ahead of applying it, instead of only writing the
+    /// file on `flush`
+    pub wal: Option<WalOptions>,
+    /// Share the lock on the file with other read-only handles, even in
+    /// other processes, and refuse every write
+    pub read_only: bool,
+}
 
 pub struct JsonDatabase {
-    data: Mutex<BTreeMap<Vec<u8>, Value>>,
+    /// Shared with the other handles on the same tree
+    data: Arc<Mutex<BTreeMap<Vec<u8>, Value>>>,
     path: String,
+    options: JsonOptions,
+    /// Shared with the other handles on the same tree
+    wal: SharedLog,
+    /// Held until every handle on the file, clones included, is dropped
+    lock: Arc<FileLock>,
+    merge_operator: Option<MergeOperator>,
+    /// Deadlines of entries added with a TTL, kept in memory only: they are
+    /// not written to the file and don't survive reopening it
+    expiry: Arc<Mutex<Expiry>>,
+    watchers: ChangeBroadcaster,
+    trees: TreeRegistry<JsonDatabase>,
 }
 
 impl Clone for JsonDatabase {
+    /// Another handle on the same tree, sharing its data, log and file, so
+    /// writes through either one reach the file together
     fn clone(&self) -> Self {
         Self {
-            data: Mutex::new(self.data.lock().unwrap().clone()),
-            path: self.path.clone(),
+            merge_operator: self.merge_operator.clone(),
+            ..self.share(&self.trees)
         }
     }
 }
 
-#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
-#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
-impl Database for JsonDatabase {
-    async fn open(path: &str) -> Result<Self> {
-        let data = if Path::new(path).exists() {
-            let contents = fs::read_to_string(path)?;
+impl JsonDatabase {
+    /// A new handle on the same tree as `self`, within the database owning `trees`
+    fn share(&self, trees: &TreeRegistry<JsonDatabase>) -> Self {
+        Self {
+            path: self.path.clone(),
+            data: Arc::clone(&self.data),
+            options: self.options,
+            wal: self.wal.clone(),
+            lock: Arc::clone(&self.lock),
+            merge_operator: None,
+            expiry: Arc::clone(&self.expiry),
+            watchers: self.watchers.clone(),
+            trees: Arc::clone(trees),
+        }
+    }
+
+    /// Drop every entry whose TTL has run out, returning how many were removed
+    fn evict_expired(&self, data: &mut BTreeMap<Vec<u8>, Value>) -> usize {
+        let expired = self.expiry.lock().unwrap().take_expired(now_millis());
+        expired
+            .iter()
+            .filter(|key| data.remove(key.as_slice()).is_some())
+            .inspect(|key| self.watchers.publish(key, None))
+            .count()
+    }
+
+    /// Lock the data for an operation, so expired entries are never observed
+    fn lock_data(&self) -> MutexGuard<'_, BTreeMap<Vec<u8>, Value>> {
+        let mut data = self.data.lock().unwrap();
+        self.evict_expired(&mut data);
+        data
+    }
+
+    /// Bytes of a stored value as reads return them: the text of a string, or
+    /// the serialized document in document mode. Outside document mode, values
+    /// in the file that aren't strings are skipped.
+    fn value_bytes(&self, value: &Value) -> Option<Vec<u8>> {
+        if self.options.documents {
+            serde_json::to_vec(value).ok()
+        } else {
+            value.as_str().map(|s| s.as_bytes().to_vec())
+        }
+    }
+
+    /// Value stored for written bytes: their text as a string, or the
+    /// document they parse to in document mode
+    fn stored_value(&self, bytes: &[u8]) -> Result<Value> {
+        let value = if self.options.documents {
+            serde_json::from_slice(bytes).map_err(|e| e.to_string())
+        } else {
+            String::from_utf8(bytes.to_vec())
+                .map(Value::String)
+                .map_err(|e| e.to_string())
+        };
+        Ok(value.map_err(DatabaseError::InvalidArgument)?)
+    }
+
+    /// Open the file at `path` in document mode: values must be JSON and are
+    /// kept as documents, written to the file nested rather than as strings.
+    /// Every entry already in the file is read as a document.
+    pub async fn open_documents(path: &str) -> Result<Self> {
+        let options = JsonOptions {
+            documents: true,
+            ..JsonOptions::default()
+        };
+        Self::open_with(path, options).await
+    }
+
+    /// Open the file at `path` with the modes `options` turn on
+    pub async fn open_with(path: &str, options: JsonOptions) -> Result<Self> {
+        let lock = FileLock::acquire(Path::new(path), options.read_only)?;
+        let source = recover_file(Path::new(path), !options.read_only)?;
+        let data = if source.exists() {
+            let contents = fs::read_to_string(&source)?;
             if contents.trim().is_empty() {
                 BTreeMap::new()
             } else {
-                let json: Map<String, Value> = serde_json::from_str(&contents)?;
+                let json: Map<String, Value> = serde_json::from_str(&contents)
+                    .map_err(|e| DatabaseError::Corruption(e.to_string()))?;
                 let mut btree = BTreeMap::new();
 
                 for (key, value) in json {
@@ -43,44 +157,171 @@ impl Database for JsonDatabase {
             BTreeMap::new()
         };
 
-        Ok(JsonDatabase {
-            data: Mutex::new(data),
+        let mut db = JsonDatabase {
+            data: Arc::new(Mutex::new(data)),
             path: path.to_string(),
-        })
+            options,
+            wal: None,
+            lock,
+            merge_operator: None,
+            expiry: Arc::default(),
+            watchers: ChangeBroadcaster::default(),
+            trees: TreeRegistry::default(),
+        };
+        let wal = wal::recover(
+            Path::new(path),
+            &mut db.data.lock().unwrap(),
+            options.wal.filter(|_| !options.read_only),
+            |value| db.stored_value(value),
+        )?;
+        db.wal = wal;
+        Ok(db)
+    }
+
+    /// Write every entry to the file, which then holds everything logged
+    fn persist(&self, data: &BTreeMap<Vec<u8>, Value>) -> Result<()> {
+        self.lock.check_held()?;
+        let mut json_map = Map::new();
+
+        for (key, value) in data.iter() {
+            let key_str = String::from_utf8(key.clone())?;
+            json_map.insert(key_str, value.clone());
+        }
+
+        let json_string = serde_json::to_string_pretty(&json_map)?;
+        write_file(Path::new(&self.path), json_string.as_bytes())?;
+        wal::checkpoint(&self.wal, Path::new(&self.path))
+    }
+
+    /// Check a write may be made and log it ahead in WAL mode. Writes that
+    /// change nothing are let through in read-only mode.
+    fn log_write(&self, batch: &WriteBatch) -> Result<()> {
+        if self.options.read_only && !batch.is_empty() {
+            bail!(DatabaseError::ReadOnly(
+                "the database was opened read-only".to_string()
+            ));
+        }
+        if !batch.is_empty() {
+            self.lock.check_held()?;
+        }
+        wal::append(&self.wal, batch)
+    }
+
+    /// Fold the write-ahead log into the file once it has grown long enough,
+    /// without failing the write that made it due
+    fn compact_if_due(&self, data: &BTreeMap<Vec<u8>, Value>) {
+        wal::compact_if_due(&self.wal, Path::new(&self.path), || self.persist(data));
+    }
+
+    fn check_documents(&self) -> Result<()> {
+        if !self.options.documents {
+            bail!(DatabaseError::InvalidArgument(
+                "the database was not opened in document mode".to_string()
+            ));
+        }
+        Ok(())
+    }
+
+    /// The field at `pointer` (RFC 6901, like `/address/city`) in the document
+    /// under `key`, `None` if either is absent
+    pub async fn get_field(&self, key: &[u8], pointer: &str) -> Result<Option<Value>> {
+        self.check_documents()?;
+        Ok(self
+            .lock_data()
+            .get(key)
+            .and_then(|document| document.pointer(pointer))
+            .cloned())
+    }
+
+    /// Update the document under `key` with a JSON merge patch (RFC 7396),
+    /// starting from `null` if it is absent, and return the patched document
+    pub async fn patch(&mut self, key: &[u8], patch: &Value) -> Result<Value> {
+        self.check_documents()?;
+        let mut data = self.lock_data();
+        let mut document = data.get(key).cloned().unwrap_or(Value::Null);
+        merge_patch(&mut document, patch);
+
+        let bytes = serde_json::to_vec(&document)?;
+        self.log_write(WriteBatch::new().put(key, &bytes))?;
+        self.expiry.lock().unwrap().clear(key);
+        self.watchers.publish(key, Some(&bytes));
+        data.insert(key.to_vec(), document.clone());
+        self.compact_if_due(&data);
+        Ok(document)
+    }
+
+    /// Documents within the range [start, end) matching every one of
+    /// `filters`, in key order
+    pub async fn filter_range(
+        &self,
+        start: &[u8],
+        end: &[u8],
+        filters: &[Filter],
+    ) -> Result<Vec<(Vec<u8>, Value)>> {
+        self.check_documents()?;
+        if start >= end {
+            return Ok(Vec::new());
+        }
+        Ok(self
+            .lock_data()
+            .range(start.to_vec()..end.to_vec())
+            .filter(|(_, document)| filters.iter().all(|filter| filter.matches(document)))
+            .map(|(key, document)| (key.clone(), document.clone()))
+            .collect())
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl Database for JsonDatabase {
+    async fn open(path: &str) -> Result<Self> {
+        Self::open_with(path, JsonOptions::default()).await
     }
 
     async fn close(&mut self) -> Result<()> {
-        self.flush().await
+        if !self.lock.is_held() {
+            return Ok(());
+        }
+        self.flush().await?;
+        // Let the file be opened again, from this process or another one
+        self.lock.release(self.options.read_only);
+        Ok(())
     }
 
     async fn add(&mut self, key: &[u8], value: &[u8]) -> Result<()> {
-        let value_str = String::from_utf8(value.to_vec())?;
-        let value_json = Value::String(value_str);
-        self.data.lock().unwrap().insert(key.to_vec(), value_json);
+        let value_json = self.stored_value(value)?;
+        let mut data = self.lock_data();
+        self.log_write(WriteBatch::new().put(key, value))?;
+        self.expiry.lock().unwrap().clear(key);
+        self.watchers.publish(key, Some(value));
+        data.insert(key.to_vec(), value_json);
+        self.compact_if_due(&data);
         Ok(())
     }
 
     async fn select(&self, key: &[u8]) -> Result<Option<Vec<u8>>> {
-        Ok(self
-            .data
-            .lock()
-            .unwrap()
-            .get(key)
-            .and_then(|v| v.as_str().map(|s| s.as_bytes().to_vec())))
+        Ok(self.lock_data().get(key).and_then(|v| self.value_bytes(v)))
     }
 
     async fn remove(&mut self, key: &[u8]) -> Result<()> {
-        self.data.lock().unwrap().remove(key);
+        let mut data = self.lock_data();
+        if !data.contains_key(key) {
+            return Ok(());
+        }
+        self.log_write(WriteBatch::new().delete(key))?;
+        data.remove(key);
+        self.watchers.publish(key, None);
+        self.compact_if_due(&data);
         Ok(())
     }
 
     async fn select_range(&self, start: &[u8], end: &[u8]) -> Result<Vec<(Vec<u8>, Vec<u8>)>> {
         let mut result = Vec::new();
-        let data = self.data.lock().unwrap();
+        let data = self.lock_data();
 
         for (key, value) in data.range(start.to_vec()..end.to_vec()) {
-            if let Some(str_value) = value.as_str() {
-                result.push((key.clone(), str_value.as_bytes().to_vec()));
+            if let Some(bytes) = self.value_bytes(value) {
+                result.push((key.clone(), bytes));
             }
         }
 
@@ -89,41 +330,279 @@ impl Database for JsonDatabase {
 
     async fn remove_range(&self, start: &[u8], end: &[u8]) -> Result<Vec<(Vec<u8>, Vec<u8>)>> {
         let mut result = Vec::new();
-        let mut data = self.data.lock().unwrap();
+        let mut data = self.lock_data();
 
         let keys_to_remove: Vec<Vec<u8>> = data
             .range(start.to_vec()..end.to_vec())
-            .filter_map(|(key, value)| {
-                value
-                    .as_str()
-                    .map(|str_value| (key.clone(), str_value.as_bytes().to_vec()))
-            })
+            .filter_map(|(key, value)| Some((key.clone(), self.value_bytes(value)?)))
             .inspect(|pair| result.push(pair.clone()))
             .map(|(key, _)| key)
             .collect();
+        self.log_write(&wal::removal_batch(&keys_to_remove))?;
 
         // Remove the collected keys
         for key in keys_to_remove {
             data.remove(&key);
         }
+        self.watchers.publish_removed(&result);
+        self.compact_if_due(&data);
+
+        Ok(result)
+    }
+
+    async fn scan_prefix(&self, prefix: &[u8]) -> Result<Vec<(Vec<u8>, Vec<u8>)>> {
+        let data = self.lock_data();
+        Ok(data
+            .range(prefix_bounds(prefix))
+            .filter_map(|(key, value)| Some((key.clone(), self.value_bytes(value)?)))
+            .collect())
+    }
+
+    async fn remove_prefix(&self, prefix: &[u8]) -> Result<Vec<(Vec<u8>, Vec<u8>)>> {
+        let mut data = self.lock_data();
+        let result: Vec<(Vec<u8>, Vec<u8>)> = data
+            .range(prefix_bounds(prefix))
+            .filter_map(|(key, value)| Some((key.clone(), self.value_bytes(value)?)))
+            .collect();
+        let keys: Vec<Vec<u8>> = result.iter().map(|(key, _)| key.clone()).collect();
+        self.log_write(&wal::removal_batch(&keys))?;
+
+        for key in keys {
+            data.remove(&key);
+        }
+        self.watchers.publish_removed(&result);
+        self.compact_if_due(&data);
 
         Ok(result)
     }
 
     async fn flush(&mut self) -> Result<()> {
-        let mut json_map = Map::new();
-        let data = self.data.lock().unwrap();
+        // Nothing can have changed in read-only mode
+        if self.options.read_only {
+            return Ok(());
+        }
+        self.persist(&self.lock_data())
+    }
 
-        for (key, value) in data.iter() {
-            let key_str = String::from_utf8(key.clone())?;
-            json_map.insert(key_str, value.clone());
+    async fn transaction<F, R>(&mut self, f: F) -> Result<R>
+    where
+        F: Fn(&mut dyn Transaction) -> Result<R> + Send,
+        R: Send,
+    {
+        let mut data = self.lock_data();
+        let read = |key: &[u8]| Ok(data.get(key).and_then(|v| self.value_bytes(v)));
+        let mut tx = OverlayTransaction::new(&read);
+        let result = f(&mut tx)?;
+        let raw_writes = tx.into_writes();
+
+        // Validate every value before applying anything so an invalid write
+        // can't leave the transaction half applied
+        let mut writes = Vec::new();
+        for (key, value) in &raw_writes {
+            let value = match value {
+                Some(raw) => Some((self.stored_value(raw)?, raw)),
+                None => None,
+            };
+            writes.push((key, value));
         }
+        self.log_write(&wal::writes_batch(&raw_writes))?;
 
-        let json_string = serde_json::to_string_pretty(&json_map)?;
-        fs::write(&self.path, json_string)?;
+        let mut expiry = self.expiry.lock().unwrap();
+        for (key, value) in writes {
+            match value {
+                Some((value, raw)) => {
+                    expiry.clear(key);
+                    self.watchers.publish(key, Some(raw));
+                    data.insert(key.clone(), value)
+                }
+                None => {
+                    self.watchers.publish(key, None);
+                    data.remove(key)
+                }
+            };
+        }
+        self.compact_if_due(&data);
+
+        Ok(result)
+    }
 
+    async fn compare_and_swap(
+        &mut self,
+        key: &[u8],
+        expected: Option<&[u8]>,
+        new: Option<&[u8]>,
+    ) -> Result<std::result::Result<(), CompareAndSwapError>> {
+        if !self.options.documents {
+            return self
+                .transaction(|tx| compare_and_swap_in(tx, key, expected, new))
+                .await;
+        }
+
+        // Documents are kept parsed and read back reserialized, so compare
+        // them as documents: `{"a": 1}` has to match a stored `{"a":1}`
+        let expected = expected.map(|bytes| self.stored_value(bytes)).transpose()?;
+        self.transaction(|tx| {
+            let current = tx.get(key)?;
+            let document = current
+                .as_deref()
+                .map(serde_json::from_slice::<Value>)
+                .transpose()?;
+            if document != expected {
+                return Ok(Err(CompareAndSwapError { current }));
+            }
+            match new {
+                Some(value) => tx.put(key, value)?,
+                None => tx.delete(key)?,
+            }
+            Ok(Ok(()))
+        })
+        .await
+    }
+
+    async fn increment(&mut self, key: &[u8], delta: i64) -> Result<i64> {
+        self.transaction(|tx| increment_in(tx, key, delta)).await
+    }
+
+    fn set_merge_operator<F>(&mut self, operator: F)
+    where
+        F: Fn(&[u8], Option<&[u8]>, &[u8]) -> Vec<u8> + Send + Sync + 'static,
+    {
+        self.merge_operator = Some(Arc::new(operator));
+    }
+
+    async fn merge(&mut self, key: &[u8], operand: &[u8]) -> Result<()> {
+        let operator = self.merge_operator.clone();
+        self.transaction(|tx| merge_in(tx, operator.as_ref(), key, operand))
+            .await
+    }
+
+    async fn add_with_ttl(&mut self, key: &[u8], value: &[u8], ttl: Duration) -> Result<()> {
+        let value_json = self.stored_value(value)?;
+        let mut data = self.lock_data();
+        self.log_write(WriteBatch::new().put(key, value))?;
+        self.expiry.lock().unwrap().set(key, deadline_after(ttl));
+        self.watchers.publish(key, Some(value));
+        data.insert(key.to_vec(), value_json);
+        self.compact_if_due(&data);
         Ok(())
     }
+
+    async fn ttl(&self, key: &[u8]) -> Result<Option<Duration>> {
+        if !self.lock_data().contains_key(key) {
+            return Ok(None);
+        }
+        let deadline = self.expiry.lock().unwrap().deadline(key);
+        Ok(deadline.and_then(remaining_until))
+    }
+
+    async fn purge_expired(&self) -> Result<usize> {
+        let mut data = self.data.lock().unwrap();
+        Ok(self.evict_expired(&mut data))
+    }
+
+    async fn open_tree(&self, name: &str) -> Result<Self> {
+        check_tree_name(name)?;
+        let opened = self
+            .trees
+            .lock()
+            .unwrap()
+            .get(name)
+            .map(|tree| tree.share(&self.trees));
+        if let Some(tree) = opened {
+            return Ok(tree);
+        }
+
+        // Each tree lives in a file of its own next to this one, written when
+        // its handle is flushed
+        let path = tree_file_path(&self.path, name);
+        let tree = Self::open_with(&path.to_string_lossy(), self.options).await?;
+        let mut trees = self.trees.lock().unwrap();
+        let tree = trees.entry(name.to_string()).or_insert(tree);
+        Ok(tree.share(&self.trees))
+    }
+
+    async fn list_trees(&self) -> Result<Vec<String>> {
+        let mut names = tree_files(&self.path)?;
+        names.extend(self.trees.lock().unwrap().keys().cloned());
+        names.sort();
+        names.dedup();
+        Ok(names)
+    }
+
+    async fn drop_tree(&self, name: &str) -> Result<bool> {
+        check_tree_name(name)?;
+        if self.options.read_only {
+            bail!(DatabaseError::ReadOnly(
+                "the database was opened read-only".to_string()
+            ));
+        }
+        let opened = self.trees.lock().unwrap().remove(name).is_some();
+        let stored = remove_file(&tree_file_path(&self.path, name))?;
+        Ok(opened || stored)
+    }
+
+    async fn write_batch(&mut self, batch: WriteBatch) -> Result<()> {
+        let written = batch.put_keys();
+        let mut data = self.lock_data();
+        let changes = if self.watchers.is_watched() {
+            batch.changes_in_map(&data)?
+        } else {
+            BTreeMap::new()
+        };
+        // Validate the values first so a batch that can't be applied isn't logged
+        for op in batch.ops() {
+            if let BatchOp::Put { value, .. } = op {
+                self.stored_value(value)?;
+            }
+        }
+        self.log_write(&batch)?;
+        batch.apply_to_map(&mut data, |value| self.stored_value(value))?;
+
+        let mut expiry = self.expiry.lock().unwrap();
+        for key in written {
+            expiry.clear(&key);
+        }
+        self.watchers.publish_all(&changes);
+        self.compact_if_due(&data);
+        Ok(())
+    }
+
+    async fn select_range_with(
+        &self,
+        start: &[u8],
+        end: &[u8],
+        options: &ScanOptions,
+    ) -> Result<Vec<(Vec<u8>, Vec<u8>)>> {
+        let data = self.lock_data();
+        Ok(select_from_map(&data, start, end, options, |value| {
+            self.value_bytes(value)
+        }))
+    }
+
+    fn scan(&self, start: &[u8], end: &[u8]) -> KvStream<'_> {
+        Box::pin(chunked_scan(start, end, move |from, end, limit| {
+            let data = self.lock_data();
+            let chunk = data
+                .range(from..end)
+                .filter_map(|(key, value)| Some((key.clone(), self.value_bytes(value)?)))
+                .take(limit)
+                .collect();
+            future::ready(Ok(chunk))
+        }))
+    }
+
+    async fn snapshot(&self) -> Result<Box<dyn Snapshot>> {
+        let data = self
+            .lock_data()
+            .iter()
+            .filter_map(|(key, value)| Some((key.clone(), self.value_bytes(value)?)))
+            .collect();
+        Ok(Box::new(MapSnapshot::new(data)))
+    }
+
+    fn watch_prefix(&self, prefix: &[u8]) -> WatchStream {
+        self.watchers.subscribe(prefix)
+    }
 }
 
 // SAFETY: JsonDatabase is safe to share between threads because data access is protected by Mutex
@@ -1,23 +1,49 @@
+pub mod batch;
 #[cfg(not(target_arch = "wasm32"))]
 pub mod client;
+pub mod collection;
 pub mod csv;
 pub mod database;
+pub mod document;
+pub mod durable;
+pub mod error;
 #[cfg(target_arch = "wasm32")]
 pub mod idb;
+pub mod index;
 pub mod json;
+pub mod lock;
 pub mod memory;
+pub mod merge;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod remote;
 #[cfg(not(target_arch = "wasm32"))]
 pub mod rocksdb;
+pub mod scan;
 #[cfg(not(target_arch = "wasm32"))]
 pub mod server;
 #[cfg(not(target_arch = "wasm32"))]
 pub mod sled;
+pub mod snapshot;
 #[cfg(not(target_arch = "wasm32"))]
 pub mod sqlite;
+pub mod transaction;
+pub mod tree;
+pub mod ttl;
+pub mod tuple;
+pub mod wal;
+pub mod watch;
 pub mod yaml;
 #[cfg(not(target_arch = "wasm32"))]
 pub use client::VerseDbClient;
-pub use database::Database;
+pub use collection::Collection;
+pub use database::{
+    Database, KvStream, MergeOperator, ScanOptions, Snapshot, Transaction, WatchEvent, WatchStream,
+    WriteBatch,
+};
+pub use error::DatabaseError;
+pub use index::IndexedDatabase;
+#[cfg(not(target_arch = "wasm32"))]
+pub use remote::RemoteDatabase;
 #[cfg(not(target_arch = "wasm32"))]
 pub use rocksdb::RocksDbDatabase;
 #[cfg(not(target_arch = "wasm32"))]
@@ -26,18 +52,3 @@ pub use server::VerseDbServer;
 pub mod versedb_capnp {
     include!("../generated/proto/versedb_capnp.rs");
 }
-
-pub fn add(left: u64, right: u64) -> u64 {
-    left + right
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn it_works() {
-        let result = add(2, 2);
-        assert_eq!(result, 4);
-    }
-}
@@ -0,0 +1,164 @@
+use crate::database::{DatabaseError, Result};
+use crate::durable::sibling;
+use anyhow::bail;
+use std::collections::BTreeMap;
+use std::fs::{self, File, OpenOptions, TryLockError};
+use std::io::{Read, Seek, Write};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, LazyLock, Mutex, Weak};
+
+/// Locks this process holds, by lock file. The OS doesn't stop a process
+/// from locking a file twice, so conflicts within it are found here.
+static HELD: LazyLock<Mutex<BTreeMap<PathBuf, Weak<FileLock>>>> = LazyLock::new(Mutex::default);
+
+/// Lock file of the database at `path`: `data.json.lock`
+pub(crate) fn lock_path(path: &Path) -> PathBuf {
+    sibling(path, ".lock")
+}
+
+/// Advisory lock on a file-backed database, held until every handle using it
+/// is dropped. A writer locks the lock file exclusively and writes its PID to
+/// it; read-only handles share the lock with each other and with a writer in
+/// their own process, but there is only one writer: opening the database for
+/// writing again in the same process takes the lock over, and the handles on
+/// the earlier one can't write any more. The operating system releases the
+/// lock of a process that dies, so it never goes stale.
+pub(crate) struct FileLock {
+    /// Open until the lock is released; closing it unlocks the file
+    file: Mutex<Option<File>>,
+    /// Canonical path of the lock file
+    path: PathBuf,
+    shared: bool,
+}
+
+impl FileLock {
+    /// Lock the database at `path`, shared for a read-only handle and
+    /// exclusively otherwise, failing at once if another process, or a
+    /// read-only handle in this one, holds a lock that conflicts
+    pub(crate) fn acquire(path: &Path, shared: bool) -> Result<Arc<FileLock>> {
+        let mut file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(false)
+            .open(lock_path(path))?;
+        let canonical = fs::canonicalize(lock_path(path))?;
+
+        let mut held = HELD.lock().unwrap();
+        if let Some(lock) = held.get(&canonical).and_then(Weak::upgrade) {
+            if shared {
+                return Ok(lock);
+            }
+            if lock.shared {
+                // Dropping the last handle on `lock` takes `HELD` again
+                drop(held);
+                bail!(DatabaseError::Conflict(format!(
+                    "database {} is open read-only in this process",
+                    path.display()
+                )));
+            }
+            // Two writers would overwrite the file with data of their own, so
+            // the earlier one gives the lock up; what it logged ahead is
+            // replayed by the new one
+            lock.close_file();
+        }
+
+        let locked = if shared {
+            file.try_lock_shared()
+        } else {
+            file.try_lock()
+        };
+        match locked {
+            Ok(()) => {}
+            Err(TryLockError::WouldBlock) => {
+                let mut holder = String::new();
+                file.read_to_string(&mut holder)?;
+                match holder.trim().parse::<u32>() {
+                    Ok(pid) => bail!(DatabaseError::Conflict(format!(
+                        "database {} is locked by pid {}",
+                        path.display(),
+                        pid
+                    ))),
+                    Err(_) => bail!(DatabaseError::Conflict(format!(
+                        "database {} is open read-only in another process",
+                        path.display()
+                    ))),
+                }
+            }
+            Err(TryLockError::Error(e)) => return Err(e.into()),
+        }
+
+        // Readers leave the file as it is: they share the lock with each
+        // other, so they can't tell whether its PID is stale
+        if !shared {
+            file.set_len(0)?;
+            file.rewind()?;
+            write!(file, "{}", std::process::id())?;
+        }
+
+        let lock = Arc::new(FileLock {
+            file: Mutex::new(Some(file)),
+            path: canonical.clone(),
+            shared,
+        });
+        held.insert(canonical, Arc::downgrade(&lock));
+        Ok(lock)
+    }
+
+    /// Give the lock up before every handle using it is dropped, as `close`
+    /// does for a handle opened `shared` or not. A read-only handle sharing
+    /// a writer's lock leaves it to the writer. Handles still holding the
+    /// lock can't write from then on.
+    pub(crate) fn release(&self, shared: bool) {
+        if shared == self.shared {
+            self.unlock();
+        }
+    }
+
+    /// Close the lock file, which unlocks it, returning whether it was open
+    fn close_file(&self) -> bool {
+        let Some(file) = self.file.lock().unwrap().take() else {
+            return false;
+        };
+        if !self.shared {
+            let _ = file.set_len(0);
+        }
+        true
+    }
+
+    fn unlock(&self) {
+        if !self.close_file() {
+            return;
+        }
+        let mut held = HELD.lock().unwrap();
+        // The path may already be locked again by a newer handle
+        if held
+            .get(&self.path)
+            .is_some_and(|lock| std::ptr::eq(lock.as_ptr(), self) || lock.strong_count() == 0)
+        {
+            held.remove(&self.path);
+        }
+    }
+
+    /// Whether the lock hasn't been released yet
+    pub(crate) fn is_held(&self) -> bool {
+        self.file.lock().unwrap().is_some()
+    }
+
+    /// Fail unless the lock is still held, before writing under it
+    pub(crate) fn check_held(&self) -> Result<()> {
+        if !self.is_held() {
+            bail!(DatabaseError::Conflict(format!(
+                "database {} was closed, or opened for writing again in this process",
+                self.path.with_extension("").display()
+            )));
+        }
+        Ok(())
+    }
+}
+
+impl Drop for FileLock {
+    fn drop(&mut self) {
+        self.unlock();
+    }
+}
@@ -1,27 +1,88 @@
-use super::database::{Database, Result};
+use super::batch::WriteBatch;
+use super::database::{
+    CompareAndSwapError, Database, KvStream, MergeOperator, Result, ScanOptions, Transaction,
+};
+use super::merge::{increment_in, merge_in};
+use super::scan::{chunked_scan, prefix_bounds, select_from_map};
+use super::snapshot::{MapSnapshot, Snapshot};
+use super::transaction::{OverlayTransaction, compare_and_swap_in};
+use super::tree::{TreeRegistry, check_tree_name};
+use super::ttl::{Expiry, deadline_after, now_millis, remaining_until};
+use super::watch::{ChangeBroadcaster, WatchStream};
 use async_trait::async_trait;
+use futures::future;
 use std::collections::BTreeMap;
-use std::sync::Mutex;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::Duration;
 
 pub struct MemoryDatabase {
-    data: Mutex<BTreeMap<Vec<u8>, Vec<u8>>>,
+    /// Shared with the other handles on the same tree
+    data: Arc<Mutex<BTreeMap<Vec<u8>, Vec<u8>>>>,
+    merge_operator: Option<MergeOperator>,
+    expiry: Arc<Mutex<Expiry>>,
+    watchers: ChangeBroadcaster,
+    trees: TreeRegistry<MemoryDatabase>,
 }
 
 impl Clone for MemoryDatabase {
     fn clone(&self) -> Self {
         Self {
-            data: Mutex::new(self.data.lock().unwrap().clone()),
+            data: Arc::new(Mutex::new(self.data.lock().unwrap().clone())),
+            merge_operator: self.merge_operator.clone(),
+            expiry: Arc::new(Mutex::new(self.expiry.lock().unwrap().clone())),
+            // The clone owns a copy of the data, so its changes are its own
+            watchers: ChangeBroadcaster::default(),
+            // Named trees are copied along with it
+            trees: Arc::new(Mutex::new(self.trees.lock().unwrap().clone())),
         }
     }
 }
 
+impl MemoryDatabase {
+    fn empty() -> Self {
+        Self {
+            data: Arc::default(),
+            merge_operator: None,
+            expiry: Arc::default(),
+            watchers: ChangeBroadcaster::default(),
+            trees: TreeRegistry::default(),
+        }
+    }
+
+    /// A new handle on the same tree as `self`, within the database owning `trees`
+    fn share(&self, trees: &TreeRegistry<MemoryDatabase>) -> Self {
+        Self {
+            data: Arc::clone(&self.data),
+            merge_operator: None,
+            expiry: Arc::clone(&self.expiry),
+            watchers: self.watchers.clone(),
+            trees: Arc::clone(trees),
+        }
+    }
+
+    /// Drop every entry whose TTL has run out, returning how many were removed
+    fn evict_expired(&self, data: &mut BTreeMap<Vec<u8>, Vec<u8>>) -> usize {
+        let expired = self.expiry.lock().unwrap().take_expired(now_millis());
+        expired
+            .iter()
+            .filter(|key| data.remove(key.as_slice()).is_some())
+            .inspect(|key| self.watchers.publish(key, None))
+            .count()
+    }
+
+    /// Lock the data for an operation, so expired entries are never observed
+    fn lock_data(&self) -> MutexGuard<'_, BTreeMap<Vec<u8>, Vec<u8>>> {
+        let mut data = self.data.lock().unwrap();
+        self.evict_expired(&mut data);
+        data
+    }
+}
+
 #[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 #[cfg_attr(not(target_arch = "wasm32"), async_trait)]
 impl Database for MemoryDatabase {
     async fn open(_path: &str) -> Result<Self> {
-        Ok(Self {
-            data: Mutex::new(BTreeMap::new()),
-        })
+        Ok(Self::empty())
     }
 
     async fn close(&mut self) -> Result<()> {
@@ -29,19 +90,23 @@ impl Database for MemoryDatabase {
     }
 
     async fn add(&mut self, key: &[u8], value: &[u8]) -> Result<()> {
-        self.data
-            .lock()
-            .unwrap()
-            .insert(key.to_vec(), value.to_vec());
+        let mut data = self.lock_data();
+        self.expiry.lock().unwrap().clear(key);
+        self.watchers.publish(key, Some(value));
+        data.insert(key.to_vec(), value.to_vec());
         Ok(())
     }
 
     async fn select(&self, key: &[u8]) -> Result<Option<Vec<u8>>> {
-        Ok(self.data.lock().unwrap().get(key).cloned())
+        Ok(self.lock_data().get(key).cloned())
     }
 
     async fn remove(&mut self, key: &[u8]) -> Result<()> {
-        self.data.lock().unwrap().remove(key);
+        let mut data = self.lock_data();
+        self.expiry.lock().unwrap().clear(key);
+        if data.remove(key).is_some() {
+            self.watchers.publish(key, None);
+        }
         Ok(())
     }
 
@@ -49,7 +114,7 @@ impl Database for MemoryDatabase {
         let mut result = Vec::new();
         let start_vec = start.to_vec();
         let end_vec = end.to_vec();
-        let data = self.data.lock().unwrap();
+        let data = self.lock_data();
         for (key, value) in data.iter() {
             if key >= &start_vec && key < &end_vec {
                 result.push((key.clone(), value.clone()));
@@ -62,7 +127,7 @@ impl Database for MemoryDatabase {
         let mut result = Vec::new();
         let start_vec = start.to_vec();
         let end_vec = end.to_vec();
-        let mut data = self.data.lock().unwrap();
+        let mut data = self.lock_data();
 
         // Collect keys to remove and their values
         let keys_to_remove: Vec<Vec<u8>> = data
@@ -74,17 +139,187 @@ impl Database for MemoryDatabase {
             })
             .collect();
 
-        // Remove the collected keys
+        // Remove the collected keys along with their deadlines
+        let mut expiry = self.expiry.lock().unwrap();
         for key in keys_to_remove {
+            expiry.clear(&key);
             data.remove(&key);
         }
+        self.watchers.publish_removed(&result);
 
         Ok(result)
     }
 
+    async fn scan_prefix(&self, prefix: &[u8]) -> Result<Vec<(Vec<u8>, Vec<u8>)>> {
+        let data = self.lock_data();
+        Ok(data
+            .range(prefix_bounds(prefix))
+            .map(|(key, value)| (key.clone(), value.clone()))
+            .collect())
+    }
+
+    async fn remove_prefix(&self, prefix: &[u8]) -> Result<Vec<(Vec<u8>, Vec<u8>)>> {
+        let mut data = self.lock_data();
+        let keys: Vec<Vec<u8>> = data
+            .range(prefix_bounds(prefix))
+            .map(|(key, _)| key.clone())
+            .collect();
+        let mut expiry = self.expiry.lock().unwrap();
+        let removed: Vec<(Vec<u8>, Vec<u8>)> = keys
+            .into_iter()
+            .inspect(|key| expiry.clear(key))
+            .filter_map(|key| data.remove_entry(&key))
+            .collect();
+        self.watchers.publish_removed(&removed);
+        Ok(removed)
+    }
+
     async fn flush(&mut self) -> Result<()> {
         Ok(())
     }
+
+    async fn transaction<F, R>(&mut self, f: F) -> Result<R>
+    where
+        F: Fn(&mut dyn Transaction) -> Result<R> + Send,
+        R: Send,
+    {
+        let mut data = self.lock_data();
+        let read = |key: &[u8]| Ok(data.get(key).cloned());
+        let mut tx = OverlayTransaction::new(&read);
+        let result = f(&mut tx)?;
+
+        // Only touch the map once the closure has succeeded
+        let mut expiry = self.expiry.lock().unwrap();
+        for (key, value) in tx.into_writes() {
+            match value {
+                Some(value) => {
+                    expiry.clear(&key);
+                    self.watchers.publish(&key, Some(&value));
+                    data.insert(key, value)
+                }
+                None => {
+                    self.watchers.publish(&key, None);
+                    data.remove(&key)
+                }
+            };
+        }
+
+        Ok(result)
+    }
+
+    async fn compare_and_swap(
+        &mut self,
+        key: &[u8],
+        expected: Option<&[u8]>,
+        new: Option<&[u8]>,
+    ) -> Result<std::result::Result<(), CompareAndSwapError>> {
+        self.transaction(|tx| compare_and_swap_in(tx, key, expected, new))
+            .await
+    }
+
+    async fn increment(&mut self, key: &[u8], delta: i64) -> Result<i64> {
+        self.transaction(|tx| increment_in(tx, key, delta)).await
+    }
+
+    fn set_merge_operator<F>(&mut self, operator: F)
+    where
+        F: Fn(&[u8], Option<&[u8]>, &[u8]) -> Vec<u8> + Send + Sync + 'static,
+    {
+        self.merge_operator = Some(Arc::new(operator));
+    }
+
+    async fn merge(&mut self, key: &[u8], operand: &[u8]) -> Result<()> {
+        let operator = self.merge_operator.clone();
+        self.transaction(|tx| merge_in(tx, operator.as_ref(), key, operand))
+            .await
+    }
+
+    async fn add_with_ttl(&mut self, key: &[u8], value: &[u8], ttl: Duration) -> Result<()> {
+        let mut data = self.lock_data();
+        self.expiry.lock().unwrap().set(key, deadline_after(ttl));
+        self.watchers.publish(key, Some(value));
+        data.insert(key.to_vec(), value.to_vec());
+        Ok(())
+    }
+
+    async fn ttl(&self, key: &[u8]) -> Result<Option<Duration>> {
+        if !self.lock_data().contains_key(key) {
+            return Ok(None);
+        }
+        let deadline = self.expiry.lock().unwrap().deadline(key);
+        Ok(deadline.and_then(remaining_until))
+    }
+
+    async fn purge_expired(&self) -> Result<usize> {
+        let mut data = self.data.lock().unwrap();
+        Ok(self.evict_expired(&mut data))
+    }
+
+    async fn open_tree(&self, name: &str) -> Result<Self> {
+        check_tree_name(name)?;
+        let mut trees = self.trees.lock().unwrap();
+        let tree = trees.entry(name.to_string()).or_insert_with(Self::empty);
+        Ok(tree.share(&self.trees))
+    }
+
+    async fn list_trees(&self) -> Result<Vec<String>> {
+        Ok(self.trees.lock().unwrap().keys().cloned().collect())
+    }
+
+    async fn drop_tree(&self, name: &str) -> Result<bool> {
+        check_tree_name(name)?;
+        Ok(self.trees.lock().unwrap().remove(name).is_some())
+    }
+
+    async fn write_batch(&mut self, batch: WriteBatch) -> Result<()> {
+        let written = batch.put_keys();
+        let mut data = self.lock_data();
+        let changes = if self.watchers.is_watched() {
+            batch.changes_in_map(&data)?
+        } else {
+            BTreeMap::new()
+        };
+        batch.apply_to_map(&mut data, |value| Ok(value.to_vec()))?;
+
+        let mut expiry = self.expiry.lock().unwrap();
+        for key in written {
+            expiry.clear(&key);
+        }
+        self.watchers.publish_all(&changes);
+        Ok(())
+    }
+
+    async fn select_range_with(
+        &self,
+        start: &[u8],
+        end: &[u8],
+        options: &ScanOptions,
+    ) -> Result<Vec<(Vec<u8>, Vec<u8>)>> {
+        let data = self.lock_data();
+        Ok(select_from_map(&data, start, end, options, |value| {
+            Some(value.clone())
+        }))
+    }
+
+    fn scan(&self, start: &[u8], end: &[u8]) -> KvStream<'_> {
+        Box::pin(chunked_scan(start, end, move |from, end, limit| {
+            let data = self.lock_data();
+            let chunk = data
+                .range(from..end)
+                .take(limit)
+                .map(|(key, value)| (key.clone(), value.clone()))
+                .collect();
+            future::ready(Ok(chunk))
+        }))
+    }
+
+    async fn snapshot(&self) -> Result<Box<dyn Snapshot>> {
+        Ok(Box::new(MapSnapshot::new(self.lock_data().clone())))
+    }
+
+    fn watch_prefix(&self, prefix: &[u8]) -> WatchStream {
+        self.watchers.subscribe(prefix)
+    }
 }
 
 // SAFETY: MemoryDatabase is safe to share between threads because data access is protected by Mutex
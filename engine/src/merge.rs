@@ -0,0 +1,63 @@
+use crate::database::{DatabaseError, Result, Transaction};
+use anyhow::bail;
+use std::sync::Arc;
+
+/// User-registered read-modify-write function applied by `Database::merge`.
+/// Called as `operator(key, existing, operand)` and returns the new value to
+/// store under `key`; `existing` is `None` when the key is absent.
+pub type MergeOperator = Arc<dyn Fn(&[u8], Option<&[u8]>, &[u8]) -> Vec<u8> + Send + Sync>;
+
+/// Counters are stored as decimal text so they stay readable in every
+/// backend, including the text-only CSV, JSON and YAML files
+pub fn encode_counter(value: i64) -> Vec<u8> {
+    value.to_string().into_bytes()
+}
+
+/// Parse a counter written by `Database::increment`, treating an absent key as 0
+pub fn decode_counter(value: Option<&[u8]>) -> Result<i64> {
+    let Some(value) = value else {
+        return Ok(0);
+    };
+    std::str::from_utf8(value)
+        .ok()
+        .and_then(|text| text.parse().ok())
+        .ok_or_else(|| {
+            DatabaseError::InvalidArgument(format!(
+                "value is not a counter: {:?}",
+                String::from_utf8_lossy(value)
+            ))
+            .into()
+        })
+}
+
+/// The counter after adding `delta` to `existing`, failing on overflow
+pub(crate) fn add_to_counter(existing: Option<&[u8]>, delta: i64) -> Result<i64> {
+    decode_counter(existing)?
+        .checked_add(delta)
+        .ok_or_else(|| DatabaseError::InvalidArgument("counter overflow".to_string()).into())
+}
+
+/// Increment expressed through a `Transaction`, for backends that get their
+/// atomicity from `Database::transaction`
+pub(crate) fn increment_in(tx: &mut dyn Transaction, key: &[u8], delta: i64) -> Result<i64> {
+    let value = add_to_counter(tx.get(key)?.as_deref(), delta)?;
+    tx.put(key, &encode_counter(value))?;
+    Ok(value)
+}
+
+/// Merge expressed through a `Transaction`, applying `operator` to the
+/// current value eagerly
+pub(crate) fn merge_in(
+    tx: &mut dyn Transaction,
+    operator: Option<&MergeOperator>,
+    key: &[u8],
+    operand: &[u8],
+) -> Result<()> {
+    let Some(operator) = operator else {
+        bail!(DatabaseError::InvalidArgument(
+            "no merge operator registered".to_string()
+        ));
+    };
+    let value = operator(key, tx.get(key)?.as_deref(), operand);
+    tx.put(key, &value)
+}
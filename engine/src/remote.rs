@@ -0,0 +1,431 @@
+use crate::client::{ClientError, SnapshotClient, VerseDbClient, connect};
+use crate::database::{
+    CompareAndSwapError, Database, DatabaseError, KvStream, MergeOperator, Result, ScanOptions,
+    Snapshot, Transaction, WatchStream, WriteBatch,
+};
+use crate::scan::chunked_scan;
+use anyhow::{anyhow, bail};
+use async_trait::async_trait;
+use futures::StreamExt;
+use futures::channel::{mpsc, oneshot};
+use futures::future::LocalBoxFuture;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::future::Future;
+use std::rc::Rc;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// What a connection thread keeps for the handles made through it: the
+/// database's client at 0 and one for each tree opened after it, and the
+/// snapshots not dropped yet
+#[derive(Default)]
+struct Connection {
+    clients: Vec<Rc<VerseDbClient>>,
+    snapshots: HashMap<u64, Rc<SnapshotClient>>,
+    next_snapshot: u64,
+}
+
+/// RPC made on the connection thread, which replies through a channel of its own
+type Call = Box<dyn FnOnce(Rc<RefCell<Connection>>) -> LocalBoxFuture<'static, ()> + Send>;
+
+/// `Database` served by a VerseDB server over Cap'n Proto RPC. `open` takes
+/// the server address, `host:port`.
+///
+/// The RPC client can't leave the thread it was created on, so each opened
+/// database runs its connection on a thread of its own; clones, trees and
+/// snapshots made from it share that connection. Writes are made by the
+/// server as they arrive, and `write_batch` applies its batch there
+/// atomically. Trees and snapshots are served as objects of their own,
+/// which the server keeps until the last handle on them is dropped.
+///
+/// `merge` runs the local merge operator in a loop of `compare_and_swap`
+/// calls, retried until no other writer changed the value in between.
+///
+/// `transaction` always fails with `DatabaseError::Unsupported`: its closure
+/// reads and writes synchronously, which can't be done over the connection.
+/// Use `write_batch` or `compare_and_swap` instead.
+#[derive(Clone)]
+pub struct RemoteDatabase {
+    addr: Arc<str>,
+    calls: mpsc::UnboundedSender<Call>,
+    /// Index of this handle's client in its `Connection`
+    client: usize,
+    merge_operator: Option<MergeOperator>,
+}
+
+/// Snapshot kept by the server for a `RemoteDatabase`
+pub struct RemoteSnapshot {
+    addr: Arc<str>,
+    calls: mpsc::UnboundedSender<Call>,
+    id: u64,
+}
+
+/// Body of a connection thread: connect to `addr`, report the outcome on
+/// `ready`, then make every call queued until the last handle is dropped
+fn run_connection(
+    addr: &str,
+    ready: oneshot::Sender<Result<()>>,
+    mut calls: mpsc::UnboundedReceiver<Call>,
+) {
+    let runtime = match tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+    {
+        Ok(runtime) => runtime,
+        Err(e) => {
+            let _ = ready.send(Err(e.into()));
+            return;
+        }
+    };
+    let local = tokio::task::LocalSet::new();
+
+    local.block_on(&runtime, async move {
+        let client = match connect(addr).await {
+            Ok(client) => Rc::new(client),
+            Err(e) => {
+                let _ = ready.send(Err(e));
+                return;
+            }
+        };
+        let connection = Rc::new(RefCell::new(Connection {
+            clients: vec![client],
+            ..Connection::default()
+        }));
+        let _ = ready.send(Ok(()));
+
+        // Calls are sent in the order they were queued but answered
+        // concurrently
+        while let Some(call) = calls.next().await {
+            tokio::task::spawn_local(call(connection.clone()));
+        }
+    });
+}
+
+/// Make `rpc` on the connection thread behind `calls` and wait for its result
+async fn call<F, Fut, R>(addr: &str, calls: &mpsc::UnboundedSender<Call>, rpc: F) -> Result<R>
+where
+    F: FnOnce(Rc<RefCell<Connection>>) -> Fut + Send + 'static,
+    Fut: Future<Output = std::result::Result<R, ClientError>> + 'static,
+    R: Send + 'static,
+{
+    let (reply, result) = oneshot::channel();
+    let call: Call = Box::new(move |connection| {
+        Box::pin(async move {
+            let _ = reply.send(rpc(connection).await);
+        })
+    });
+    let closed = || anyhow!("connection to {} is closed", addr);
+    calls.unbounded_send(call).map_err(|_| closed())?;
+    match result.await.map_err(|_| closed())? {
+        Ok(value) => Ok(value),
+        // Raised as the server raised it, so `DatabaseError::of` finds it
+        Err(ClientError::Database(e)) => Err(e.into()),
+        Err(e) => Err(e.into()),
+    }
+}
+
+impl RemoteDatabase {
+    /// Make `rpc` with this handle's client and wait for its result
+    async fn call<F, Fut, R>(&self, rpc: F) -> Result<R>
+    where
+        F: FnOnce(Rc<VerseDbClient>) -> Fut + Send + 'static,
+        Fut: Future<Output = std::result::Result<R, ClientError>> + 'static,
+        R: Send + 'static,
+    {
+        let index = self.client;
+        call(&self.addr, &self.calls, move |connection| {
+            let client = connection.borrow().clients[index].clone();
+            rpc(client)
+        })
+        .await
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl Snapshot for RemoteSnapshot {
+    async fn select(&self, key: &[u8]) -> Result<Option<Vec<u8>>> {
+        let (id, key) = (self.id, key.to_vec());
+        call(&self.addr, &self.calls, move |connection| {
+            let snapshot = connection.borrow().snapshots[&id].clone();
+            async move { snapshot.select(&key).await }
+        })
+        .await
+    }
+
+    async fn select_range(&self, start: &[u8], end: &[u8]) -> Result<Vec<(Vec<u8>, Vec<u8>)>> {
+        let (id, start, end) = (self.id, start.to_vec(), end.to_vec());
+        call(&self.addr, &self.calls, move |connection| {
+            let snapshot = connection.borrow().snapshots[&id].clone();
+            async move { snapshot.select_range(&start, &end).await }
+        })
+        .await
+    }
+}
+
+impl Drop for RemoteSnapshot {
+    fn drop(&mut self) {
+        // Dropping the client releases the server's view
+        let id = self.id;
+        let _ = self.calls.unbounded_send(Box::new(move |connection| {
+            connection.borrow_mut().snapshots.remove(&id);
+            Box::pin(async {})
+        }));
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl Database for RemoteDatabase {
+    async fn open(path: &str) -> Result<Self> {
+        let addr: Arc<str> = Arc::from(path);
+        let (calls, queue) = mpsc::unbounded();
+        let (ready, connected) = oneshot::channel();
+        let thread_addr = addr.clone();
+        std::thread::Builder::new()
+            .name(format!("versedb-remote-{}", addr))
+            .spawn(move || run_connection(&thread_addr, ready, queue))?;
+        connected
+            .await
+            .map_err(|_| anyhow!("connection to {} failed", addr))??;
+
+        Ok(Self {
+            addr,
+            calls,
+            client: 0,
+            merge_operator: None,
+        })
+    }
+
+    async fn close(&mut self) -> Result<()> {
+        self.flush().await
+    }
+
+    async fn add(&mut self, key: &[u8], value: &[u8]) -> Result<()> {
+        let (key, value) = (key.to_vec(), value.to_vec());
+        self.call(move |client| async move { client.add(&key, &value).await })
+            .await
+    }
+
+    async fn select(&self, key: &[u8]) -> Result<Option<Vec<u8>>> {
+        let key = key.to_vec();
+        self.call(move |client| async move { client.select(&key).await })
+            .await
+    }
+
+    async fn remove(&mut self, key: &[u8]) -> Result<()> {
+        let key = key.to_vec();
+        self.call(move |client| async move { client.remove(&key).await })
+            .await
+    }
+
+    async fn select_range(&self, start: &[u8], end: &[u8]) -> Result<Vec<(Vec<u8>, Vec<u8>)>> {
+        self.select_range_with(start, end, &ScanOptions::default())
+            .await
+    }
+
+    async fn remove_range(&self, start: &[u8], end: &[u8]) -> Result<Vec<(Vec<u8>, Vec<u8>)>> {
+        let (start, end) = (start.to_vec(), end.to_vec());
+        self.call(move |client| async move { client.remove_range(&start, &end).await })
+            .await
+    }
+
+    async fn scan_prefix(&self, prefix: &[u8]) -> Result<Vec<(Vec<u8>, Vec<u8>)>> {
+        let prefix = prefix.to_vec();
+        self.call(move |client| async move { client.scan_prefix(&prefix).await })
+            .await
+    }
+
+    async fn remove_prefix(&self, prefix: &[u8]) -> Result<Vec<(Vec<u8>, Vec<u8>)>> {
+        let prefix = prefix.to_vec();
+        self.call(move |client| async move { client.remove_prefix(&prefix).await })
+            .await
+    }
+
+    async fn select_range_with(
+        &self,
+        start: &[u8],
+        end: &[u8],
+        options: &ScanOptions,
+    ) -> Result<Vec<(Vec<u8>, Vec<u8>)>> {
+        let (start, end, options) = (start.to_vec(), end.to_vec(), options.clone());
+        self.call(
+            move |client| async move { client.select_range_with(&start, &end, &options).await },
+        )
+        .await
+    }
+
+    fn scan(&self, start: &[u8], end: &[u8]) -> KvStream<'_> {
+        // Each chunk is a limited range request, so the server never sends
+        // the whole range at once
+        Box::pin(chunked_scan(start, end, move |from, end, limit| {
+            let options = ScanOptions {
+                limit: Some(limit),
+                ..ScanOptions::default()
+            };
+            self.call(
+                move |client| async move { client.select_range_with(&from, &end, &options).await },
+            )
+        }))
+    }
+
+    async fn snapshot(&self) -> Result<Box<dyn Snapshot>> {
+        let index = self.client;
+        let id = call(&self.addr, &self.calls, move |connection| async move {
+            let client = connection.borrow().clients[index].clone();
+            let snapshot = client.snapshot().await?;
+            let mut connection = connection.borrow_mut();
+            let id = connection.next_snapshot;
+            connection.next_snapshot += 1;
+            connection.snapshots.insert(id, Rc::new(snapshot));
+            Ok(id)
+        })
+        .await?;
+        Ok(Box::new(RemoteSnapshot {
+            addr: self.addr.clone(),
+            calls: self.calls.clone(),
+            id,
+        }))
+    }
+
+    fn watch_prefix(&self, prefix: &[u8]) -> WatchStream {
+        let (sender, events) = mpsc::unbounded();
+        let (index, prefix) = (self.client, prefix.to_vec());
+        let call: Call = Box::new(move |connection| {
+            let client = connection.borrow().clients[index].clone();
+            Box::pin(async move {
+                // A subscription that can't be made ends the stream at once
+                let Ok(mut subscription) = client.watch_prefix(&prefix).await else {
+                    return;
+                };
+                while let Some(event) = subscription.next().await {
+                    if sender.unbounded_send(event).is_err() {
+                        break;
+                    }
+                }
+            })
+        });
+        let _ = self.calls.unbounded_send(call);
+        Box::pin(events)
+    }
+
+    async fn flush(&mut self) -> Result<()> {
+        self.call(|client| async move { client.flush().await })
+            .await
+    }
+
+    /// Always fails: the closure can't run on the server, and running it
+    /// here would take a round trip for every read it makes
+    async fn transaction<F, R>(&mut self, _f: F) -> Result<R>
+    where
+        F: Fn(&mut dyn Transaction) -> Result<R> + Send,
+        R: Send,
+    {
+        bail!(DatabaseError::Unsupported(
+            "transactions are not supported by a remote database".to_string()
+        ))
+    }
+
+    async fn compare_and_swap(
+        &mut self,
+        key: &[u8],
+        expected: Option<&[u8]>,
+        new: Option<&[u8]>,
+    ) -> Result<std::result::Result<(), CompareAndSwapError>> {
+        let key = key.to_vec();
+        let expected = expected.map(<[u8]>::to_vec);
+        let new = new.map(<[u8]>::to_vec);
+        self.call(move |client| async move {
+            client
+                .compare_and_swap(&key, expected.as_deref(), new.as_deref())
+                .await
+        })
+        .await
+    }
+
+    async fn write_batch(&mut self, batch: WriteBatch) -> Result<()> {
+        self.call(move |client| async move { client.write_batch(&batch).await })
+            .await
+    }
+
+    async fn increment(&mut self, key: &[u8], delta: i64) -> Result<i64> {
+        let key = key.to_vec();
+        self.call(move |client| async move { client.increment(&key, delta).await })
+            .await
+    }
+
+    fn set_merge_operator<F>(&mut self, operator: F)
+    where
+        F: Fn(&[u8], Option<&[u8]>, &[u8]) -> Vec<u8> + Send + Sync + 'static,
+    {
+        self.merge_operator = Some(Arc::new(operator));
+    }
+
+    async fn merge(&mut self, key: &[u8], operand: &[u8]) -> Result<()> {
+        let Some(operator) = self.merge_operator.clone() else {
+            bail!(DatabaseError::InvalidArgument(
+                "no merge operator registered".to_string()
+            ));
+        };
+        // Retry until no other writer changed the value in between; a failed
+        // swap hands back the value to merge into next
+        let mut current = self.select(key).await?;
+        loop {
+            let value = operator(key, current.as_deref(), operand);
+            match self
+                .compare_and_swap(key, current.as_deref(), Some(&value))
+                .await?
+            {
+                Ok(()) => return Ok(()),
+                Err(e) => current = e.current,
+            }
+        }
+    }
+
+    async fn add_with_ttl(&mut self, key: &[u8], value: &[u8], ttl: Duration) -> Result<()> {
+        let (key, value) = (key.to_vec(), value.to_vec());
+        self.call(move |client| async move { client.add_with_ttl(&key, &value, ttl).await })
+            .await
+    }
+
+    async fn ttl(&self, key: &[u8]) -> Result<Option<Duration>> {
+        let key = key.to_vec();
+        self.call(move |client| async move { client.ttl(&key).await })
+            .await
+    }
+
+    async fn purge_expired(&self) -> Result<usize> {
+        self.call(|client| async move { client.purge_expired().await })
+            .await
+    }
+
+    async fn open_tree(&self, name: &str) -> Result<Self> {
+        let (index, name) = (self.client, name.to_string());
+        let tree = call(&self.addr, &self.calls, move |connection| async move {
+            let client = connection.borrow().clients[index].clone();
+            let tree = client.open_tree(&name).await?;
+            let mut connection = connection.borrow_mut();
+            connection.clients.push(Rc::new(tree));
+            Ok(connection.clients.len() - 1)
+        })
+        .await?;
+        Ok(Self {
+            addr: self.addr.clone(),
+            calls: self.calls.clone(),
+            client: tree,
+            merge_operator: None,
+        })
+    }
+
+    async fn list_trees(&self) -> Result<Vec<String>> {
+        self.call(|client| async move { client.list_trees().await })
+            .await
+    }
+
+    async fn drop_tree(&self, name: &str) -> Result<bool> {
+        let name = name.to_string();
+        self.call(move |client| async move { client.drop_tree(&name).await })
+            .await
+    }
+}
@@ -1,11 +1,62 @@
-use super::database::{Database, Result};
+use super::batch::{BatchOp, WriteBatch};
+use super::database::{
+    CompareAndSwapError, Database, DatabaseError, KvStream, MergeOperator, Result, ScanOptions,
+    Transaction,
+};
+use super::merge::increment_in;
+use super::scan::{chunked_scan, prefix_successor};
+use super::snapshot::Snapshot;
+use super::transaction::{OverlayTransaction, compare_and_swap_in};
+use super::tree::{TreeRegistry, check_tree_name};
+use super::ttl::{
+    ExpiredKeys, deadline_after, deadline_key, decode_expiry, decode_queue_key, encode_expiry,
+    expired_queue_range, fingerprint, has_expired, now_millis, queue_key, remaining_until,
+};
+use super::watch::{ChangeBroadcaster, WatchStream};
+use anyhow::{anyhow, bail};
 use async_trait::async_trait;
-use rocksdb::{ColumnFamilyDescriptor, DB, IteratorMode, Options, ReadOptions, WriteOptions};
-use std::sync::{Arc, Mutex};
+use futures::future;
+use rocksdb::{
+    BoundColumnFamily, ColumnFamilyDescriptor, DBWithThreadMode, DEFAULT_COLUMN_FAMILY_NAME,
+    IteratorMode, MultiThreaded, Options, ReadOptions, SnapshotWithThreadMode, WriteOptions,
+};
+use std::collections::BTreeMap;
+use std::sync::{Arc, Mutex, MutexGuard, RwLock};
+use std::time::Duration;
+
+/// Trees create and drop column families through the shared handle, which
+/// takes the multi-threaded mode
+type Rocks = DBWithThreadMode<MultiThreaded>;
+
+/// Column family holding the expiry indexes of entries added with a TTL
+const EXPIRY_CF: &str = "versedb_expiry";
+
+/// Name RocksDB records for the merge operator of the entry column families
+const MERGE_OPERATOR_NAME: &str = "versedb_merge";
+
+/// Operator applied by `merge`, shared by every handle on a tree
+type MergeSlot = Arc<RwLock<Option<MergeOperator>>>;
+
+/// State shared by every handle on a named tree
+#[derive(Clone, Default)]
+struct TreeState {
+    merge_operator: MergeSlot,
+    watchers: ChangeBroadcaster,
+}
 
 pub struct RocksDbDatabase {
-    db: Arc<Mutex<DB>>,
+    db: Arc<Rocks>,
+    /// Held by every write, so read-modify-write operations see nothing
+    /// change between their reads and their commit. Reads don't take it.
+    writer: Arc<Mutex<()>>,
     path: String,
+    /// Column family holding the entries of this handle's tree
+    cf: String,
+    /// Column family holding the expiry indexes of this handle's tree
+    expiry_cf: String,
+    merge_operator: MergeSlot,
+    watchers: ChangeBroadcaster,
+    trees: TreeRegistry<TreeState>,
 }
 
 impl Clone for RocksDbDatabase {
@@ -13,25 +64,215 @@ impl Clone for RocksDbDatabase {
         // Just clone the Arc, which will share the same DB instance
         Self {
             db: Arc::clone(&self.db),
+            writer: Arc::clone(&self.writer),
             path: self.path.clone(),
+            cf: self.cf.clone(),
+            expiry_cf: self.expiry_cf.clone(),
+            merge_operator: Arc::clone(&self.merge_operator),
+            watchers: self.watchers.clone(),
+            trees: Arc::clone(&self.trees),
         }
     }
 }
 
+fn base_options() -> Options {
+    let mut options = Options::default();
+    options.create_if_missing(true);
+    options.create_missing_column_families(true);
+    options.set_keep_log_file_num(10);
+    options.set_max_total_wal_size(64 * 1024 * 1024); // 64MB
+    options.set_write_buffer_size(64 * 1024 * 1024); // 64MB
+    options
+}
+
+/// Options of a column family holding entries, resolving merge operands with
+/// whatever operator is registered in `slot` at the time RocksDB merges them
+fn data_options(slot: &MergeSlot) -> Options {
+    let slot = Arc::clone(slot);
+    let mut options = base_options();
+    options.set_merge_operator_associative(MERGE_OPERATOR_NAME, move |key, existing, operands| {
+        // With no operator registered the read fails rather than dropping
+        // the operands
+        let operator = slot.read().unwrap().clone()?;
+        let mut value = existing.map(<[u8]>::to_vec);
+        for operand in operands {
+            value = Some(operator(key, value.as_deref(), operand));
+        }
+        value
+    });
+    options
+}
+
+/// Column family holding the expiry indexes of the tree `name`
+fn tree_expiry_cf(name: &str) -> String {
+    format!("{}.{}", EXPIRY_CF, name)
+}
+
+/// Handle of the column family `name`, which is gone once its tree is dropped
+fn cf_handle<'a>(db: &'a Rocks, name: &str) -> Result<Arc<BoundColumnFamily<'a>>> {
+    db.cf_handle(name)
+        .ok_or_else(|| anyhow!("missing column family {}", name))
+}
+
+impl RocksDbDatabase {
+    /// Handle of the column family holding this handle's entries
+    fn data_cf(&self) -> Result<Arc<BoundColumnFamily<'_>>> {
+        cf_handle(&self.db, &self.cf)
+    }
+
+    /// Handle of the column family holding this handle's expiry indexes
+    fn expiry_cf(&self) -> Result<Arc<BoundColumnFamily<'_>>> {
+        cf_handle(&self.db, &self.expiry_cf)
+    }
+
+    /// Drop every entry whose TTL has run out, returning how many were removed
+    fn evict_expired(&self) -> Result<usize> {
+        let _writer = self.lock_writer();
+        let db = &self.db;
+        let data = self.data_cf()?;
+        let expiry = self.expiry_cf()?;
+        let (start, end) = expired_queue_range(now_millis());
+        let mut read_opts = ReadOptions::default();
+        read_opts.set_iterate_lower_bound(start);
+        read_opts.set_iterate_upper_bound(end);
+
+        // Writers hold the same lock, so values can't change while this runs
+        let mut batch = rocksdb::WriteBatch::default();
+        let mut evicted = Vec::new();
+        for item in db.iterator_cf_opt(&expiry, read_opts, IteratorMode::Start) {
+            let (queue_key, _) = item?;
+            if let Some((deadline, key)) = decode_queue_key(&queue_key) {
+                let record_key = deadline_key(key);
+                let record = db.get_cf(&expiry, &record_key)?;
+                let record = record.as_deref().and_then(decode_expiry);
+
+                // The queue entry is stale if the key got a new deadline since
+                if record.is_some_and(|(current, _)| current == deadline) {
+                    let value = db.get_cf(&data, key)?;
+                    if value.is_some_and(|value| record == Some((deadline, fingerprint(&value)))) {
+                        batch.delete_cf(&data, key);
+                        evicted.push(key.to_vec());
+                    }
+                    batch.delete_cf(&expiry, record_key);
+                }
+            }
+            batch.delete_cf(&expiry, queue_key);
+        }
+
+        if !batch.is_empty() {
+            db.write_opt(batch, &WriteOptions::default())?;
+        }
+        for key in &evicted {
+            self.watchers.publish(key, None);
+        }
+        Ok(evicted.len())
+    }
+
+    /// Remove the entries from `start` up to `end`, or to the last key when
+    /// there is no end, returning them. They are read and deleted under the
+    /// same lock, so a concurrent write can't slip in between
+    fn take_range(&self, start: &[u8], end: Option<Vec<u8>>) -> Result<Vec<(Vec<u8>, Vec<u8>)>> {
+        let writer = self.lock_writer();
+        let db = &self.db;
+        let data = self.data_cf()?;
+        let expiry = self.expiry_cf()?;
+
+        let mut read_opts = ReadOptions::default();
+        read_opts.set_iterate_lower_bound(start.to_vec());
+        if let Some(end) = end {
+            read_opts.set_iterate_upper_bound(end);
+        }
+
+        // Expired entries are deleted along with the rest, but were already
+        // gone as far as callers are concerned
+        let expired = self.expired_keys(ReadOptions::default)?;
+        let mut batch = rocksdb::WriteBatch::default();
+        let mut items = Vec::new();
+        for item in db.iterator_cf_opt(&data, read_opts, IteratorMode::Start) {
+            let (key, value) = item?;
+            batch.delete_cf(&data, &key);
+            batch.delete_cf(&expiry, deadline_key(&key));
+            if !expired.contains(&key, &value) {
+                items.push((key.to_vec(), value.to_vec()));
+            }
+        }
+        if !batch.is_empty() {
+            db.write_opt(batch, &WriteOptions::default())?;
+        }
+        drop(writer);
+
+        self.watchers.publish_removed(&items);
+        Ok(items)
+    }
+
+    /// Keys whose TTL has run out but that `purge_expired` hasn't removed,
+    /// as seen by reads made with the options `read_opts` returns
+    fn expired_keys(&self, read_opts: impl Fn() -> ReadOptions) -> Result<ExpiredKeys> {
+        let expiry = self.expiry_cf()?;
+        let (start, end) = expired_queue_range(now_millis());
+        let mut queue_opts = read_opts();
+        queue_opts.set_iterate_lower_bound(start);
+        queue_opts.set_iterate_upper_bound(end);
+        let queue = self
+            .db
+            .iterator_cf_opt(&expiry, queue_opts, IteratorMode::Start)
+            .map(|item| Ok(item?.0.to_vec()));
+        ExpiredKeys::collect(queue, |record_key| {
+            Ok(self.db.get_cf_opt(&expiry, record_key, &read_opts())?)
+        })
+    }
+
+    /// The value of `key`, unless its TTL has run out
+    fn get_live(&self, key: &[u8]) -> Result<Option<Vec<u8>>> {
+        let Some(value) = self.db.get_cf(&self.data_cf()?, key)? else {
+            return Ok(None);
+        };
+        let record = self.db.get_cf(&self.expiry_cf()?, deadline_key(key))?;
+        Ok((!has_expired(record.as_deref(), &value, now_millis())).then_some(value))
+    }
+
+    /// Lock out other writers for a write. Expired entries are left in place
+    /// for `purge_expired`, and reads skip them.
+    fn lock_writer(&self) -> MutexGuard<'_, ()> {
+        self.writer.lock().unwrap()
+    }
+}
+
 #[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 #[cfg_attr(not(target_arch = "wasm32"), async_trait)]
 impl Database for RocksDbDatabase {
     async fn open(path: &str) -> Result<Self> {
-        let mut options = Options::default();
-        options.create_if_missing(true);
-        options.set_keep_log_file_num(10);
-        options.set_max_total_wal_size(64 * 1024 * 1024); // 64MB
-        options.set_write_buffer_size(64 * 1024 * 1024); // 64MB
+        let merge_operator = MergeSlot::default();
+        let options = data_options(&merge_operator);
+        let mut column_families = vec![
+            ColumnFamilyDescriptor::new(DEFAULT_COLUMN_FAMILY_NAME, options.clone()),
+            ColumnFamilyDescriptor::new(EXPIRY_CF, Options::default()),
+        ];
+
+        // Every column family has to be opened along with the DB, so pick up
+        // the trees created before; a new database has none to list
+        let mut trees = BTreeMap::new();
+        for name in Rocks::list_cf(&options, path).unwrap_or_default() {
+            if check_tree_name(&name).is_ok() {
+                let tree = TreeState::default();
+                let options = data_options(&tree.merge_operator);
+                column_families.push(ColumnFamilyDescriptor::new(&name, options));
+                trees.insert(name, tree);
+            } else if name != DEFAULT_COLUMN_FAMILY_NAME && name != EXPIRY_CF {
+                column_families.push(ColumnFamilyDescriptor::new(name, Options::default()));
+            }
+        }
 
-        let db = DB::open(&options, path)?;
+        let db = Rocks::open_cf_descriptors(&options, path, column_families)?;
         Ok(Self {
-            db: Arc::new(Mutex::new(db)),
+            db: Arc::new(db),
+            writer: Arc::default(),
             path: path.to_string(),
+            cf: DEFAULT_COLUMN_FAMILY_NAME.to_string(),
+            expiry_cf: EXPIRY_CF.to_string(),
+            merge_operator,
+            watchers: ChangeBroadcaster::default(),
+            trees: Arc::new(Mutex::new(trees)),
         })
     }
 
@@ -39,44 +280,50 @@ impl Database for RocksDbDatabase {
         // RocksDB doesn't have an explicit close method
         // Dropping the DB instance will close it automatically
         // We can flush to ensure all data is persisted
-        self.db.lock().unwrap().flush()?;
+        self.db.flush()?;
         Ok(())
     }
 
     async fn add(&mut self, key: &[u8], value: &[u8]) -> Result<()> {
-        let write_opts = WriteOptions::default();
-        self.db.lock().unwrap().put_opt(key, value, &write_opts)?;
+        let _writer = self.lock_writer();
+        let mut batch = rocksdb::WriteBatch::default();
+        batch.put_cf(&self.data_cf()?, key, value);
+        batch.delete_cf(&self.expiry_cf()?, deadline_key(key));
+        self.db.write_opt(batch, &WriteOptions::default())?;
+        self.watchers.publish(key, Some(value));
         Ok(())
     }
 
     async fn select(&self, key: &[u8]) -> Result<Option<Vec<u8>>> {
-        let read_opts = ReadOptions::default();
-        match self.db.lock().unwrap().get_opt(key, &read_opts)? {
-            Some(value) => Ok(Some(value)),
-            None => Ok(None),
-        }
+        self.get_live(key)
     }
 
     async fn remove(&mut self, key: &[u8]) -> Result<()> {
-        let write_opts = WriteOptions::default();
-        self.db.lock().unwrap().delete_opt(key, &write_opts)?;
+        let _writer = self.lock_writer();
+        let mut batch = rocksdb::WriteBatch::default();
+        batch.delete_cf(&self.data_cf()?, key);
+        batch.delete_cf(&self.expiry_cf()?, deadline_key(key));
+        self.db.write_opt(batch, &WriteOptions::default())?;
+        self.watchers.publish(key, None);
         Ok(())
     }
 
     async fn select_range(&self, start: &[u8], end: &[u8]) -> Result<Vec<(Vec<u8>, Vec<u8>)>> {
         let mut result = Vec::new();
-        let db = self.db.lock().unwrap();
-
         let mut read_opts = ReadOptions::default();
         read_opts.set_iterate_lower_bound(start.to_vec());
         read_opts.set_iterate_upper_bound(end.to_vec());
 
-        let iter = db.iterator_opt(IteratorMode::Start, read_opts);
+        let expired = self.expired_keys(ReadOptions::default)?;
+        let data = self.data_cf()?;
+        let iter = self
+            .db
+            .iterator_cf_opt(&data, read_opts, IteratorMode::Start);
         for item in iter {
             let (key, value) = item?;
             // Since the iterator might return keys outside our range despite the bounds,
             // we double-check the key is in our desired range
-            if key.as_ref() >= start && key.as_ref() < end {
+            if key.as_ref() >= start && key.as_ref() < end && !expired.contains(&key, &value) {
                 result.push((key.to_vec(), value.to_vec()));
             }
         }
@@ -85,36 +332,381 @@ impl Database for RocksDbDatabase {
     }
 
     async fn remove_range(&self, start: &[u8], end: &[u8]) -> Result<Vec<(Vec<u8>, Vec<u8>)>> {
-        // First collect all items in range
-        let items = self.select_range(start, end).await?;
+        self.take_range(start, Some(end.to_vec()))
+    }
 
-        if !items.is_empty() {
-            let db = self.db.lock().unwrap();
-            let write_opts = WriteOptions::default();
+    async fn scan_prefix(&self, prefix: &[u8]) -> Result<Vec<(Vec<u8>, Vec<u8>)>> {
+        let mut result = Vec::new();
+        let mut read_opts = ReadOptions::default();
+        read_opts.set_iterate_lower_bound(prefix.to_vec());
+        if let Some(successor) = prefix_successor(prefix) {
+            read_opts.set_iterate_upper_bound(successor);
+        }
 
-            // Using a WriteBatch for better performance
-            let mut batch = rocksdb::WriteBatch::default();
+        let expired = self.expired_keys(ReadOptions::default)?;
+        let data = self.data_cf()?;
+        for item in self
+            .db
+            .iterator_cf_opt(&data, read_opts, IteratorMode::Start)
+        {
+            let (key, value) = item?;
+            if !expired.contains(&key, &value) {
+                result.push((key.to_vec(), value.to_vec()));
+            }
+        }
+
+        Ok(result)
+    }
+
+    async fn remove_prefix(&self, prefix: &[u8]) -> Result<Vec<(Vec<u8>, Vec<u8>)>> {
+        self.take_range(prefix, prefix_successor(prefix))
+    }
+
+    async fn flush(&mut self) -> Result<()> {
+        self.db.flush()?;
+        Ok(())
+    }
 
-            for (key, _) in &items {
-                batch.delete(key);
+    async fn transaction<F, R>(&mut self, f: F) -> Result<R>
+    where
+        F: Fn(&mut dyn Transaction) -> Result<R> + Send,
+        R: Send,
+    {
+        // Holding the lock for the whole closure keeps other writers out, so
+        // buffering the writes and committing them as one WriteBatch is atomic
+        let _writer = self.lock_writer();
+        let read = |key: &[u8]| self.get_live(key);
+        let mut tx = OverlayTransaction::new(&read);
+        let result = f(&mut tx)?;
+
+        let writes = tx.into_writes();
+        let data = self.data_cf()?;
+        let expiry = self.expiry_cf()?;
+        let mut batch = rocksdb::WriteBatch::default();
+        for (key, value) in &writes {
+            match value {
+                Some(value) => batch.put_cf(&data, key, value),
+                None => batch.delete_cf(&data, key),
             }
+            batch.delete_cf(&expiry, deadline_key(key));
+        }
+        self.db.write_opt(batch, &WriteOptions::default())?;
 
-            // Execute the batch delete
-            db.write_opt(batch, &write_opts)?;
+        self.watchers.publish_all(&writes);
+        Ok(result)
+    }
+
+    async fn compare_and_swap(
+        &mut self,
+        key: &[u8],
+        expected: Option<&[u8]>,
+        new: Option<&[u8]>,
+    ) -> Result<std::result::Result<(), CompareAndSwapError>> {
+        self.transaction(|tx| compare_and_swap_in(tx, key, expected, new))
+            .await
+    }
+
+    async fn increment(&mut self, key: &[u8], delta: i64) -> Result<i64> {
+        self.transaction(|tx| increment_in(tx, key, delta)).await
+    }
+
+    fn set_merge_operator<F>(&mut self, operator: F)
+    where
+        F: Fn(&[u8], Option<&[u8]>, &[u8]) -> Vec<u8> + Send + Sync + 'static,
+    {
+        *self.merge_operator.write().unwrap() = Some(Arc::new(operator));
+    }
+
+    async fn merge(&mut self, key: &[u8], operand: &[u8]) -> Result<()> {
+        // Stored as a RocksDB merge operand, resolved by the operator set on
+        // the column family; a reopened database needs the operator set
+        // again before merged keys can be read
+        if self.merge_operator.read().unwrap().is_none() {
+            bail!(DatabaseError::InvalidArgument(
+                "no merge operator registered".to_string()
+            ));
         }
 
-        Ok(items)
+        let _writer = self.lock_writer();
+        let data = self.data_cf()?;
+        let mut batch = rocksdb::WriteBatch::default();
+        batch.merge_cf(&data, key, operand);
+        batch.delete_cf(&self.expiry_cf()?, deadline_key(key));
+        self.db.write_opt(batch, &WriteOptions::default())?;
+
+        if self.watchers.is_watched() {
+            let value = self.db.get_cf(&data, key)?;
+            self.watchers.publish(key, value.as_deref());
+        }
+        Ok(())
     }
 
-    async fn flush(&mut self) -> Result<()> {
-        self.db.lock().unwrap().flush()?;
+    async fn add_with_ttl(&mut self, key: &[u8], value: &[u8], ttl: Duration) -> Result<()> {
+        let _writer = self.lock_writer();
+        let expiry = self.expiry_cf()?;
+        let deadline = deadline_after(ttl);
+
+        let mut batch = rocksdb::WriteBatch::default();
+        batch.put_cf(&expiry, deadline_key(key), encode_expiry(deadline, value));
+        batch.put_cf(&expiry, queue_key(deadline, key), b"");
+        batch.put_cf(&self.data_cf()?, key, value);
+        self.db.write_opt(batch, &WriteOptions::default())?;
+        self.watchers.publish(key, Some(value));
+        Ok(())
+    }
+
+    async fn ttl(&self, key: &[u8]) -> Result<Option<Duration>> {
+        let record = self.db.get_cf(&self.expiry_cf()?, deadline_key(key))?;
+        let value = self.db.get_cf(&self.data_cf()?, key)?;
+        let (Some(value), Some(record)) = (value, record) else {
+            return Ok(None);
+        };
+        Ok(match decode_expiry(&record) {
+            Some((deadline, hash)) if hash == fingerprint(&value) => remaining_until(deadline),
+            _ => None,
+        })
+    }
+
+    async fn purge_expired(&self) -> Result<usize> {
+        self.evict_expired()
+    }
+
+    async fn open_tree(&self, name: &str) -> Result<Self> {
+        check_tree_name(name)?;
+        let _writer = self.lock_writer();
+        let db = &self.db;
+        let tree = self
+            .trees
+            .lock()
+            .unwrap()
+            .entry(name.to_string())
+            .or_default()
+            .clone();
+
+        // Checked one by one, so a tree left half-created gets completed
+        if db.cf_handle(name).is_none() {
+            db.create_cf(name, &data_options(&tree.merge_operator))?;
+        }
+        let expiry_cf = tree_expiry_cf(name);
+        if db.cf_handle(&expiry_cf).is_none() {
+            db.create_cf(&expiry_cf, &Options::default())?;
+        }
+
+        Ok(Self {
+            db: Arc::clone(&self.db),
+            writer: Arc::clone(&self.writer),
+            path: self.path.clone(),
+            cf: name.to_string(),
+            expiry_cf,
+            merge_operator: tree.merge_operator,
+            watchers: tree.watchers,
+            trees: Arc::clone(&self.trees),
+        })
+    }
+
+    async fn list_trees(&self) -> Result<Vec<String>> {
+        let mut names: Vec<String> = Rocks::list_cf(&Options::default(), &self.path)?
+            .into_iter()
+            .filter(|name| check_tree_name(name).is_ok())
+            .collect();
+        names.sort();
+        Ok(names)
+    }
+
+    async fn drop_tree(&self, name: &str) -> Result<bool> {
+        check_tree_name(name)?;
+        let _writer = self.lock_writer();
+        let db = &self.db;
+        let existed = db.cf_handle(name).is_some();
+        if existed {
+            db.drop_cf(name)?;
+        }
+        let expiry_cf = tree_expiry_cf(name);
+        if db.cf_handle(&expiry_cf).is_some() {
+            db.drop_cf(&expiry_cf)?;
+        }
+
+        self.trees.lock().unwrap().remove(name);
+        Ok(existed)
+    }
+
+    async fn write_batch(&mut self, batch: WriteBatch) -> Result<()> {
+        let _writer = self.lock_writer();
+        let db = &self.db;
+        let data = self.data_cf()?;
+        let changes = if self.watchers.is_watched() {
+            batch.clone().resolve(|start, end| {
+                let mut read_opts = ReadOptions::default();
+                read_opts.set_iterate_lower_bound(start.to_vec());
+                read_opts.set_iterate_upper_bound(end.to_vec());
+                db.iterator_cf_opt(&data, read_opts, IteratorMode::Start)
+                    .map(|item| Ok(item?.0.to_vec()))
+                    .collect()
+            })?
+        } else {
+            BTreeMap::new()
+        };
+
+        // Every key written loses its expiry record along with the old value
+        let expiry = self.expiry_cf()?;
+        let mut rocks_batch = rocksdb::WriteBatch::default();
+        for op in batch.ops() {
+            match op {
+                BatchOp::Put { key, value } => {
+                    rocks_batch.put_cf(&data, key, value);
+                    rocks_batch.delete_cf(&expiry, deadline_key(key));
+                }
+                BatchOp::Delete { key } => {
+                    rocks_batch.delete_cf(&data, key);
+                    rocks_batch.delete_cf(&expiry, deadline_key(key));
+                }
+                BatchOp::DeleteRange { start, end } => {
+                    rocks_batch.delete_range_cf(&data, start, end);
+                    rocks_batch.delete_range_cf(&expiry, deadline_key(start), deadline_key(end));
+                }
+            }
+        }
+        db.write_opt(rocks_batch, &WriteOptions::default())?;
+
+        self.watchers.publish_all(&changes);
         Ok(())
     }
+
+    async fn select_range_with(
+        &self,
+        start: &[u8],
+        end: &[u8],
+        options: &ScanOptions,
+    ) -> Result<Vec<(Vec<u8>, Vec<u8>)>> {
+        let (lower, upper) = options.bounds(start, end);
+        if lower >= upper {
+            return Ok(Vec::new());
+        }
+
+        let mut read_opts = ReadOptions::default();
+        read_opts.set_iterate_lower_bound(lower);
+        read_opts.set_iterate_upper_bound(upper);
+
+        // With bounds set, End starts from the last key below the upper bound
+        let mode = if options.reverse {
+            IteratorMode::End
+        } else {
+            IteratorMode::Start
+        };
+
+        let expired = self.expired_keys(ReadOptions::default)?;
+        self.db
+            .iterator_cf_opt(&self.data_cf()?, read_opts, mode)
+            .map(|item| {
+                let (key, value) = item?;
+                Ok((key.to_vec(), value.to_vec()))
+            })
+            .filter(|item| !matches!(item, Ok((key, value)) if expired.contains(key, value)))
+            .take(options.max_entries())
+            .collect()
+    }
+
+    fn scan(&self, start: &[u8], end: &[u8]) -> KvStream<'_> {
+        // A RocksDB iterator can't be held across awaits, so read the range
+        // in bounded chunks instead
+        Box::pin(chunked_scan(start, end, move |from, end, limit| {
+            let data = match self.data_cf() {
+                Ok(data) => data,
+                Err(e) => return future::ready(Err(e)),
+            };
+            let expired = match self.expired_keys(ReadOptions::default) {
+                Ok(expired) => expired,
+                Err(e) => return future::ready(Err(e)),
+            };
+            let mut read_opts = ReadOptions::default();
+            read_opts.set_iterate_lower_bound(from);
+            read_opts.set_iterate_upper_bound(end);
+
+            let chunk = self
+                .db
+                .iterator_cf_opt(&data, read_opts, IteratorMode::Start)
+                .map(|item| {
+                    let (key, value) = item?;
+                    Ok((key.to_vec(), value.to_vec()))
+                })
+                .filter(|item| !matches!(item, Ok((key, value)) if expired.contains(key, value)))
+                .take(limit)
+                .collect();
+            future::ready(chunk)
+        }))
+    }
+
+    async fn snapshot(&self) -> Result<Box<dyn Snapshot>> {
+        // SAFETY: the snapshot borrows the DB, which the Arc stored next to
+        // it keeps open; `RocksDbSnapshot` releases the snapshot first
+        let snapshot = unsafe {
+            std::mem::transmute::<
+                SnapshotWithThreadMode<'_, Rocks>,
+                SnapshotWithThreadMode<'static, Rocks>,
+            >(self.db.snapshot())
+        };
+        let expired = self.expired_keys(|| snapshot_read_options(&snapshot))?;
+        Ok(Box::new(RocksDbSnapshot {
+            snapshot,
+            db: Arc::clone(&self.db),
+            cf: self.cf.clone(),
+            expired,
+        }))
+    }
+
+    fn watch_prefix(&self, prefix: &[u8]) -> WatchStream {
+        self.watchers.subscribe(prefix)
+    }
+}
+
+/// Read options reading through `snapshot`
+fn snapshot_read_options(snapshot: &SnapshotWithThreadMode<'_, Rocks>) -> ReadOptions {
+    let mut read_opts = ReadOptions::default();
+    read_opts.set_snapshot(snapshot);
+    read_opts
+}
+
+/// Reads through a RocksDB snapshot, which pins the version of the database
+/// it was taken at for as long as it lives
+struct RocksDbSnapshot {
+    // Declared before `db`, so the snapshot is released while the DB it
+    // borrows is still open
+    snapshot: SnapshotWithThreadMode<'static, Rocks>,
+    db: Arc<Rocks>,
+    cf: String,
+    /// Keys already expired when the snapshot was taken
+    expired: ExpiredKeys,
 }
 
-// SAFETY: RocksDbDatabase is safe to share between threads because data access is protected by Mutex
-unsafe impl Send for RocksDbDatabase {}
-unsafe impl Sync for RocksDbDatabase {}
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl Snapshot for RocksDbSnapshot {
+    async fn select(&self, key: &[u8]) -> Result<Option<Vec<u8>>> {
+        let data = cf_handle(&self.db, &self.cf)?;
+        let value = self
+            .db
+            .get_cf_opt(&data, key, &snapshot_read_options(&self.snapshot))?;
+        Ok(value.filter(|value| !self.expired.contains(key, value)))
+    }
+
+    async fn select_range(&self, start: &[u8], end: &[u8]) -> Result<Vec<(Vec<u8>, Vec<u8>)>> {
+        if start >= end {
+            return Ok(Vec::new());
+        }
+        let data = cf_handle(&self.db, &self.cf)?;
+        let mut read_opts = snapshot_read_options(&self.snapshot);
+        read_opts.set_iterate_lower_bound(start.to_vec());
+        read_opts.set_iterate_upper_bound(end.to_vec());
+        self.db
+            .iterator_cf_opt(&data, read_opts, IteratorMode::Start)
+            .map(|item| {
+                let (key, value) = item?;
+                Ok((key.to_vec(), value.to_vec()))
+            })
+            .filter(|item| !matches!(item, Ok((key, value)) if self.expired.contains(key, value)))
+            .collect()
+    }
+}
 
 #[cfg(test)]
 mod tests {
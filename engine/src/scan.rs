@@ -0,0 +1,174 @@
+use crate::database::Result;
+use futures::Stream;
+use futures::StreamExt;
+use std::collections::BTreeMap;
+use std::future::Future;
+use std::ops::Bound;
+
+/// Async iterator of key-value pairs returned by `Database::scan`
+#[cfg(not(target_arch = "wasm32"))]
+pub type KvStream<'a> = futures::stream::BoxStream<'a, Result<(Vec<u8>, Vec<u8>)>>;
+
+/// Async iterator of key-value pairs returned by `Database::scan`
+#[cfg(target_arch = "wasm32")]
+pub type KvStream<'a> = futures::stream::LocalBoxStream<'a, Result<(Vec<u8>, Vec<u8>)>>;
+
+/// Direction, bound inclusivity and paging for `Database::select_range_with`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScanOptions {
+    /// Return entries from the end of the range backwards
+    pub reverse: bool,
+    /// Maximum number of entries to return, `None` for no limit
+    pub limit: Option<usize>,
+    /// Whether `start` itself is part of the range (default: true)
+    pub start_inclusive: bool,
+    /// Whether `end` itself is part of the range (default: false)
+    pub end_inclusive: bool,
+    /// Resume after this key, usually the last key of the previous page;
+    /// it and every key before it in scan direction are skipped
+    pub after: Option<Vec<u8>>,
+}
+
+impl Default for ScanOptions {
+    fn default() -> Self {
+        Self {
+            reverse: false,
+            limit: None,
+            start_inclusive: true,
+            end_inclusive: false,
+            after: None,
+        }
+    }
+}
+
+impl ScanOptions {
+    /// Normalize the bounds and resume key into a half-open range [lower, upper)
+    pub(crate) fn bounds(&self, start: &[u8], end: &[u8]) -> (Vec<u8>, Vec<u8>) {
+        let mut lower = if self.start_inclusive {
+            start.to_vec()
+        } else {
+            next_key(start)
+        };
+        let mut upper = if self.end_inclusive {
+            next_key(end)
+        } else {
+            end.to_vec()
+        };
+
+        if let Some(after) = &self.after {
+            if self.reverse {
+                upper = upper.min(after.clone());
+            } else {
+                lower = lower.max(next_key(after));
+            }
+        }
+
+        (lower, upper)
+    }
+
+    /// The limit as a count, `usize::MAX` when unlimited
+    pub(crate) fn max_entries(&self) -> usize {
+        self.limit.unwrap_or(usize::MAX)
+    }
+}
+
+/// Apply `options` to an in-memory map; `convert` turns a stored value into
+/// bytes, returning `None` for entries that should be skipped
+pub(crate) fn select_from_map<V>(
+    data: &BTreeMap<Vec<u8>, V>,
+    start: &[u8],
+    end: &[u8],
+    options: &ScanOptions,
+    convert: impl Fn(&V) -> Option<Vec<u8>>,
+) -> Vec<(Vec<u8>, Vec<u8>)> {
+    let (lower, upper) = options.bounds(start, end);
+    if lower >= upper {
+        return Vec::new();
+    }
+
+    let range = data.range(lower..upper);
+    let entries: Box<dyn Iterator<Item = (&Vec<u8>, &V)>> = if options.reverse {
+        Box::new(range.rev())
+    } else {
+        Box::new(range)
+    };
+
+    entries
+        .filter_map(|(key, value)| convert(value).map(|value| (key.clone(), value)))
+        .take(options.max_entries())
+        .collect()
+}
+
+/// Number of entries fetched per cursor step by `chunked_scan`
+pub(crate) const SCAN_CHUNK_SIZE: usize = 1024;
+
+/// The smallest key that sorts strictly after `key`
+pub(crate) fn next_key(key: &[u8]) -> Vec<u8> {
+    let mut next = Vec::with_capacity(key.len() + 1);
+    next.extend_from_slice(key);
+    next.push(0);
+    next
+}
+
+/// The smallest key greater than every key starting with `prefix`, or `None`
+/// when no such key exists (the prefix is empty or all `0xff` bytes)
+pub fn prefix_successor(prefix: &[u8]) -> Option<Vec<u8>> {
+    let mut successor = prefix.to_vec();
+    while let Some(last) = successor.pop() {
+        if last < 0xff {
+            successor.push(last + 1);
+            return Some(successor);
+        }
+    }
+    None
+}
+
+/// Range bounds covering exactly the keys that start with `prefix`
+pub(crate) fn prefix_bounds(prefix: &[u8]) -> (Bound<Vec<u8>>, Bound<Vec<u8>>) {
+    let upper = match prefix_successor(prefix) {
+        Some(successor) => Bound::Excluded(successor),
+        None => Bound::Unbounded,
+    };
+    (Bound::Included(prefix.to_vec()), upper)
+}
+
+/// Stream the range [start, end) by repeatedly calling `fetch(from, end, limit)`,
+/// which must return up to `limit` entries with keys in [from, end) in key order.
+/// Used by backends whose cursors can't outlive a lock or transaction: each
+/// chunk is read with a short-lived cursor and the next one resumes just after
+/// the last key seen, so at most `SCAN_CHUNK_SIZE` entries are held at a time.
+pub(crate) fn chunked_scan<'a, F, Fut>(
+    start: &[u8],
+    end: &[u8],
+    fetch: F,
+) -> impl Stream<Item = Result<(Vec<u8>, Vec<u8>)>> + 'a
+where
+    F: FnMut(Vec<u8>, Vec<u8>, usize) -> Fut + 'a,
+    Fut: Future<Output = Result<Vec<(Vec<u8>, Vec<u8>)>>> + 'a,
+{
+    let end = end.to_vec();
+    futures::stream::unfold((fetch, Some(start.to_vec())), move |(mut fetch, from)| {
+        let end = end.clone();
+        async move {
+            let from = from.filter(|from| *from < end)?;
+            match fetch(from, end, SCAN_CHUNK_SIZE).await {
+                Ok(chunk) => {
+                    let resume = if chunk.len() < SCAN_CHUNK_SIZE {
+                        None
+                    } else {
+                        chunk.last().map(|(key, _)| next_key(key))
+                    };
+                    Some((Ok(chunk), (fetch, resume)))
+                }
+                Err(e) => Some((Err(e), (fetch, None))),
+            }
+        }
+    })
+    .flat_map(|chunk| {
+        let items: Vec<Result<(Vec<u8>, Vec<u8>)>> = match chunk {
+            Ok(chunk) => chunk.into_iter().map(Ok).collect(),
+            Err(e) => vec![Err(e)],
+        };
+        futures::stream::iter(items)
+    })
+}
@@ -1,25 +1,197 @@
-use crate::database::{Database, Result as DbResult};
-use crate::sled::SledDatabase;
-use crate::versedb_capnp::versedb;
+use crate::database::{
+    Database, DatabaseError, Result as DbResult, ScanOptions, Snapshot, WatchEvent, WriteBatch,
+};
+use crate::versedb_capnp::{batch_op, scan_options, snapshot, versedb, watch_handle};
 use capnp::Error;
 use capnp::capability::{Client, FromServer, Promise};
 use capnp_rpc::{RpcSystem, rpc_twoparty_capnp, twoparty};
 use futures::AsyncReadExt;
+use futures::StreamExt;
+use futures::future::{AbortHandle, abortable};
 use std::collections::BTreeMap;
+use std::future::Future;
 use std::net::ToSocketAddrs;
-use std::sync::{Arc, Mutex};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::{OwnedRwLockReadGuard, OwnedRwLockWriteGuard, RwLock};
+
+/// Convert wire scan options; a missing options struct reads as the defaults
+fn read_scan_options(reader: scan_options::Reader) -> capnp::Result<ScanOptions> {
+    Ok(ScanOptions {
+        reverse: reader.get_reverse(),
+        limit: match reader.get_limit() {
+            0 => None,
+            limit => Some(limit as usize),
+        },
+        start_inclusive: !reader.get_start_exclusive(),
+        end_inclusive: reader.get_end_inclusive(),
+        after: if reader.has_after() {
+            Some(reader.get_after()?.to_vec())
+        } else {
+            None
+        },
+    })
+}
+
+/// Convert the wire operations of a `writeBatch` call, in order
+fn read_batch(ops: capnp::struct_list::Reader<batch_op::Owned>) -> capnp::Result<WriteBatch> {
+    let mut batch = WriteBatch::new();
+    for op in ops.iter() {
+        match op.which()? {
+            batch_op::Put(pair) => {
+                let pair = pair?;
+                batch.put(pair.get_key()?, pair.get_value()?);
+            }
+            batch_op::Delete(key) => {
+                batch.delete(key?);
+            }
+            batch_op::DeleteRange(range) => {
+                let range = range?;
+                batch.delete_range(range.get_start()?, range.get_end()?);
+            }
+        }
+    }
+    Ok(batch)
+}
+
+/// How often the server purges expired entries that nothing has read
+const SWEEP_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Server side of a `WatchHandle`; the client dropping its handle releases
+/// this, which stops forwarding events to its watcher
+struct WatchGuard {
+    forwarding: AbortHandle,
+}
+
+impl watch_handle::Server for WatchGuard {}
+
+impl Drop for WatchGuard {
+    fn drop(&mut self) {
+        self.forwarding.abort();
+    }
+}
 
+/// Server side of a `Snapshot`, answering reads from the view taken for
+/// one `snapshot` call until the client drops it
+struct SnapshotServer {
+    snapshot: Arc<dyn Snapshot>,
+}
+
+/// Read a request parameter, answering a malformed request with an
+/// `InvalidArgument` error where `unwrap` would panic the server task
+macro_rules! param {
+    ($read:expr) => {
+        match $read {
+            Ok(value) => value,
+            Err(e) => {
+                let error = DatabaseError::InvalidArgument(format!("malformed request: {}", e));
+                return Promise::err(error.to_rpc());
+            }
+        }
+    };
+}
+
+impl snapshot::Server for SnapshotServer {
+    fn select(
+        &mut self,
+        params: snapshot::SelectParams,
+        mut results: snapshot::SelectResults,
+    ) -> Promise<(), Error> {
+        let key = param!(param!(params.get()).get_key()).to_vec();
+        let snapshot = self.snapshot.clone();
+
+        Promise::from_future(async move {
+            let value = blocking(snapshot, move |snapshot| async move {
+                snapshot.select(&key).await
+            })
+            .await?;
+            match value {
+                Some(value) => results.get().set_value(&value),
+                None => results.get().set_found(false),
+            }
+            Ok(())
+        })
+    }
+
+    fn select_range(
+        &mut self,
+        params: snapshot::SelectRangeParams,
+        mut results: snapshot::SelectRangeResults,
+    ) -> Promise<(), Error> {
+        let range = param!(param!(params.get()).get_range());
+        let start = param!(range.get_start()).to_vec();
+        let end = param!(range.get_end()).to_vec();
+        let snapshot = self.snapshot.clone();
+
+        Promise::from_future(async move {
+            let pairs = blocking(snapshot, move |snapshot| async move {
+                snapshot.select_range(&start, &end).await
+            })
+            .await?;
+            let mut pairs_builder = results.get().init_pairs(pairs.len() as u32);
+
+            for (i, (key, value)) in pairs.iter().enumerate() {
+                let mut pair = pairs_builder.reborrow().get(i as u32);
+                pair.set_key(key);
+                pair.set_value(value);
+            }
+
+            Ok(())
+        })
+    }
+}
+
+/// Run a store operation on the blocking thread pool. The backends do their
+/// I/O synchronously, which on the RPC event loop would stall every
+/// connection it serves.
+async fn blocking<G, F, Fut, R>(store: G, operation: F) -> Result<R, Error>
+where
+    G: Send + 'static,
+    F: FnOnce(G) -> Fut + Send + 'static,
+    Fut: Future<Output = DbResult<R>>,
+    R: Send + 'static,
+{
+    tokio::task::spawn_blocking(move || futures::executor::block_on(operation(store)))
+        .await
+        .map_err(|e| Error::failed(format!("{}", e)))?
+        .map_err(|e| DatabaseError::of(&e).to_rpc())
+}
+
+/// Serves a store to any number of connections. Reads, and writes taking
+/// `&self` which the backends synchronize themselves, share the store and run
+/// concurrently; writes taking `&mut self` wait for exclusive access. Its
+/// clones share the store, so each connection is given one.
 #[derive(Clone)]
 pub struct VerseDbServer<T: Database + Clone + Send + Sync + 'static> {
-    store: Arc<Mutex<T>>,
+    store: Arc<RwLock<T>>,
 }
 
 impl<T: Database + Clone + Send + Sync + 'static> VerseDbServer<T> {
     pub fn new(store: T) -> Self {
         Self {
-            store: Arc::new(Mutex::new(store)),
+            store: Arc::new(RwLock::new(store)),
         }
     }
+
+    /// Run `operation` with shared access to the store
+    async fn read<F, Fut, R>(store: Arc<RwLock<T>>, operation: F) -> Result<R, Error>
+    where
+        F: FnOnce(OwnedRwLockReadGuard<T>) -> Fut + Send + 'static,
+        Fut: Future<Output = DbResult<R>>,
+        R: Send + 'static,
+    {
+        blocking(store.read_owned().await, operation).await
+    }
+
+    /// Run `operation` with exclusive access to the store
+    async fn write<F, Fut, R>(store: Arc<RwLock<T>>, operation: F) -> Result<R, Error>
+    where
+        F: FnOnce(OwnedRwLockWriteGuard<T>) -> Fut + Send + 'static,
+        Fut: Future<Output = DbResult<R>>,
+        R: Send + 'static,
+    {
+        blocking(store.write_owned().await, operation).await
+    }
 }
 
 impl<T: Database + Clone + Send + Sync + 'static> versedb::Server for VerseDbServer<T> {
@@ -28,17 +200,15 @@ impl<T: Database + Clone + Send + Sync + 'static> versedb::Server for VerseDbSer
         params: versedb::AddParams,
         mut results: versedb::AddResults,
     ) -> Promise<(), Error> {
-        let key = params.get().unwrap().get_key().unwrap().to_vec();
-        let value = params.get().unwrap().get_value().unwrap().to_vec();
+        let key = param!(param!(params.get()).get_key()).to_vec();
+        let value = param!(param!(params.get()).get_value()).to_vec();
 
         let store = self.store.clone();
         Promise::from_future(async move {
-            store
-                .lock()
-                .unwrap()
-                .add(&key, &value)
-                .await
-                .map_err(|e| Error::failed(format!("{}", e)))?;
+            Self::write(store, move |mut store| async move {
+                store.add(&key, &value).await
+            })
+            .await?;
             Ok(())
         })
     }
@@ -48,18 +218,15 @@ impl<T: Database + Clone + Send + Sync + 'static> versedb::Server for VerseDbSer
         params: versedb::SelectParams,
         mut results: versedb::SelectResults,
     ) -> Promise<(), Error> {
-        let key = params.get().unwrap().get_key().unwrap().to_vec();
+        let key = param!(param!(params.get()).get_key()).to_vec();
         let store = self.store.clone();
 
         Promise::from_future(async move {
-            if let Some(value) = store
-                .lock()
-                .unwrap()
-                .select(&key)
-                .await
-                .map_err(|e| Error::failed(format!("{}", e)))?
-            {
-                results.get().set_value(&value);
+            let value =
+                Self::read(store, move |store| async move { store.select(&key).await }).await?;
+            match value {
+                Some(value) => results.get().set_value(&value),
+                None => results.get().set_found(false),
             }
             Ok(())
         })
@@ -70,16 +237,15 @@ impl<T: Database + Clone + Send + Sync + 'static> versedb::Server for VerseDbSer
         params: versedb::RemoveParams,
         _results: versedb::RemoveResults,
     ) -> Promise<(), Error> {
-        let key = params.get().unwrap().get_key().unwrap().to_vec();
+        let key = param!(param!(params.get()).get_key()).to_vec();
         let store = self.store.clone();
 
         Promise::from_future(async move {
-            store
-                .lock()
-                .unwrap()
-                .remove(&key)
-                .await
-                .map_err(|e| Error::failed(format!("{}", e)))?;
+            Self::write(
+                store,
+                move |mut store| async move { store.remove(&key).await },
+            )
+            .await?;
             Ok(())
         })
     }
@@ -89,18 +255,18 @@ impl<T: Database + Clone + Send + Sync + 'static> versedb::Server for VerseDbSer
         params: versedb::SelectRangeParams,
         mut results: versedb::SelectRangeResults,
     ) -> Promise<(), Error> {
-        let range = params.get().unwrap().get_range().unwrap();
-        let start = range.get_start().unwrap().to_vec();
-        let end = range.get_end().unwrap().to_vec();
+        let params = param!(params.get());
+        let range = param!(params.get_range());
+        let start = param!(range.get_start()).to_vec();
+        let end = param!(range.get_end()).to_vec();
+        let options = param!(read_scan_options(param!(params.get_options())));
         let store = self.store.clone();
 
         Promise::from_future(async move {
-            let pairs = store
-                .lock()
-                .unwrap()
-                .select_range(&start, &end)
-                .await
-                .map_err(|e| Error::failed(format!("{}", e)))?;
+            let pairs = Self::read(store, move |store| async move {
+                store.select_range_with(&start, &end, &options).await
+            })
+            .await?;
             let mut pairs_builder = results.get().init_pairs(pairs.len() as u32);
 
             for (i, (key, value)) in pairs.iter().enumerate() {
@@ -118,18 +284,16 @@ impl<T: Database + Clone + Send + Sync + 'static> versedb::Server for VerseDbSer
         params: versedb::RemoveRangeParams,
         mut results: versedb::RemoveRangeResults,
     ) -> Promise<(), Error> {
-        let range = params.get().unwrap().get_range().unwrap();
-        let start = range.get_start().unwrap().to_vec();
-        let end = range.get_end().unwrap().to_vec();
+        let range = param!(param!(params.get()).get_range());
+        let start = param!(range.get_start()).to_vec();
+        let end = param!(range.get_end()).to_vec();
         let store = self.store.clone();
 
         Promise::from_future(async move {
-            let pairs = store
-                .lock()
-                .unwrap()
-                .remove_range(&start, &end)
-                .await
-                .map_err(|e| Error::failed(format!("{}", e)))?;
+            let pairs = Self::read(store, move |store| async move {
+                store.remove_range(&start, &end).await
+            })
+            .await?;
             let mut pairs_builder = results.get().init_pairs(pairs.len() as u32);
 
             for (i, (key, value)) in pairs.iter().enumerate() {
@@ -142,97 +306,321 @@ impl<T: Database + Clone + Send + Sync + 'static> versedb::Server for VerseDbSer
         })
     }
 
-    fn helloworld(
+    fn scan_prefix(
         &mut self,
-        params: versedb::HelloworldParams,
-        mut results: versedb::HelloworldResults,
+        params: versedb::ScanPrefixParams,
+        mut results: versedb::ScanPrefixResults,
     ) -> Promise<(), Error> {
-        let input = params.get().unwrap().get_input().unwrap();
-        let input_str = input.to_str().unwrap();
-        results.get().set_output(&format!("Hello, {}!", input_str));
-        Promise::ok(())
+        let prefix = param!(param!(params.get()).get_prefix()).to_vec();
+        let store = self.store.clone();
+
+        Promise::from_future(async move {
+            let pairs = Self::read(store, move |store| async move {
+                store.scan_prefix(&prefix).await
+            })
+            .await?;
+            let mut pairs_builder = results.get().init_pairs(pairs.len() as u32);
+
+            for (i, (key, value)) in pairs.iter().enumerate() {
+                let mut pair = pairs_builder.reborrow().get(i as u32);
+                pair.set_key(key);
+                pair.set_value(value);
+            }
+
+            Ok(())
+        })
     }
 
-    fn flush(
+    fn remove_prefix(
         &mut self,
-        _params: versedb::FlushParams,
-        _results: versedb::FlushResults,
+        params: versedb::RemovePrefixParams,
+        mut results: versedb::RemovePrefixResults,
     ) -> Promise<(), Error> {
+        let prefix = param!(param!(params.get()).get_prefix()).to_vec();
         let store = self.store.clone();
+
         Promise::from_future(async move {
-            store
-                .lock()
-                .unwrap()
-                .flush()
-                .await
-                .map_err(|e| Error::failed(format!("{}", e)))?;
+            let pairs = Self::read(store, move |store| async move {
+                store.remove_prefix(&prefix).await
+            })
+            .await?;
+            let mut pairs_builder = results.get().init_pairs(pairs.len() as u32);
+
+            for (i, (key, value)) in pairs.iter().enumerate() {
+                let mut pair = pairs_builder.reborrow().get(i as u32);
+                pair.set_key(key);
+                pair.set_value(value);
+            }
+
             Ok(())
         })
     }
-}
 
-impl<T: Database + Clone + Send + Sync + 'static> versedb::Server for Arc<VerseDbServer<T>> {
-    fn add(
+    fn compare_and_swap(
         &mut self,
-        params: versedb::AddParams,
-        results: versedb::AddResults,
+        params: versedb::CompareAndSwapParams,
+        mut results: versedb::CompareAndSwapResults,
     ) -> Promise<(), Error> {
-        let mut server = self.as_ref().clone();
-        server.add(params, results)
+        // A null pointer stands for an absent value, as opposed to an empty one
+        let params = param!(params.get());
+        let key = param!(params.get_key()).to_vec();
+        let expected = if params.has_expected() {
+            Some(param!(params.get_expected()).to_vec())
+        } else {
+            None
+        };
+        let new = if params.has_new() {
+            Some(param!(params.get_new()).to_vec())
+        } else {
+            None
+        };
+        let store = self.store.clone();
+
+        Promise::from_future(async move {
+            let outcome = Self::write(store, move |mut store| async move {
+                store
+                    .compare_and_swap(&key, expected.as_deref(), new.as_deref())
+                    .await
+            })
+            .await?;
+
+            match outcome {
+                Ok(()) => results.get().set_swapped(true),
+                Err(conflict) => {
+                    if let Some(current) = conflict.current {
+                        results.get().set_current(&current);
+                    }
+                }
+            }
+            Ok(())
+        })
     }
 
-    fn select(
+    fn increment(
         &mut self,
-        params: versedb::SelectParams,
-        results: versedb::SelectResults,
+        params: versedb::IncrementParams,
+        mut results: versedb::IncrementResults,
     ) -> Promise<(), Error> {
-        let mut server = self.as_ref().clone();
-        server.select(params, results)
+        let params = param!(params.get());
+        let key = param!(params.get_key()).to_vec();
+        let delta = params.get_delta();
+        let store = self.store.clone();
+
+        Promise::from_future(async move {
+            let value = Self::write(store, move |mut store| async move {
+                store.increment(&key, delta).await
+            })
+            .await?;
+            results.get().set_value(value);
+            Ok(())
+        })
     }
 
-    fn remove(
+    fn add_with_ttl(
         &mut self,
-        params: versedb::RemoveParams,
-        results: versedb::RemoveResults,
+        params: versedb::AddWithTtlParams,
+        _results: versedb::AddWithTtlResults,
     ) -> Promise<(), Error> {
-        let mut server = self.as_ref().clone();
-        server.remove(params, results)
+        let params = param!(params.get());
+        let key = param!(params.get_key()).to_vec();
+        let value = param!(params.get_value()).to_vec();
+        let ttl = Duration::from_millis(params.get_ttl_millis());
+        let store = self.store.clone();
+
+        Promise::from_future(async move {
+            Self::write(store, move |mut store| async move {
+                store.add_with_ttl(&key, &value, ttl).await
+            })
+            .await?;
+            Ok(())
+        })
     }
 
-    fn select_range(
+    fn ttl(
         &mut self,
-        params: versedb::SelectRangeParams,
-        results: versedb::SelectRangeResults,
+        params: versedb::TtlParams,
+        mut results: versedb::TtlResults,
     ) -> Promise<(), Error> {
-        let mut server = self.as_ref().clone();
-        server.select_range(params, results)
+        let key = param!(param!(params.get()).get_key()).to_vec();
+        let store = self.store.clone();
+
+        Promise::from_future(async move {
+            let remaining =
+                Self::read(store, move |store| async move { store.ttl(&key).await }).await?;
+
+            if let Some(remaining) = remaining {
+                let mut results = results.get();
+                results.set_has_ttl(true);
+                results.set_remaining_millis(remaining.as_millis() as u64);
+            }
+            Ok(())
+        })
     }
 
-    fn remove_range(
+    fn watch_prefix(
         &mut self,
-        params: versedb::RemoveRangeParams,
-        results: versedb::RemoveRangeResults,
+        params: versedb::WatchPrefixParams,
+        mut results: versedb::WatchPrefixResults,
     ) -> Promise<(), Error> {
-        let mut server = self.as_ref().clone();
-        server.remove_range(params, results)
+        let params = param!(params.get());
+        let prefix = param!(params.get_prefix()).to_vec();
+        let watcher = param!(params.get_watcher());
+        let store = self.store.clone();
+
+        Promise::from_future(async move {
+            let mut events = store.read().await.watch_prefix(&prefix);
+            let (forward, forwarding) = abortable(async move {
+                while let Some(event) = events.next().await {
+                    let mut request = watcher.event_request();
+                    {
+                        let mut builder = request.get().init_event();
+                        builder.set_key(event.key());
+                        match &event {
+                            WatchEvent::Put { value, .. } => builder.set_put(value),
+                            WatchEvent::Delete { .. } => builder.set_delete(()),
+                        }
+                    }
+                    // Waiting for each call keeps a slow watcher from queueing
+                    // events without bound; a failed call means it has gone away
+                    if request.send().promise.await.is_err() {
+                        break;
+                    }
+                }
+            });
+            tokio::task::spawn_local(forward);
+
+            results
+                .get()
+                .set_handle(capnp_rpc::new_client(WatchGuard { forwarding }));
+            Ok(())
+        })
     }
 
     fn helloworld(
         &mut self,
         params: versedb::HelloworldParams,
-        results: versedb::HelloworldResults,
+        mut results: versedb::HelloworldResults,
     ) -> Promise<(), Error> {
-        let mut server = self.as_ref().clone();
-        server.helloworld(params, results)
+        let input = param!(param!(params.get()).get_input());
+        let input_str = param!(input.to_str());
+        results.get().set_output(&format!("Hello, {}!", input_str));
+        Promise::ok(())
+    }
+
+    fn write_batch(
+        &mut self,
+        params: versedb::WriteBatchParams,
+        _results: versedb::WriteBatchResults,
+    ) -> Promise<(), Error> {
+        let batch = param!(read_batch(param!(param!(params.get()).get_ops())));
+        let store = self.store.clone();
+
+        Promise::from_future(async move {
+            Self::write(store, move |mut store| async move {
+                store.write_batch(batch).await
+            })
+            .await
+        })
+    }
+
+    fn purge_expired(
+        &mut self,
+        _params: versedb::PurgeExpiredParams,
+        mut results: versedb::PurgeExpiredResults,
+    ) -> Promise<(), Error> {
+        let store = self.store.clone();
+        Promise::from_future(async move {
+            let purged =
+                Self::read(store, |store| async move { store.purge_expired().await }).await?;
+            results.get().set_purged(purged as u64);
+            Ok(())
+        })
+    }
+
+    fn snapshot(
+        &mut self,
+        _params: versedb::SnapshotParams,
+        mut results: versedb::SnapshotResults,
+    ) -> Promise<(), Error> {
+        let store = self.store.clone();
+        Promise::from_future(async move {
+            let snapshot = Self::read(store, |store| async move { store.snapshot().await }).await?;
+            results
+                .get()
+                .set_snapshot(capnp_rpc::new_client(SnapshotServer {
+                    snapshot: Arc::from(snapshot),
+                }));
+            Ok(())
+        })
+    }
+
+    fn open_tree(
+        &mut self,
+        params: versedb::OpenTreeParams,
+        mut results: versedb::OpenTreeResults,
+    ) -> Promise<(), Error> {
+        let name = param!(param!(params.get()).get_name());
+        let name = param!(name.to_string());
+        let store = self.store.clone();
+
+        Promise::from_future(async move {
+            let tree = Self::read(
+                store,
+                move |store| async move { store.open_tree(&name).await },
+            )
+            .await?;
+            results
+                .get()
+                .set_tree(capnp_rpc::new_client(VerseDbServer::new(tree)));
+            Ok(())
+        })
+    }
+
+    fn list_trees(
+        &mut self,
+        _params: versedb::ListTreesParams,
+        mut results: versedb::ListTreesResults,
+    ) -> Promise<(), Error> {
+        let store = self.store.clone();
+        Promise::from_future(async move {
+            let names = Self::read(store, |store| async move { store.list_trees().await }).await?;
+            let mut names_builder = results.get().init_names(names.len() as u32);
+            for (i, name) in names.iter().enumerate() {
+                names_builder.set(i as u32, name);
+            }
+            Ok(())
+        })
+    }
+
+    fn drop_tree(
+        &mut self,
+        params: versedb::DropTreeParams,
+        mut results: versedb::DropTreeResults,
+    ) -> Promise<(), Error> {
+        let name = param!(param!(params.get()).get_name());
+        let name = param!(name.to_string());
+        let store = self.store.clone();
+
+        Promise::from_future(async move {
+            let existed = Self::read(
+                store,
+                move |store| async move { store.drop_tree(&name).await },
+            )
+            .await?;
+            results.get().set_existed(existed);
+            Ok(())
+        })
     }
 
     fn flush(
         &mut self,
-        params: versedb::FlushParams,
-        results: versedb::FlushResults,
+        _params: versedb::FlushParams,
+        _results: versedb::FlushResults,
     ) -> Promise<(), Error> {
-        let mut server = self.as_ref().clone();
-        server.flush(params, results)
+        let store = self.store.clone();
+        Promise::from_future(async move {
+            Self::write(store, |mut store| async move { store.flush().await }).await
+        })
     }
 }
 
@@ -240,6 +628,21 @@ pub async fn run_server<T: Database + Clone + Send + Sync + 'static>(
     addr: &str,
     store: T,
 ) -> anyhow::Result<()> {
+    let listener = bind(addr).await?;
+    serve(listener, store).await
+}
+
+/// Like `run_server`, but spreading connections over `workers` threads
+pub async fn run_server_with_workers<T: Database + Clone + Send + Sync + 'static>(
+    addr: &str,
+    store: T,
+    workers: usize,
+) -> anyhow::Result<()> {
+    let listener = bind(addr).await?;
+    serve_with_workers(listener, store, workers).await
+}
+
+async fn bind(addr: &str) -> anyhow::Result<tokio::net::TcpListener> {
     let addr = addr
         .to_socket_addrs()?
         .next()
@@ -247,54 +650,142 @@ pub async fn run_server<T: Database + Clone + Send + Sync + 'static>(
 
     let listener = tokio::net::TcpListener::bind(&addr).await?;
     println!("Server listening on {}", addr);
+    Ok(listener)
+}
+
+/// Purge expired entries of `store` every `SWEEP_INTERVAL`. Reads already
+/// skip expired entries; the sweeper reclaims the space of those nothing
+/// reads again.
+async fn sweep_expired<T: Database + Clone + Send + Sync + 'static>(store: Arc<RwLock<T>>) {
+    let mut interval = tokio::time::interval(SWEEP_INTERVAL);
+    loop {
+        interval.tick().await;
+        let purged =
+            VerseDbServer::read(
+                store.clone(),
+                |store| async move { store.purge_expired().await },
+            );
+        if let Err(e) = purged.await {
+            eprintln!("Failed to purge expired entries: {}", e);
+        }
+    }
+}
+
+/// Run the RPC system of one connection on the current `LocalSet`
+fn spawn_connection<T: Database + Clone + Send + Sync + 'static>(
+    server: &VerseDbServer<T>,
+    stream: tokio::net::TcpStream,
+) -> anyhow::Result<()> {
+    stream.set_nodelay(true)?;
+    let stream = tokio_util::compat::TokioAsyncReadCompatExt::compat(stream);
+    let (reader, writer) = stream.split();
+
+    let rpc_network = Box::new(twoparty::VatNetwork::new(
+        reader,
+        writer,
+        rpc_twoparty_capnp::Side::Server,
+        Default::default(),
+    ));
 
+    let client: versedb::Client = capnp_rpc::new_client(server.clone());
+    let rpc_system = RpcSystem::new(rpc_network, Some(client.client));
+
+    tokio::task::spawn_local(rpc_system);
+    Ok(())
+}
+
+/// Serve `store` to every connection accepted on `listener`. The RPC system
+/// runs on the calling thread; store operations run on the blocking pool.
+pub async fn serve<T: Database + Clone + Send + Sync + 'static>(
+    listener: tokio::net::TcpListener,
+    store: T,
+) -> anyhow::Result<()> {
     let server = VerseDbServer::new(store);
-    let server = Arc::new(server);
     let local = tokio::task::LocalSet::new();
 
     local
         .run_until(async move {
-            let result: anyhow::Result<()> = async move {
-                loop {
-                    let (stream, _) = listener.accept().await?;
-                    stream.set_nodelay(true)?;
-                    let stream = tokio_util::compat::TokioAsyncReadCompatExt::compat(stream);
-                    let (reader, writer) = stream.split();
-
-                    let rpc_network = Box::new(twoparty::VatNetwork::new(
-                        reader,
-                        writer,
-                        rpc_twoparty_capnp::Side::Server,
-                        Default::default(),
-                    ));
-
-                    let server = server.clone();
-                    let client: versedb::Client = capnp_rpc::new_client(server);
-                    let rpc_system = RpcSystem::new(rpc_network, Some(client.client));
-
-                    tokio::task::spawn_local(rpc_system);
+            tokio::task::spawn_local(sweep_expired(server.store.clone()));
+
+            loop {
+                let (stream, _) = listener.accept().await?;
+                if let Err(e) = spawn_connection(&server, stream) {
+                    eprintln!("Failed to serve a connection: {}", e);
                 }
             }
-            .await;
-            result
         })
-        .await?;
-
-    Ok(())
+        .await
 }
 
-#[tokio::main]
-#[cfg(not(target_arch = "wasm32"))]
-async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    // Create a CSV database
-    let db = SledDatabase::open("data.sled").await?;
+/// Serve `store` to the connections accepted on `listener`, handing them out
+/// in turn to `workers` threads. Each worker runs the RPC systems of its
+/// connections on a `LocalSet` of its own, so they use as many cores as there
+/// are workers; all of them share the one store.
+pub async fn serve_with_workers<T: Database + Clone + Send + Sync + 'static>(
+    listener: tokio::net::TcpListener,
+    store: T,
+    workers: usize,
+) -> anyhow::Result<()> {
+    if workers == 0 {
+        anyhow::bail!("the server needs at least one worker");
+    }
+    let server = VerseDbServer::new(store);
+
+    let mut queues = Vec::with_capacity(workers);
+    for worker in 0..workers {
+        let (queue, connections) = tokio::sync::mpsc::unbounded_channel();
+        let server = server.clone();
+        std::thread::Builder::new()
+            .name(format!("versedb-worker-{}", worker))
+            .spawn(move || {
+                if let Err(e) = run_worker(server, connections) {
+                    eprintln!("Server worker failed: {}", e);
+                }
+            })?;
+        queues.push(queue);
+    }
 
-    // Run the server on localhost:8000 with the CSV database
-    run_server("127.0.0.1:8000", db).await?;
-    Ok(())
+    tokio::spawn(sweep_expired(server.store.clone()));
+
+    for queue in queues.iter().cycle() {
+        let (stream, _) = listener.accept().await?;
+        // A stream stays registered with the runtime that accepted it until
+        // it is handed over as a std stream
+        let stream = match stream.into_std() {
+            Ok(stream) => stream,
+            Err(e) => {
+                eprintln!("Failed to hand over a connection: {}", e);
+                continue;
+            }
+        };
+        if queue.send(stream).is_err() {
+            anyhow::bail!("a server worker stopped");
+        }
+    }
+    unreachable!("cycling over at least one worker never ends")
 }
 
-#[cfg(target_arch = "wasm32")]
-fn main() {
-    // Empty main function for wasm32 target
+/// Body of a worker thread: serve each connection handed to it until the
+/// accepting side goes away. A connection that can't be set up is dropped
+/// without stopping the worker.
+fn run_worker<T: Database + Clone + Send + Sync + 'static>(
+    server: VerseDbServer<T>,
+    mut connections: tokio::sync::mpsc::UnboundedReceiver<std::net::TcpStream>,
+) -> anyhow::Result<()> {
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()?;
+    let local = tokio::task::LocalSet::new();
+
+    local.block_on(&runtime, async move {
+        while let Some(stream) = connections.recv().await {
+            let served = tokio::net::TcpStream::from_std(stream)
+                .map_err(anyhow::Error::from)
+                .and_then(|stream| spawn_connection(&server, stream));
+            if let Err(e) = served {
+                eprintln!("Failed to serve a connection: {}", e);
+            }
+        }
+    });
+    Ok(())
 }
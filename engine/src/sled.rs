@@ -1,17 +1,337 @@
-use super::database::{Database, Result};
+use super::batch::WriteBatch;
+use super::database::{
+    CompareAndSwapError, Database, DatabaseError, KvStream, Result, ScanOptions, Transaction,
+    WatchEvent, WatchStream,
+};
+use super::merge::increment_in;
+use super::snapshot::Snapshot;
+use super::transaction::compare_and_swap_in;
+use super::tree::{TreeRegistry, check_tree_name};
+use super::ttl::{
+    ExpiredKeys, deadline_after, deadline_key, decode_expiry, decode_queue_key, encode_expiry,
+    expired_queue_range, fingerprint, has_expired, now_millis, queue_key, remaining_until,
+};
 use async_trait::async_trait;
-use sled::Db;
-use std::sync::Mutex;
+use sled::transaction::{
+    ConflictableTransactionError, TransactionError, TransactionalTree, UnabortableTransactionError,
+};
+use sled::{Db, Event, IVec, Transactional, Tree};
+use std::cell::Cell;
+use std::collections::BTreeMap;
+use std::sync::{Arc, Mutex, MutexGuard, Weak};
+use std::time::Duration;
+
+/// Tree holding the expiry indexes of entries added with a TTL
+const EXPIRY_TREE: &str = "__versedb_expiry";
+
+/// Tree holding the expiry indexes of the named tree `name`
+fn expiry_tree_name(name: &str) -> String {
+    format!("{}.{}", EXPIRY_TREE, name)
+}
+
+/// Values keys held when a snapshot was taken, for the keys written since
+type Preimages = BTreeMap<Vec<u8>, Option<IVec>>;
+
+/// Preimages of the live snapshots of a tree, shared by every handle on it.
+/// sled has no snapshots of its own, so writers keep what they overwrite in
+/// each snapshot instead of snapshots copying the tree.
+#[derive(Clone, Default)]
+struct SnapshotLogs(Arc<Mutex<Vec<Weak<Mutex<Preimages>>>>>);
+
+impl SnapshotLogs {
+    /// Start a log for a new snapshot. Called with the writer lock held, so
+    /// no write is halfway done.
+    fn open(&self) -> Arc<Mutex<Preimages>> {
+        let log = Arc::default();
+        self.0.lock().unwrap().push(Arc::downgrade(&log));
+        log
+    }
+
+    /// Keep the value `key` holds in every live snapshot that has none for it
+    /// yet, reading it through `current`. Writers call this with the writer
+    /// lock held, before the write; a preimage kept for a write that then
+    /// fails is still the key's value, so it does no harm.
+    fn record<E>(
+        &self,
+        key: &[u8],
+        current: impl FnOnce() -> std::result::Result<Option<IVec>, E>,
+    ) -> std::result::Result<(), E> {
+        let logs: Vec<_> = {
+            let mut logs = self.0.lock().unwrap();
+            logs.retain(|log| log.strong_count() > 0);
+            logs.iter().filter_map(Weak::upgrade).collect()
+        };
+        let pending: Vec<_> = logs
+            .iter()
+            .filter(|log| !log.lock().unwrap().contains_key(key))
+            .collect();
+        if pending.is_empty() {
+            return Ok(());
+        }
+
+        let value = current()?;
+        for log in pending {
+            log.lock()
+                .unwrap()
+                .entry(key.to_vec())
+                .or_insert_with(|| value.clone());
+        }
+        Ok(())
+    }
+}
 
 pub struct SledDatabase {
-    db: Mutex<Db>,
+    root: Db,
+    db: Tree,
+    expiry: Tree,
+    /// Held by every write, so read-modify-write operations see nothing
+    /// change between their reads and their commit. Reads don't take it.
+    writer: Arc<Mutex<()>>,
+    snapshots: SnapshotLogs,
+    trees: TreeRegistry<SnapshotLogs>,
 }
 
 impl Clone for SledDatabase {
     fn clone(&self) -> Self {
         Self {
-            db: Mutex::new(self.db.lock().unwrap().clone()),
+            root: self.root.clone(),
+            db: self.db.clone(),
+            expiry: self.expiry.clone(),
+            writer: Arc::clone(&self.writer),
+            snapshots: self.snapshots.clone(),
+            trees: Arc::clone(&self.trees),
+        }
+    }
+}
+
+/// Adapts a sled `TransactionalTree` to the backend-neutral `Transaction` trait
+struct SledTransaction<'a> {
+    tree: &'a TransactionalTree,
+    /// Expiry indexes of the tree, whose records every write clears
+    expiry: &'a TransactionalTree,
+    snapshots: &'a SnapshotLogs,
+    conflict: Cell<bool>,
+}
+
+impl SledTransaction<'_> {
+    fn check(&self, e: UnabortableTransactionError) -> anyhow::Error {
+        if let UnabortableTransactionError::Conflict = e {
+            self.conflict.set(true);
         }
+        e.into()
+    }
+
+    /// Keep the value `key` holds in the live snapshots before writing it
+    fn record(&self, key: &[u8]) -> Result<()> {
+        self.snapshots
+            .record(key, || self.tree.get(key))
+            .map_err(|e| self.check(e))
+    }
+}
+
+impl Transaction for SledTransaction<'_> {
+    fn get(&self, key: &[u8]) -> Result<Option<Vec<u8>>> {
+        let Some(value) = self.tree.get(key).map_err(|e| self.check(e))? else {
+            return Ok(None);
+        };
+        let record = self
+            .expiry
+            .get(deadline_key(key))
+            .map_err(|e| self.check(e))?;
+        Ok((!has_expired(record.as_deref(), &value, now_millis())).then(|| value.to_vec()))
+    }
+
+    fn put(&mut self, key: &[u8], value: &[u8]) -> Result<()> {
+        self.record(key)?;
+        self.tree.insert(key, value).map_err(|e| self.check(e))?;
+        self.expiry
+            .remove(deadline_key(key))
+            .map_err(|e| self.check(e))?;
+        Ok(())
+    }
+
+    fn delete(&mut self, key: &[u8]) -> Result<()> {
+        self.record(key)?;
+        self.tree.remove(key).map_err(|e| self.check(e))?;
+        self.expiry
+            .remove(deadline_key(key))
+            .map_err(|e| self.check(e))?;
+        Ok(())
+    }
+}
+
+/// The outcome of a sled transaction as a `Result`
+fn from_transaction<R>(
+    result: std::result::Result<R, TransactionError<anyhow::Error>>,
+) -> Result<R> {
+    match result {
+        Ok(value) => Ok(value),
+        Err(TransactionError::Abort(e)) => Err(e),
+        Err(TransactionError::Storage(e)) => Err(e.into()),
+    }
+}
+
+/// Reads the tree as it was when the snapshot was taken: keys written since
+/// read their preimage, every other key the live tree
+struct SledSnapshot {
+    db: Tree,
+    preimages: Arc<Mutex<Preimages>>,
+    /// Keys already expired when the snapshot was taken
+    expired: ExpiredKeys,
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl Snapshot for SledSnapshot {
+    async fn select(&self, key: &[u8]) -> Result<Option<Vec<u8>>> {
+        // Writers keep the preimage before writing, so reading the live value
+        // first and the preimages after never misses a write in between
+        let live = self.db.get(key)?;
+        let value = match self.preimages.lock().unwrap().get(key) {
+            Some(preimage) => preimage.clone(),
+            None => live,
+        };
+        Ok(value
+            .filter(|value| !self.expired.contains(key, value))
+            .map(|value| value.to_vec()))
+    }
+
+    async fn select_range(&self, start: &[u8], end: &[u8]) -> Result<Vec<(Vec<u8>, Vec<u8>)>> {
+        if start >= end {
+            return Ok(Vec::new());
+        }
+        let mut entries = BTreeMap::new();
+        for item in self.db.range(start..end) {
+            let (key, value) = item?;
+            entries.insert(key.to_vec(), value);
+        }
+        for (key, preimage) in self
+            .preimages
+            .lock()
+            .unwrap()
+            .range(start.to_vec()..end.to_vec())
+        {
+            match preimage {
+                Some(value) => entries.insert(key.clone(), value.clone()),
+                None => entries.remove(key),
+            };
+        }
+        Ok(entries
+            .into_iter()
+            .filter(|(key, value)| !self.expired.contains(key, value))
+            .map(|(key, value)| (key, value.to_vec()))
+            .collect())
+    }
+}
+
+impl SledDatabase {
+    /// Drop every entry whose TTL has run out, returning how many were removed
+    fn evict_expired(&self) -> Result<usize> {
+        let _writer = self.lock_writer();
+        let (start, end) = expired_queue_range(now_millis());
+        let mut evicted = 0;
+        for item in self.expiry.range(start..end) {
+            let (queue_key, _) = item?;
+            if let Some((deadline, key)) = decode_queue_key(&queue_key) {
+                let record_key = deadline_key(key);
+                let record = self.expiry.get(&record_key)?;
+                let record = record.as_deref().and_then(decode_expiry);
+
+                // The queue entry is stale if the key got a new deadline since
+                if record.is_some_and(|(current, _)| current == deadline) {
+                    if let Some(value) = self.db.get(key)? {
+                        // Only drop the value the TTL was set for, and only if
+                        // nothing replaced it in the meantime
+                        self.snapshots
+                            .record(key, || Ok::<_, sled::Error>(Some(value.clone())))?;
+                        if record == Some((deadline, fingerprint(&value)))
+                            && self
+                                .db
+                                .compare_and_swap(key, Some(value), None::<IVec>)?
+                                .is_ok()
+                        {
+                            evicted += 1;
+                        }
+                    }
+                    self.expiry.remove(&record_key)?;
+                }
+            }
+            self.expiry.remove(&queue_key)?;
+        }
+        Ok(evicted)
+    }
+
+    /// Apply `writes` in one transaction with the removal of their expiry
+    /// records, which makes the keys permanent again. A stale queue entry is
+    /// skipped once its record is gone. Called with the writer lock held.
+    fn apply(&self, writes: &[(Vec<u8>, Option<Vec<u8>>)]) -> Result<()> {
+        for (key, _) in writes {
+            self.snapshots.record(key, || self.db.get(key))?;
+        }
+        let result = (&self.db, &self.expiry).transaction(|(data, expiry)| {
+            for (key, value) in writes {
+                match value {
+                    Some(value) => data.insert(key.as_slice(), value.as_slice())?,
+                    None => data.remove(key.as_slice())?,
+                };
+                expiry.remove(deadline_key(key))?;
+            }
+            Ok::<_, ConflictableTransactionError<anyhow::Error>>(())
+        });
+        from_transaction(result)
+    }
+
+    /// Remove the expiry record of `key` after a single-key write sled makes
+    /// on its own. Should this be interrupted, the stale record no longer
+    /// matches the value's fingerprint and is ignored.
+    fn clear_deadline(&self, key: &[u8]) -> Result<()> {
+        self.expiry.remove(deadline_key(key))?;
+        Ok(())
+    }
+
+    /// Keys whose TTL has run out but that `purge_expired` hasn't removed
+    fn expired_keys(&self) -> Result<ExpiredKeys> {
+        let (start, end) = expired_queue_range(now_millis());
+        let queue = self
+            .expiry
+            .range(start..end)
+            .keys()
+            .map(|key| Ok(key?.to_vec()));
+        ExpiredKeys::collect(queue, |record_key| {
+            Ok(self.expiry.get(record_key)?.map(|v| v.to_vec()))
+        })
+    }
+
+    /// The value of `key`, unless its TTL has run out
+    fn get_live(&self, key: &[u8]) -> Result<Option<Vec<u8>>> {
+        let Some(value) = self.db.get(key)? else {
+            return Ok(None);
+        };
+        let record = self.expiry.get(deadline_key(key))?;
+        Ok((!has_expired(record.as_deref(), &value, now_millis())).then(|| value.to_vec()))
+    }
+
+    /// Remove `key` if its TTL has run out, so a write sled makes on its own
+    /// doesn't build on the expired value. Called with the writer lock held.
+    fn drop_if_expired(&self, key: &[u8]) -> Result<()> {
+        self.snapshots.record(key, || self.db.get(key))?;
+        let result = (&self.db, &self.expiry).transaction(|(data, expiry)| {
+            if let Some(value) = data.get(key)? {
+                let record_key = deadline_key(key);
+                if has_expired(expiry.get(&record_key)?.as_deref(), &value, now_millis()) {
+                    data.remove(key)?;
+                    expiry.remove(record_key)?;
+                }
+            }
+            Ok::<_, ConflictableTransactionError<anyhow::Error>>(())
+        });
+        from_transaction(result)
+    }
+
+    /// Lock out other writers for a write. Expired entries are left in place
+    /// for `purge_expired`, and reads skip them.
+    fn lock_writer(&self) -> MutexGuard<'_, ()> {
+        self.writer.lock().unwrap()
     }
 }
 
@@ -19,65 +339,332 @@ impl Clone for SledDatabase {
 #[cfg_attr(not(target_arch = "wasm32"), async_trait)]
 impl Database for SledDatabase {
     async fn open(path: &str) -> Result<Self> {
-        let db = sled::open(path)?;
-        Ok(Self { db: Mutex::new(db) })
+        let root = sled::open(path)?;
+        let expiry = root.open_tree(EXPIRY_TREE)?;
+        Ok(Self {
+            db: Tree::clone(&root),
+            root,
+            expiry,
+            writer: Arc::default(),
+            snapshots: SnapshotLogs::default(),
+            trees: TreeRegistry::default(),
+        })
     }
 
     async fn close(&mut self) -> Result<()> {
-        self.db.lock().unwrap().flush()?;
+        self.db.flush()?;
         Ok(())
     }
 
     async fn add(&mut self, key: &[u8], value: &[u8]) -> Result<()> {
-        self.db.lock().unwrap().insert(key, value)?;
-        Ok(())
+        let _writer = self.lock_writer();
+        self.apply(&[(key.to_vec(), Some(value.to_vec()))])
     }
 
     async fn select(&self, key: &[u8]) -> Result<Option<Vec<u8>>> {
-        Ok(self.db.lock().unwrap().get(key)?.map(|v| v.to_vec()))
+        self.get_live(key)
     }
 
     async fn remove(&mut self, key: &[u8]) -> Result<()> {
-        self.db.lock().unwrap().remove(key)?;
-        Ok(())
+        let _writer = self.lock_writer();
+        self.apply(&[(key.to_vec(), None)])
     }
 
     async fn select_range(&self, start: &[u8], end: &[u8]) -> Result<Vec<(Vec<u8>, Vec<u8>)>> {
         let mut result = Vec::new();
-        let db = self.db.lock().unwrap();
-        for item in db.range(start..end) {
+        let expired = self.expired_keys()?;
+        for item in self.db.range(start..end) {
             let (key, value) = item?;
-            result.push((key.to_vec(), value.to_vec()));
+            if !expired.contains(&key, &value) {
+                result.push((key.to_vec(), value.to_vec()));
+            }
         }
         Ok(result)
     }
 
     async fn remove_range(&self, start: &[u8], end: &[u8]) -> Result<Vec<(Vec<u8>, Vec<u8>)>> {
-        let mut result = Vec::new();
-        let db = self.db.lock().unwrap();
+        let _writer = self.lock_writer();
+        let expired = self.expired_keys()?;
 
         // First collect all items in range
-        let items_to_remove: Vec<(Vec<u8>, Vec<u8>)> = db
+        let entries: Vec<(Vec<u8>, Vec<u8>)> = self
+            .db
             .range(start..end)
             .filter_map(|res| res.ok())
             .map(|(key, value)| (key.to_vec(), value.to_vec()))
             .collect();
 
-        // Then remove them and build result
-        for (key, value) in &items_to_remove {
-            db.remove(key)?;
-            result.push((key.clone(), value.clone()));
+        // Then remove them all at once, expired ones included, though only
+        // live ones are reported
+        let writes: Vec<_> = entries.iter().map(|(key, _)| (key.clone(), None)).collect();
+        self.apply(&writes)?;
+        Ok(entries
+            .into_iter()
+            .filter(|(key, value)| !expired.contains(key, value))
+            .collect())
+    }
+
+    async fn scan_prefix(&self, prefix: &[u8]) -> Result<Vec<(Vec<u8>, Vec<u8>)>> {
+        let mut result = Vec::new();
+        let expired = self.expired_keys()?;
+        for item in self.db.scan_prefix(prefix) {
+            let (key, value) = item?;
+            if !expired.contains(&key, &value) {
+                result.push((key.to_vec(), value.to_vec()));
+            }
         }
+        Ok(result)
+    }
+
+    async fn remove_prefix(&self, prefix: &[u8]) -> Result<Vec<(Vec<u8>, Vec<u8>)>> {
+        let mut result = Vec::new();
+        let _writer = self.lock_writer();
+        let expired = self.expired_keys()?;
+
+        let mut writes = Vec::new();
+        for item in self.db.scan_prefix(prefix) {
+            let (key, value) = item?;
+            writes.push((key.to_vec(), None));
+            if !expired.contains(&key, &value) {
+                result.push((key.to_vec(), value.to_vec()));
+            }
+        }
+        self.apply(&writes)?;
 
         Ok(result)
     }
 
     async fn flush(&mut self) -> Result<()> {
-        self.db.lock().unwrap().flush()?;
+        self.db.flush()?;
         Ok(())
     }
-}
 
-// SAFETY: SledDatabase is safe to share between threads because data access is protected by Mutex
-unsafe impl Send for SledDatabase {}
-unsafe impl Sync for SledDatabase {}
+    async fn transaction<F, R>(&mut self, f: F) -> Result<R>
+    where
+        F: Fn(&mut dyn Transaction) -> Result<R> + Send,
+        R: Send,
+    {
+        let _writer = self.lock_writer();
+        let result = (&self.db, &self.expiry).transaction(|(tree, expiry)| {
+            let mut tx = SledTransaction {
+                tree,
+                expiry,
+                snapshots: &self.snapshots,
+                conflict: Cell::new(false),
+            };
+            match f(&mut tx) {
+                Ok(value) => Ok(value),
+                // Hand conflicts back to sled so it retries the closure
+                Err(_) if tx.conflict.get() => Err(ConflictableTransactionError::Conflict),
+                Err(e) => Err(ConflictableTransactionError::Abort(e)),
+            }
+        });
+        from_transaction(result)
+    }
+
+    async fn compare_and_swap(
+        &mut self,
+        key: &[u8],
+        expected: Option<&[u8]>,
+        new: Option<&[u8]>,
+    ) -> Result<std::result::Result<(), CompareAndSwapError>> {
+        // A transaction over both trees, so an expired value counts as
+        // absent and the swap clears the key's expiry record with it
+        self.transaction(|tx| compare_and_swap_in(tx, key, expected, new))
+            .await
+    }
+
+    async fn increment(&mut self, key: &[u8], delta: i64) -> Result<i64> {
+        self.transaction(|tx| increment_in(tx, key, delta)).await
+    }
+
+    fn set_merge_operator<F>(&mut self, operator: F)
+    where
+        F: Fn(&[u8], Option<&[u8]>, &[u8]) -> Vec<u8> + Send + Sync + 'static,
+    {
+        self.db
+            .set_merge_operator(move |key: &[u8], old: Option<&[u8]>, operand: &[u8]| {
+                Some(operator(key, old, operand))
+            });
+    }
+
+    async fn merge(&mut self, key: &[u8], operand: &[u8]) -> Result<()> {
+        let _writer = self.lock_writer();
+        self.drop_if_expired(key)?;
+        match self.db.merge(key, operand) {
+            // Raised when no operator was registered on the tree
+            Err(sled::Error::Unsupported(message)) => {
+                return Err(DatabaseError::InvalidArgument(message).into());
+            }
+            result => result?,
+        };
+        self.clear_deadline(key)
+    }
+
+    async fn add_with_ttl(&mut self, key: &[u8], value: &[u8], ttl: Duration) -> Result<()> {
+        let _writer = self.lock_writer();
+        self.snapshots.record(key, || self.db.get(key))?;
+        let deadline = deadline_after(ttl);
+        let result = (&self.db, &self.expiry).transaction(|(data, expiry)| {
+            expiry.insert(deadline_key(key), encode_expiry(deadline, value))?;
+            expiry.insert(queue_key(deadline, key), b"")?;
+            data.insert(key, value)?;
+            Ok::<_, ConflictableTransactionError<anyhow::Error>>(())
+        });
+        from_transaction(result)
+    }
+
+    async fn ttl(&self, key: &[u8]) -> Result<Option<Duration>> {
+        let (Some(value), Some(record)) = (self.db.get(key)?, self.expiry.get(deadline_key(key))?)
+        else {
+            return Ok(None);
+        };
+        Ok(match decode_expiry(&record) {
+            Some((deadline, hash)) if hash == fingerprint(&value) => remaining_until(deadline),
+            _ => None,
+        })
+    }
+
+    async fn open_tree(&self, name: &str) -> Result<Self> {
+        check_tree_name(name)?;
+        let snapshots = self
+            .trees
+            .lock()
+            .unwrap()
+            .entry(name.to_string())
+            .or_default()
+            .clone();
+        Ok(Self {
+            root: self.root.clone(),
+            db: self.root.open_tree(name)?,
+            expiry: self.root.open_tree(expiry_tree_name(name))?,
+            writer: Arc::clone(&self.writer),
+            snapshots,
+            trees: Arc::clone(&self.trees),
+        })
+    }
+
+    async fn list_trees(&self) -> Result<Vec<String>> {
+        // Reserved names start with `_`, which also hides sled's default tree
+        let mut names = Vec::new();
+        for name in self.root.tree_names() {
+            let name = String::from_utf8(name.to_vec())?;
+            if check_tree_name(&name).is_ok() {
+                names.push(name);
+            }
+        }
+        names.sort();
+        Ok(names)
+    }
+
+    async fn drop_tree(&self, name: &str) -> Result<bool> {
+        check_tree_name(name)?;
+        let _writer = self.lock_writer();
+        self.root.drop_tree(expiry_tree_name(name))?;
+        self.trees.lock().unwrap().remove(name);
+        Ok(self.root.drop_tree(name)?)
+    }
+
+    async fn purge_expired(&self) -> Result<usize> {
+        self.evict_expired()
+    }
+
+    async fn write_batch(&mut self, batch: WriteBatch) -> Result<()> {
+        let _writer = self.lock_writer();
+
+        // sled::Batch has no range delete, so expand ranges into the keys they
+        // currently cover before applying everything in one atomic batch
+        let writes = batch.resolve(|start, end| {
+            self.db
+                .range(start..end)
+                .keys()
+                .map(|key| Ok(key?.to_vec()))
+                .collect()
+        })?;
+
+        let writes: Vec<_> = writes.into_iter().collect();
+        self.apply(&writes)
+    }
+
+    async fn select_range_with(
+        &self,
+        start: &[u8],
+        end: &[u8],
+        options: &ScanOptions,
+    ) -> Result<Vec<(Vec<u8>, Vec<u8>)>> {
+        let (lower, upper) = options.bounds(start, end);
+        if lower >= upper {
+            return Ok(Vec::new());
+        }
+
+        let expired = self.expired_keys()?;
+        let range = self.db.range(lower..upper);
+        let entries: Box<dyn Iterator<Item = sled::Result<(IVec, IVec)>>> = if options.reverse {
+            Box::new(range.rev())
+        } else {
+            Box::new(range)
+        };
+
+        entries
+            .filter(|item| !matches!(item, Ok((key, value)) if expired.contains(key, value)))
+            .take(options.max_entries())
+            .map(|item| {
+                let (key, value) = item?;
+                Ok((key.to_vec(), value.to_vec()))
+            })
+            .collect()
+    }
+
+    fn scan(&self, start: &[u8], end: &[u8]) -> KvStream<'_> {
+        if start >= end {
+            return Box::pin(futures::stream::empty());
+        }
+
+        // sled iterators own a handle to the tree, so they can be streamed
+        // directly
+        let expired = match self.expired_keys() {
+            Ok(expired) => expired,
+            Err(e) => return Box::pin(futures::stream::once(async { Err(e) })),
+        };
+        let iter = self.db.range(start.to_vec()..end.to_vec());
+        Box::pin(futures::stream::iter(
+            iter.filter(
+                move |item| !matches!(item, Ok((key, value)) if expired.contains(key, value)),
+            )
+            .map(|item| {
+                let (key, value) = item?;
+                Ok((key.to_vec(), value.to_vec()))
+            }),
+        ))
+    }
+
+    async fn snapshot(&self) -> Result<Box<dyn Snapshot>> {
+        // Holding the writer lock keeps writes out until the snapshot's log
+        // is in place and the keys expired so far are known
+        let _writer = self.lock_writer();
+        Ok(Box::new(SledSnapshot {
+            db: self.db.clone(),
+            preimages: self.snapshots.open(),
+            expired: self.expired_keys()?,
+        }))
+    }
+
+    fn watch_prefix(&self, prefix: &[u8]) -> WatchStream {
+        // sled delivers events through a bounded queue, so writers stall if
+        // the stream is left unpolled for long
+        let subscriber = self.db.watch_prefix(prefix);
+        Box::pin(futures::stream::unfold(
+            subscriber,
+            |mut subscriber| async move {
+                let event = match (&mut subscriber).await? {
+                    Event::Insert { key, value } => WatchEvent::Put {
+                        key: key.to_vec(),
+                        value: value.to_vec(),
+                    },
+                    Event::Remove { key } => WatchEvent::Delete { key: key.to_vec() },
+                };
+                Some((event, subscriber))
+            },
+        ))
+    }
+}
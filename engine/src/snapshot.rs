@@ -0,0 +1,46 @@
+use crate::database::Result;
+use async_trait::async_trait;
+use std::collections::BTreeMap;
+
+/// Read-only view of a database as it was when `Database::snapshot` was
+/// called; writes made since are not visible through it
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+pub trait Snapshot: Send + Sync {
+    /// Select a value by key
+    async fn select(&self, key: &[u8]) -> Result<Option<Vec<u8>>>;
+
+    /// Select key-value pairs within a range [start, end)
+    async fn select_range(&self, start: &[u8], end: &[u8]) -> Result<Vec<(Vec<u8>, Vec<u8>)>>;
+}
+
+/// Snapshot holding its own copy of every entry, for backends without a
+/// native one
+pub(crate) struct MapSnapshot {
+    data: BTreeMap<Vec<u8>, Vec<u8>>,
+}
+
+impl MapSnapshot {
+    pub(crate) fn new(data: BTreeMap<Vec<u8>, Vec<u8>>) -> Self {
+        Self { data }
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl Snapshot for MapSnapshot {
+    async fn select(&self, key: &[u8]) -> Result<Option<Vec<u8>>> {
+        Ok(self.data.get(key).cloned())
+    }
+
+    async fn select_range(&self, start: &[u8], end: &[u8]) -> Result<Vec<(Vec<u8>, Vec<u8>)>> {
+        if start >= end {
+            return Ok(Vec::new());
+        }
+        Ok(self
+            .data
+            .range(start.to_vec()..end.to_vec())
+            .map(|(key, value)| (key.clone(), value.clone()))
+            .collect())
+    }
+}
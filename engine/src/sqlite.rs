@@ -1,13 +1,198 @@
-use super::database::{Database, Result};
+use super::batch::{BatchOp, WriteBatch};
+use super::database::{
+    CompareAndSwapError, Database, KvStream, MergeOperator, Result, ScanOptions, Transaction,
+};
+use super::merge::{increment_in, merge_in};
+use super::scan::{chunked_scan, prefix_successor};
+use super::snapshot::{MapSnapshot, Snapshot};
+use super::transaction::compare_and_swap_in;
+use super::tree::{TreeRegistry, check_tree_name};
+use super::ttl::{deadline_after, fingerprint, now_millis, remaining_until};
+use super::watch::{ChangeBroadcaster, WatchStream};
 use async_trait::async_trait;
-use rusqlite::{Connection, params};
+use futures::future;
+use rusqlite::{Connection, OptionalExtension, TransactionBehavior, params};
 use std::cell::UnsafeCell;
+use std::collections::BTreeMap;
 use std::error::Error;
-use std::sync::Mutex;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::Duration;
+
+/// Tables prefixed with this hold the named trees, one per tree
+const TREE_TABLE_PREFIX: &str = "kv_tree_";
+
+/// Columns of the tables holding the entries
+const STORE_COLUMNS: &str = "key BLOB PRIMARY KEY, value BLOB";
+
+/// Columns of the tables holding the deadlines of entries added with a TTL,
+/// with a fingerprint of the value each was set for
+const EXPIRY_COLUMNS: &str =
+    "key BLOB PRIMARY KEY, expires_at INTEGER NOT NULL, fingerprint INTEGER NOT NULL";
+
+/// Tables holding the entries and TTLs of one tree, quoted for use in SQL
+#[derive(Clone)]
+struct Tables {
+    store: String,
+    expiry: String,
+    expiry_index: String,
+    /// Start of the names of the triggers clearing TTLs on writes
+    triggers: String,
+}
+
+impl Tables {
+    fn root() -> Self {
+        Self {
+            store: "kv_store".to_string(),
+            expiry: "kv_expiry".to_string(),
+            expiry_index: "kv_expiry_expires_at".to_string(),
+            triggers: "kv_store_clear_ttl".to_string(),
+        }
+    }
+
+    /// Tree names are checked to be plain ASCII, but may hold `-` and `.`
+    fn tree(name: &str) -> Self {
+        Self {
+            store: format!("\"{}{}\"", TREE_TABLE_PREFIX, name),
+            expiry: format!("\"kv_ttl_{}\"", name),
+            expiry_index: format!("\"kv_deadline_{}\"", name),
+            triggers: format!("kv_clear_ttl_{}", name),
+        }
+    }
+
+    /// The entries whose TTL hasn't run out by `now`, as a subquery to read
+    /// from in place of the store. The triggers drop a key's deadline on every
+    /// write, so a deadline left in the table belongs to the current value.
+    fn live(&self, now: u64) -> String {
+        format!(
+            "(SELECT key, value FROM {} AS kv WHERE NOT EXISTS
+              (SELECT 1 FROM {} AS expiry WHERE expiry.key = kv.key AND expiry.expires_at <= {}))",
+            self.store, self.expiry, now
+        )
+    }
+
+    fn create(&self, conn: &Connection) -> Result<()> {
+        conn.execute(
+            &format!(
+                "CREATE TABLE IF NOT EXISTS {} ({})",
+                self.store, STORE_COLUMNS
+            ),
+            [],
+        )?;
+        conn.execute(
+            &format!(
+                "CREATE TABLE IF NOT EXISTS {} ({})",
+                self.expiry, EXPIRY_COLUMNS
+            ),
+            [],
+        )?;
+        conn.execute(
+            &format!(
+                "CREATE INDEX IF NOT EXISTS {} ON {} (expires_at)",
+                self.expiry_index, self.expiry
+            ),
+            [],
+        )?;
+
+        // Writing a key in any way makes it permanent again, so every change
+        // to the store drops the key's deadline
+        for (event, row) in [("INSERT", "NEW"), ("UPDATE", "NEW"), ("DELETE", "OLD")] {
+            conn.execute(
+                &format!(
+                    "CREATE TRIGGER IF NOT EXISTS \"{}_{}\" AFTER {} ON {}
+                     BEGIN DELETE FROM {} WHERE key = {}.key; END",
+                    self.triggers,
+                    event.to_lowercase(),
+                    event,
+                    self.store,
+                    self.expiry,
+                    row
+                ),
+                [],
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Convert tables written when keys were TEXT, holding lossily decoded
+    /// UTF-8 and sorted by collation rather than bytewise: each is copied into
+    /// a new table with every key cast to its UTF-8 bytes. The expiry index is
+    /// dropped along the way and comes back with `create`.
+    fn migrate_text_keys(&self, sql_tx: &rusqlite::Transaction) -> Result<()> {
+        let tables = [
+            (&self.store, STORE_COLUMNS, "value"),
+            (&self.expiry, EXPIRY_COLUMNS, "expires_at, fingerprint"),
+        ];
+        for (table, columns, values) in tables {
+            let key_type: Option<String> = sql_tx
+                .query_row(
+                    "SELECT type FROM pragma_table_info(?) WHERE name = 'key'",
+                    [table.trim_matches('"')],
+                    |row| row.get(0),
+                )
+                .optional()?;
+            if !key_type.is_some_and(|key_type| key_type.eq_ignore_ascii_case("TEXT")) {
+                continue;
+            }
+            sql_tx.execute_batch(&format!(
+                "CREATE TABLE versedb_migration ({});
+                 INSERT INTO versedb_migration (key, {})
+                     SELECT CAST(key AS BLOB), {} FROM {};
+                 DROP TABLE {};
+                 ALTER TABLE versedb_migration RENAME TO {};",
+                columns, values, values, table, table, table
+            ))?;
+        }
+        Ok(())
+    }
+}
+
+/// Version of the schema this code writes, kept in `PRAGMA user_version`.
+/// Version 1 stores keys as BLOBs.
+const SCHEMA_VERSION: i64 = 1;
+
+/// Bring a database written by an older version up to `SCHEMA_VERSION`, the
+/// root and every tree in one transaction. Once done, opening only reads the
+/// version.
+fn migrate(conn: &Connection) -> Result<()> {
+    let version = |conn: &Connection| -> Result<i64> {
+        Ok(conn.query_row("PRAGMA user_version", [], |row| row.get(0))?)
+    };
+    if version(conn)? >= SCHEMA_VERSION {
+        return Ok(());
+    }
+
+    let sql_tx = rusqlite::Transaction::new_unchecked(conn, TransactionBehavior::Immediate)?;
+    // Another connection may have migrated before this one got the lock
+    if version(&sql_tx)? < 1 {
+        let trees: Vec<String> = sql_tx
+            .prepare(
+                "SELECT substr(name, ?1 + 1) FROM sqlite_master
+                 WHERE type = 'table' AND substr(name, 1, ?1) = ?2",
+            )?
+            .query_map(
+                params![TREE_TABLE_PREFIX.len() as i64, TREE_TABLE_PREFIX],
+                |row| row.get(0),
+            )?
+            .collect::<rusqlite::Result<_>>()?;
+        for tables in
+            std::iter::once(Tables::root()).chain(trees.iter().map(|name| Tables::tree(name)))
+        {
+            tables.migrate_text_keys(&sql_tx)?;
+        }
+    }
+    sql_tx.execute_batch(&format!("PRAGMA user_version = {}", SCHEMA_VERSION))?;
+    sql_tx.commit()?;
+    Ok(())
+}
 
 pub struct SqliteDatabase {
     conn: UnsafeCell<Mutex<Connection>>,
     path: String,
+    tables: Tables,
+    merge_operator: Option<MergeOperator>,
+    watchers: ChangeBroadcaster,
+    /// Watchers of each named tree, shared by every handle on the database
+    trees: TreeRegistry<ChangeBroadcaster>,
 }
 
 impl Clone for SqliteDatabase {
@@ -15,6 +200,10 @@ impl Clone for SqliteDatabase {
         Self {
             conn: UnsafeCell::new(Mutex::new(Connection::open(&self.path).unwrap())),
             path: self.path.clone(),
+            tables: self.tables.clone(),
+            merge_operator: self.merge_operator.clone(),
+            watchers: self.watchers.clone(),
+            trees: Arc::clone(&self.trees),
         }
     }
 }
@@ -24,6 +213,203 @@ impl SqliteDatabase {
     fn get_conn(&self) -> &Mutex<Connection> {
         unsafe { &*self.conn.get() }
     }
+
+    /// Drop every entry whose TTL has run out, returning how many were removed
+    fn evict_expired(&self, conn: &mut Connection) -> Result<usize> {
+        let now = now_millis() as i64;
+        let pending: bool = conn.query_row(
+            &format!(
+                "SELECT EXISTS(SELECT 1 FROM {} WHERE expires_at <= ?)",
+                self.tables.expiry
+            ),
+            [now],
+            |row| row.get(0),
+        )?;
+        if !pending {
+            return Ok(0);
+        }
+
+        let sql_tx = conn.transaction_with_behavior(TransactionBehavior::Immediate)?;
+        let mut evicted = Vec::new();
+        {
+            let mut expired = sql_tx.prepare_cached(&format!(
+                "SELECT key, fingerprint FROM {} WHERE expires_at <= ?",
+                self.tables.expiry
+            ))?;
+            let mut select = sql_tx.prepare_cached(&format!(
+                "SELECT value FROM {} WHERE key = ?",
+                self.tables.store
+            ))?;
+            let mut delete = sql_tx
+                .prepare_cached(&format!("DELETE FROM {} WHERE key = ?", self.tables.store))?;
+
+            let rows: Vec<(Vec<u8>, i64)> = expired
+                .query_map([now], |row| Ok((row.get(0)?, row.get(1)?)))?
+                .collect::<rusqlite::Result<_>>()?;
+            for (key, hash) in rows {
+                // Only drop the value the TTL was set for
+                let value: Option<Vec<u8>> =
+                    select.query_row([&key], |row| row.get(0)).optional()?;
+                if value.is_some_and(|value| fingerprint(&value) as i64 == hash) {
+                    delete.execute([&key])?;
+                    evicted.push(key);
+                }
+            }
+        }
+        sql_tx.execute(
+            &format!("DELETE FROM {} WHERE expires_at <= ?", self.tables.expiry),
+            [now],
+        )?;
+        sql_tx.commit()?;
+
+        for key in &evicted {
+            self.watchers.publish(key, None);
+        }
+        Ok(evicted.len())
+    }
+
+    /// Remove the entries from `start` up to `end`, or to the last key when
+    /// there is no end, returning them. They are read and deleted in one
+    /// transaction, so a concurrent write can't slip in between
+    fn take_range(&self, start: &[u8], end: Option<&[u8]>) -> Result<Vec<(Vec<u8>, Vec<u8>)>> {
+        let bound = if end.is_some() { " AND key < ?" } else { "" };
+        let mut conn = self.lock_conn()?;
+        let sql_tx = conn.transaction_with_behavior(TransactionBehavior::Immediate)?;
+        let entries = {
+            // Expired entries are deleted along with the rest, but were
+            // already gone as far as callers are concerned
+            let mut select = sql_tx.prepare_cached(&format!(
+                "SELECT key, value FROM {} WHERE key >= ?{} ORDER BY key",
+                self.tables.live(now_millis()),
+                bound
+            ))?;
+            let mut delete = sql_tx.prepare_cached(&format!(
+                "DELETE FROM {} WHERE key >= ?{}",
+                self.tables.store, bound
+            ))?;
+            let row = |row: &rusqlite::Row| Ok((row.get(0)?, row.get(1)?));
+            match end {
+                Some(end) => {
+                    let entries: Vec<(Vec<u8>, Vec<u8>)> = select
+                        .query_map(params![start, end], row)?
+                        .collect::<rusqlite::Result<_>>()?;
+                    delete.execute(params![start, end])?;
+                    entries
+                }
+                None => {
+                    let entries: Vec<(Vec<u8>, Vec<u8>)> = select
+                        .query_map(params![start], row)?
+                        .collect::<rusqlite::Result<_>>()?;
+                    delete.execute(params![start])?;
+                    entries
+                }
+            }
+        };
+        sql_tx.commit()?;
+
+        self.watchers.publish_removed(&entries);
+        Ok(entries)
+    }
+
+    /// Lock the connection for an operation. Expired entries are left in
+    /// place for `purge_expired`, and reads skip them through `Tables::live`
+    fn lock_conn(&self) -> Result<MutexGuard<'_, Connection>> {
+        Ok(self.get_conn().lock().unwrap())
+    }
+}
+
+/// Reads through a connection of its own holding a read transaction open, so
+/// it keeps seeing the database as it was when the transaction started
+struct SqliteSnapshot {
+    conn: Mutex<Connection>,
+    /// Entries still live when the snapshot was taken, from `Tables::live`
+    live: String,
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl Snapshot for SqliteSnapshot {
+    async fn select(&self, key: &[u8]) -> Result<Option<Vec<u8>>> {
+        let conn = self.conn.lock().unwrap();
+        let value = conn
+            .query_row(
+                &format!("SELECT value FROM {} WHERE key = ?", self.live),
+                [key],
+                |row| row.get(0),
+            )
+            .optional()?;
+        Ok(value)
+    }
+
+    async fn select_range(&self, start: &[u8], end: &[u8]) -> Result<Vec<(Vec<u8>, Vec<u8>)>> {
+        let conn = self.conn.lock().unwrap();
+        let mut stmt = conn.prepare_cached(&format!(
+            "SELECT key, value FROM {} WHERE key >= ? AND key < ? ORDER BY key",
+            self.live
+        ))?;
+        let rows = stmt
+            .query_map(params![start, end], |row| {
+                let key: Vec<u8> = row.get(0)?;
+                let value: Vec<u8> = row.get(1)?;
+                Ok((key, value))
+            })?
+            .collect::<rusqlite::Result<_>>()?;
+        Ok(rows)
+    }
+}
+
+impl Drop for SqliteSnapshot {
+    fn drop(&mut self) {
+        let _ = self.conn.get_mut().unwrap().execute_batch("COMMIT");
+    }
+}
+
+/// Runs transactional operations against an open SQLite transaction; dropping
+/// the underlying transaction without committing rolls everything back
+struct SqliteTransaction<'a> {
+    tx: &'a rusqlite::Transaction<'a>,
+    store: &'a str,
+    /// Entries still live when the transaction started, from `Tables::live`
+    live: String,
+    /// Final value of each written key, reported to watchers after commit
+    writes: BTreeMap<Vec<u8>, Option<Vec<u8>>>,
+}
+
+impl Transaction for SqliteTransaction<'_> {
+    fn get(&self, key: &[u8]) -> Result<Option<Vec<u8>>> {
+        let result = self.tx.query_row(
+            &format!("SELECT value FROM {} WHERE key = ?", self.live),
+            [key],
+            |row| row.get(0),
+        );
+
+        match result {
+            Ok(value) => Ok(Some(value)),
+            Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    fn put(&mut self, key: &[u8], value: &[u8]) -> Result<()> {
+        self.tx.execute(
+            &format!(
+                "INSERT OR REPLACE INTO {} (key, value) VALUES (?, ?)",
+                self.store
+            ),
+            params![key, value],
+        )?;
+        self.writes.insert(key.to_vec(), Some(value.to_vec()));
+        Ok(())
+    }
+
+    fn delete(&mut self, key: &[u8]) -> Result<()> {
+        self.tx.execute(
+            &format!("DELETE FROM {} WHERE key = ?", self.store),
+            params![key],
+        )?;
+        self.writes.insert(key.to_vec(), None);
+        Ok(())
+    }
 }
 
 #[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
@@ -31,19 +417,21 @@ impl SqliteDatabase {
 impl Database for SqliteDatabase {
     async fn open(path: &str) -> Result<Self> {
         let conn = Connection::open(path)?;
+        // Readers in WAL mode don't block writers, so snapshots can hold a
+        // read transaction open for as long as they live
+        conn.query_row("PRAGMA journal_mode = WAL", [], |_| Ok(()))?;
 
-        // Create the table if it doesn't exist
-        conn.execute(
-            "CREATE TABLE IF NOT EXISTS kv_store (
-                key TEXT PRIMARY KEY,
-                value BLOB
-            )",
-            [],
-        )?;
+        migrate(&conn)?;
+        let tables = Tables::root();
+        tables.create(&conn)?;
 
         Ok(SqliteDatabase {
             conn: UnsafeCell::new(Mutex::new(conn)),
             path: path.to_string(),
+            tables,
+            merge_operator: None,
+            watchers: ChangeBroadcaster::default(),
+            trees: TreeRegistry::default(),
         })
     }
 
@@ -52,20 +440,26 @@ impl Database for SqliteDatabase {
     }
 
     async fn add(&mut self, key: &[u8], value: &[u8]) -> Result<()> {
-        let conn = self.get_conn().lock().unwrap();
+        let conn = self.lock_conn()?;
         conn.execute(
-            "INSERT OR REPLACE INTO kv_store (key, value) VALUES (?, ?)",
-            params![String::from_utf8_lossy(key), value],
+            &format!(
+                "INSERT OR REPLACE INTO {} (key, value) VALUES (?, ?)",
+                self.tables.store
+            ),
+            params![key, value],
         )?;
+
+        self.watchers.publish(key, Some(value));
         Ok(())
     }
 
     async fn select(&self, key: &[u8]) -> Result<Option<Vec<u8>>> {
-        let conn = self.get_conn().lock().unwrap();
-        let mut stmt = conn.prepare("SELECT value FROM kv_store WHERE key = ?")?;
-        let key_str = String::from_utf8_lossy(key);
-
-        let result = stmt.query_row([&key_str], |row| {
+        let conn = self.lock_conn()?;
+        let mut stmt = conn.prepare(&format!(
+            "SELECT value FROM {} WHERE key = ?",
+            self.tables.live(now_millis())
+        ))?;
+        let result = stmt.query_row([key], |row| {
             let value: Vec<u8> = row.get(0)?;
             Ok(value)
         });
@@ -78,26 +472,28 @@ impl Database for SqliteDatabase {
     }
 
     async fn remove(&mut self, key: &[u8]) -> Result<()> {
-        let conn = self.get_conn().lock().unwrap();
-        conn.execute(
-            "DELETE FROM kv_store WHERE key = ?",
-            params![String::from_utf8_lossy(key)],
+        let conn = self.lock_conn()?;
+        let removed = conn.execute(
+            &format!("DELETE FROM {} WHERE key = ?", self.tables.store),
+            params![key],
         )?;
+        if removed > 0 {
+            self.watchers.publish(key, None);
+        }
         Ok(())
     }
 
     async fn select_range(&self, start: &[u8], end: &[u8]) -> Result<Vec<(Vec<u8>, Vec<u8>)>> {
-        let conn = self.get_conn().lock().unwrap();
-        let mut stmt = conn
-            .prepare("SELECT key, value FROM kv_store WHERE key >= ? AND key < ? ORDER BY key")?;
-
-        let start_str = String::from_utf8_lossy(start);
-        let end_str = String::from_utf8_lossy(end);
+        let conn = self.lock_conn()?;
+        let mut stmt = conn.prepare(&format!(
+            "SELECT key, value FROM {} WHERE key >= ? AND key < ? ORDER BY key",
+            self.tables.live(now_millis())
+        ))?;
 
-        let rows = stmt.query_map([&start_str, &end_str], |row| {
-            let key: String = row.get(0)?;
+        let rows = stmt.query_map([start, end], |row| {
+            let key: Vec<u8> = row.get(0)?;
             let value: Vec<u8> = row.get(1)?;
-            Ok((key.into_bytes(), value))
+            Ok((key, value))
         })?;
 
         let mut results = Vec::new();
@@ -109,17 +505,48 @@ impl Database for SqliteDatabase {
     }
 
     async fn remove_range(&self, start: &[u8], end: &[u8]) -> Result<Vec<(Vec<u8>, Vec<u8>)>> {
-        // First get all entries that will be removed
-        let entries = self.select_range(start, end).await?;
+        self.take_range(start, Some(end))
+    }
 
-        // Then delete the range
-        let conn = self.get_conn().lock().unwrap();
-        conn.execute(
-            "DELETE FROM kv_store WHERE key >= ? AND key < ?",
-            params![String::from_utf8_lossy(start), String::from_utf8_lossy(end)],
-        )?;
+    async fn scan_prefix(&self, prefix: &[u8]) -> Result<Vec<(Vec<u8>, Vec<u8>)>> {
+        let conn = self.lock_conn()?;
 
-        Ok(entries)
+        // Keys compare bytewise, so the prefix range is exactly the keys
+        // starting with it
+        let rows = match prefix_successor(prefix) {
+            Some(successor) => {
+                let mut stmt = conn.prepare_cached(&format!(
+                    "SELECT key, value FROM {} WHERE key >= ? AND key < ? ORDER BY key",
+                    self.tables.live(now_millis())
+                ))?;
+                stmt.query_map(params![prefix, successor], |row| {
+                    let key: Vec<u8> = row.get(0)?;
+                    let value: Vec<u8> = row.get(1)?;
+                    Ok((key, value))
+                })?
+                .collect::<rusqlite::Result<_>>()?
+            }
+            None => {
+                let mut stmt = conn.prepare_cached(&format!(
+                    "SELECT key, value FROM {} WHERE key >= ? ORDER BY key",
+                    self.tables.live(now_millis())
+                ))?;
+                stmt.query_map(params![prefix], |row| {
+                    let key: Vec<u8> = row.get(0)?;
+                    let value: Vec<u8> = row.get(1)?;
+                    Ok((key, value))
+                })?
+                .collect::<rusqlite::Result<_>>()?
+            }
+        };
+
+        Ok(rows)
+    }
+
+    async fn remove_prefix(&self, prefix: &[u8]) -> Result<Vec<(Vec<u8>, Vec<u8>)>> {
+        // Keys compare bytewise, so the prefix range is exactly the keys
+        // starting with it
+        self.take_range(prefix, prefix_successor(prefix).as_deref())
     }
 
     async fn flush(&mut self) -> Result<()> {
@@ -127,6 +554,307 @@ impl Database for SqliteDatabase {
         conn.query_row("PRAGMA wal_checkpoint(FULL)", [], |_| Ok(()))?;
         Ok(())
     }
+
+    async fn transaction<F, R>(&mut self, f: F) -> Result<R>
+    where
+        F: Fn(&mut dyn Transaction) -> Result<R> + Send,
+        R: Send,
+    {
+        let mut conn = self.lock_conn()?;
+        let sql_tx = conn.transaction_with_behavior(TransactionBehavior::Immediate)?;
+        let mut tx = SqliteTransaction {
+            tx: &sql_tx,
+            store: &self.tables.store,
+            live: self.tables.live(now_millis()),
+            writes: BTreeMap::new(),
+        };
+        let result = f(&mut tx)?;
+        let writes = tx.writes;
+        sql_tx.commit()?;
+
+        self.watchers.publish_all(&writes);
+        Ok(result)
+    }
+
+    async fn compare_and_swap(
+        &mut self,
+        key: &[u8],
+        expected: Option<&[u8]>,
+        new: Option<&[u8]>,
+    ) -> Result<std::result::Result<(), CompareAndSwapError>> {
+        self.transaction(|tx| compare_and_swap_in(tx, key, expected, new))
+            .await
+    }
+
+    async fn increment(&mut self, key: &[u8], delta: i64) -> Result<i64> {
+        self.transaction(|tx| increment_in(tx, key, delta)).await
+    }
+
+    fn set_merge_operator<F>(&mut self, operator: F)
+    where
+        F: Fn(&[u8], Option<&[u8]>, &[u8]) -> Vec<u8> + Send + Sync + 'static,
+    {
+        self.merge_operator = Some(Arc::new(operator));
+    }
+
+    async fn merge(&mut self, key: &[u8], operand: &[u8]) -> Result<()> {
+        let operator = self.merge_operator.clone();
+        self.transaction(|tx| merge_in(tx, operator.as_ref(), key, operand))
+            .await
+    }
+
+    async fn add_with_ttl(&mut self, key: &[u8], value: &[u8], ttl: Duration) -> Result<()> {
+        let mut conn = self.lock_conn()?;
+        let sql_tx = conn.transaction()?;
+        // The deadline goes in after the value, whose trigger clears the old one
+        sql_tx.execute(
+            &format!(
+                "INSERT OR REPLACE INTO {} (key, value) VALUES (?, ?)",
+                self.tables.store
+            ),
+            params![key, value],
+        )?;
+        sql_tx.execute(
+            &format!(
+                "INSERT OR REPLACE INTO {} (key, expires_at, fingerprint) VALUES (?, ?, ?)",
+                self.tables.expiry
+            ),
+            params![key, deadline_after(ttl) as i64, fingerprint(value) as i64],
+        )?;
+        sql_tx.commit()?;
+
+        self.watchers.publish(key, Some(value));
+        Ok(())
+    }
+
+    async fn ttl(&self, key: &[u8]) -> Result<Option<Duration>> {
+        let conn = self.lock_conn()?;
+        let row: Option<(Vec<u8>, i64, i64)> = conn
+            .query_row(
+                &format!(
+                    "SELECT kv.value, expiry.expires_at, expiry.fingerprint
+                     FROM {} kv JOIN {} expiry ON kv.key = expiry.key
+                     WHERE kv.key = ?",
+                    self.tables.store, self.tables.expiry
+                ),
+                [key],
+                |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)),
+            )
+            .optional()?;
+
+        Ok(match row {
+            Some((value, deadline, hash)) if fingerprint(&value) as i64 == hash => {
+                remaining_until(deadline as u64)
+            }
+            _ => None,
+        })
+    }
+
+    async fn purge_expired(&self) -> Result<usize> {
+        let mut conn = self.get_conn().lock().unwrap();
+        self.evict_expired(&mut conn)
+    }
+
+    async fn open_tree(&self, name: &str) -> Result<Self> {
+        check_tree_name(name)?;
+        let conn = Connection::open(&self.path)?;
+        let tables = Tables::tree(name);
+        tables.create(&conn)?;
+
+        let watchers = self
+            .trees
+            .lock()
+            .unwrap()
+            .entry(name.to_string())
+            .or_default()
+            .clone();
+        Ok(SqliteDatabase {
+            conn: UnsafeCell::new(Mutex::new(conn)),
+            path: self.path.clone(),
+            tables,
+            merge_operator: None,
+            watchers,
+            trees: Arc::clone(&self.trees),
+        })
+    }
+
+    async fn list_trees(&self) -> Result<Vec<String>> {
+        let conn = self.get_conn().lock().unwrap();
+        let mut stmt = conn.prepare(
+            "SELECT substr(name, ?1 + 1) FROM sqlite_master
+             WHERE type = 'table' AND substr(name, 1, ?1) = ?2 ORDER BY name",
+        )?;
+        let names = stmt
+            .query_map(
+                params![TREE_TABLE_PREFIX.len() as i64, TREE_TABLE_PREFIX],
+                |row| row.get(0),
+            )?
+            .collect::<rusqlite::Result<_>>()?;
+        Ok(names)
+    }
+
+    async fn drop_tree(&self, name: &str) -> Result<bool> {
+        check_tree_name(name)?;
+        let tables = Tables::tree(name);
+        let mut conn = self.get_conn().lock().unwrap();
+        let sql_tx = conn.transaction_with_behavior(TransactionBehavior::Immediate)?;
+        let existed: bool = sql_tx.query_row(
+            "SELECT EXISTS(SELECT 1 FROM sqlite_master WHERE type = 'table' AND name = ?)",
+            [format!("{}{}", TREE_TABLE_PREFIX, name)],
+            |row| row.get(0),
+        )?;
+        sql_tx.execute(&format!("DROP TABLE IF EXISTS {}", tables.store), [])?;
+        sql_tx.execute(&format!("DROP TABLE IF EXISTS {}", tables.expiry), [])?;
+        sql_tx.commit()?;
+
+        self.trees.lock().unwrap().remove(name);
+        Ok(existed)
+    }
+
+    async fn write_batch(&mut self, batch: WriteBatch) -> Result<()> {
+        let mut conn = self.lock_conn()?;
+        let sql_tx = conn.transaction_with_behavior(TransactionBehavior::Immediate)?;
+        let changes = if self.watchers.is_watched() {
+            batch.clone().resolve(|start, end| {
+                let mut stmt = sql_tx.prepare_cached(&format!(
+                    "SELECT key FROM {} WHERE key >= ? AND key < ?",
+                    self.tables.live(now_millis())
+                ))?;
+                let keys = stmt
+                    .query_map(params![start, end], |row| row.get(0))?
+                    .collect::<rusqlite::Result<_>>()?;
+                Ok(keys)
+            })?
+        } else {
+            BTreeMap::new()
+        };
+        {
+            let mut put = sql_tx.prepare_cached(&format!(
+                "INSERT OR REPLACE INTO {} (key, value) VALUES (?, ?)",
+                self.tables.store
+            ))?;
+            let mut delete = sql_tx
+                .prepare_cached(&format!("DELETE FROM {} WHERE key = ?", self.tables.store))?;
+            let mut delete_range = sql_tx.prepare_cached(&format!(
+                "DELETE FROM {} WHERE key >= ? AND key < ?",
+                self.tables.store
+            ))?;
+
+            for op in batch.ops() {
+                match op {
+                    BatchOp::Put { key, value } => {
+                        put.execute(params![key, value])?;
+                    }
+                    BatchOp::Delete { key } => {
+                        delete.execute(params![key])?;
+                    }
+                    BatchOp::DeleteRange { start, end } => {
+                        delete_range.execute(params![start, end])?;
+                    }
+                }
+            }
+        }
+        sql_tx.commit()?;
+
+        self.watchers.publish_all(&changes);
+        Ok(())
+    }
+
+    async fn select_range_with(
+        &self,
+        start: &[u8],
+        end: &[u8],
+        options: &ScanOptions,
+    ) -> Result<Vec<(Vec<u8>, Vec<u8>)>> {
+        let (lower, upper) = options.bounds(start, end);
+        if lower >= upper {
+            return Ok(Vec::new());
+        }
+
+        let conn = self.lock_conn()?;
+        let order = if options.reverse { "DESC" } else { "ASC" };
+        let mut stmt = conn.prepare_cached(&format!(
+            "SELECT key, value FROM {} WHERE key >= ? AND key < ? ORDER BY key {} LIMIT ?",
+            self.tables.live(now_millis()),
+            order
+        ))?;
+
+        // A negative LIMIT means no limit in SQLite
+        let limit = options.limit.map_or(-1, |limit| limit as i64);
+        let rows = stmt.query_map(params![&lower, &upper, limit], |row| {
+            let key: Vec<u8> = row.get(0)?;
+            let value: Vec<u8> = row.get(1)?;
+            Ok((key, value))
+        })?;
+
+        let mut results = Vec::new();
+        for row in rows {
+            results.push(row?);
+        }
+
+        Ok(results)
+    }
+
+    fn scan(&self, start: &[u8], end: &[u8]) -> KvStream<'_> {
+        // Statements borrow the locked connection, so page through the range
+        // with LIMIT queries instead of holding the lock while the caller awaits
+        Box::pin(chunked_scan(start, end, move |from, end, limit| {
+            let conn = match self.lock_conn() {
+                Ok(conn) => conn,
+                Err(e) => return future::ready(Err(e)),
+            };
+            let chunk = conn
+                .prepare_cached(&format!(
+                    "SELECT key, value FROM {} WHERE key >= ? AND key < ? ORDER BY key LIMIT ?",
+                    self.tables.live(now_millis())
+                ))
+                .and_then(|mut stmt| {
+                    stmt.query_map(params![&from, &end, limit as i64], |row| {
+                        let key: Vec<u8> = row.get(0)?;
+                        let value: Vec<u8> = row.get(1)?;
+                        Ok((key, value))
+                    })?
+                    .collect()
+                })
+                .map_err(Into::into);
+            future::ready(chunk)
+        }))
+    }
+
+    async fn snapshot(&self) -> Result<Box<dyn Snapshot>> {
+        // Holding this handle's lock until the snapshot has settled on a
+        // version keeps its writes from slipping in between
+        let conn = self.lock_conn()?;
+
+        // An in-memory or temporary database can't be opened a second time,
+        // and a shared cache would lock writers out for as long as the
+        // snapshot lives, so take a copy instead
+        let path = conn.path().unwrap_or_default();
+        if path.is_empty() {
+            let mut stmt = conn.prepare(&format!(
+                "SELECT key, value FROM {} ORDER BY key",
+                self.tables.live(now_millis())
+            ))?;
+            let data = stmt
+                .query_map([], |row| Ok((row.get(0)?, row.get(1)?)))?
+                .collect::<rusqlite::Result<_>>()?;
+            return Ok(Box::new(MapSnapshot::new(data)));
+        }
+
+        // A read transaction only settles on a version of the database at its
+        // first read, so make one straight away
+        let reader = Connection::open(path)?;
+        reader.execute_batch("BEGIN")?;
+        reader.query_row("SELECT COUNT(*) FROM sqlite_master", [], |_| Ok(()))?;
+        Ok(Box::new(SqliteSnapshot {
+            conn: Mutex::new(reader),
+            live: self.tables.live(now_millis()),
+        }))
+    }
+
+    fn watch_prefix(&self, prefix: &[u8]) -> WatchStream {
+        self.watchers.subscribe(prefix)
+    }
 }
 
 // SAFETY: SqliteDatabase is safe to share between threads because Connection access is protected by Mutex
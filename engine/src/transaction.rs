@@ -0,0 +1,95 @@
+use crate::database::Result;
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// Read and write access to a database from inside `Database::transaction`
+pub trait Transaction {
+    /// Select a value by key, observing writes made earlier in the transaction
+    fn get(&self, key: &[u8]) -> Result<Option<Vec<u8>>>;
+
+    /// Add a key-value pair as part of the transaction
+    fn put(&mut self, key: &[u8], value: &[u8]) -> Result<()>;
+
+    /// Remove a key as part of the transaction
+    fn delete(&mut self, key: &[u8]) -> Result<()>;
+}
+
+/// Returned by `Database::compare_and_swap` when the stored value did not
+/// match the expected one
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CompareAndSwapError {
+    /// The value actually stored under the key, `None` if it is absent
+    pub current: Option<Vec<u8>>,
+}
+
+impl fmt::Display for CompareAndSwapError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "compare and swap conflict")
+    }
+}
+
+impl std::error::Error for CompareAndSwapError {}
+
+/// Compare-and-swap expressed through a `Transaction`, for backends that
+/// get their atomicity from `Database::transaction`
+pub(crate) fn compare_and_swap_in(
+    tx: &mut dyn Transaction,
+    key: &[u8],
+    expected: Option<&[u8]>,
+    new: Option<&[u8]>,
+) -> Result<std::result::Result<(), CompareAndSwapError>> {
+    let current = tx.get(key)?;
+    if current.as_deref() != expected {
+        return Ok(Err(CompareAndSwapError { current }));
+    }
+
+    match new {
+        Some(value) => tx.put(key, value)?,
+        None => tx.delete(key)?,
+    }
+    Ok(Ok(()))
+}
+
+/// Point lookup against the committed state of a store
+pub(crate) type ReadFn<'a> = &'a dyn Fn(&[u8]) -> Result<Option<Vec<u8>>>;
+
+/// Buffers transactional writes on top of a read-only view of the store.
+/// Backends without native transactions run the closure against an overlay
+/// and only apply the buffered writes once it returns successfully.
+pub(crate) struct OverlayTransaction<'a> {
+    read: ReadFn<'a>,
+    writes: BTreeMap<Vec<u8>, Option<Vec<u8>>>,
+}
+
+impl<'a> OverlayTransaction<'a> {
+    pub(crate) fn new(read: ReadFn<'a>) -> Self {
+        Self {
+            read,
+            writes: BTreeMap::new(),
+        }
+    }
+
+    /// Consume the overlay, returning the pending writes (`None` marks a removal)
+    pub(crate) fn into_writes(self) -> BTreeMap<Vec<u8>, Option<Vec<u8>>> {
+        self.writes
+    }
+}
+
+impl Transaction for OverlayTransaction<'_> {
+    fn get(&self, key: &[u8]) -> Result<Option<Vec<u8>>> {
+        match self.writes.get(key) {
+            Some(pending) => Ok(pending.clone()),
+            None => (self.read)(key),
+        }
+    }
+
+    fn put(&mut self, key: &[u8], value: &[u8]) -> Result<()> {
+        self.writes.insert(key.to_vec(), Some(value.to_vec()));
+        Ok(())
+    }
+
+    fn delete(&mut self, key: &[u8]) -> Result<()> {
+        self.writes.insert(key.to_vec(), None);
+        Ok(())
+    }
+}
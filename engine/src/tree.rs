@@ -0,0 +1,86 @@
+use crate::database::{DatabaseError, Result};
+use anyhow::bail;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+/// Named trees of an in-memory backend, shared by every handle on the database
+pub(crate) type TreeRegistry<T> = Arc<Mutex<BTreeMap<String, T>>>;
+
+/// Check `name` can be passed to `Database::open_tree`.
+/// Names are ASCII letters, digits, `-`, `_` and `.` so they fit in table and
+/// file names unquoted; a leading `_` or `.`, `default` and the `versedb`
+/// prefix are reserved for the backends' own keyspaces.
+pub(crate) fn check_tree_name(name: &str) -> Result<()> {
+    let valid_chars = name
+        .bytes()
+        .all(|byte| byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'_' | b'.'));
+    if name.is_empty() || !valid_chars {
+        bail!(DatabaseError::InvalidArgument(format!(
+            "invalid tree name {:?}",
+            name
+        )));
+    }
+    if name.starts_with(['_', '.']) || name == "default" || name.starts_with("versedb") {
+        bail!(DatabaseError::InvalidArgument(format!(
+            "tree name {:?} is reserved",
+            name
+        )));
+    }
+    Ok(())
+}
+
+/// File holding the tree `name` of the file-backed database at `path`:
+/// `data.json` keeps tree `users` in `data.users.json`
+pub(crate) fn tree_file_path(path: &str, name: &str) -> PathBuf {
+    let path = Path::new(path);
+    let stem = path.file_stem().unwrap_or_default().to_string_lossy();
+    let file_name = match path.extension() {
+        Some(extension) => format!("{}.{}.{}", stem, name, extension.to_string_lossy()),
+        None => format!("{}.{}", stem, name),
+    };
+    path.with_file_name(file_name)
+}
+
+/// Suffixes of the files a file-backed database keeps next to its own: its
+/// temp file, backup, write-ahead log and lock file
+const SIDE_FILE_SUFFIXES: [&str; 4] = [".tmp", ".bak", ".wal", ".lock"];
+
+/// Names of the trees with a file next to the file-backed database at `path`
+pub(crate) fn tree_files(path: &str) -> Result<Vec<String>> {
+    let path = Path::new(path);
+    let Some(file_name) = path.file_name() else {
+        return Ok(Vec::new());
+    };
+    let directory = match path.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => parent,
+        _ => Path::new("."),
+    };
+    if !directory.exists() {
+        return Ok(Vec::new());
+    }
+
+    let stem = path.file_stem().unwrap_or(file_name).to_string_lossy();
+    let suffix = match path.extension() {
+        Some(extension) => format!(".{}", extension.to_string_lossy()),
+        None => String::new(),
+    };
+    let prefix = format!("{}.", stem);
+
+    let mut names = Vec::new();
+    for entry in std::fs::read_dir(directory)? {
+        let entry_name = entry?.file_name();
+        let entry_name = entry_name.to_string_lossy();
+        let name = entry_name
+            .strip_prefix(&prefix)
+            .and_then(|rest| rest.strip_suffix(&suffix))
+            .filter(|name| check_tree_name(name).is_ok())
+            // Without an extension `data.lock` would read as tree `lock`
+            .filter(|name| !SIDE_FILE_SUFFIXES.iter().any(|side| name.ends_with(side)));
+        if let Some(name) = name {
+            names.push(name.to_string());
+        }
+    }
+    names.sort();
+    Ok(names)
+}
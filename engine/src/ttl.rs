@@ -0,0 +1,185 @@
+use crate::database::Result;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::time::Duration;
+
+/// Milliseconds since the Unix epoch, the unit expiry deadlines are kept in
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) fn now_millis() -> u64 {
+    use std::time::{SystemTime, UNIX_EPOCH};
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_millis() as u64)
+        .unwrap_or(0)
+}
+
+/// Milliseconds since the Unix epoch, the unit expiry deadlines are kept in
+#[cfg(target_arch = "wasm32")]
+pub(crate) fn now_millis() -> u64 {
+    // SystemTime::now panics on wasm32-unknown-unknown
+    js_sys::Date::now() as u64
+}
+
+/// The deadline of an entry written now that should live for `ttl`
+pub(crate) fn deadline_after(ttl: Duration) -> u64 {
+    let ttl = u64::try_from(ttl.as_millis()).unwrap_or(u64::MAX);
+    now_millis().saturating_add(ttl)
+}
+
+/// Time left until `deadline`, `None` once it has passed
+pub(crate) fn remaining_until(deadline: u64) -> Option<Duration> {
+    let now = now_millis();
+    (deadline > now).then(|| Duration::from_millis(deadline - now))
+}
+
+/// Expiry metadata for the in-memory stores: each key's deadline plus a
+/// queue ordered by deadline, so finding what has expired never walks
+/// entries that are still live
+#[derive(Debug, Clone, Default)]
+pub(crate) struct Expiry {
+    deadlines: BTreeMap<Vec<u8>, u64>,
+    queue: BTreeSet<(u64, Vec<u8>)>,
+}
+
+impl Expiry {
+    /// Expire `key` at `deadline`, replacing any earlier deadline
+    pub(crate) fn set(&mut self, key: &[u8], deadline: u64) {
+        self.clear(key);
+        self.deadlines.insert(key.to_vec(), deadline);
+        self.queue.insert((deadline, key.to_vec()));
+    }
+
+    /// Make `key` live forever again
+    pub(crate) fn clear(&mut self, key: &[u8]) {
+        if let Some(deadline) = self.deadlines.remove(key) {
+            self.queue.remove(&(deadline, key.to_vec()));
+        }
+    }
+
+    /// The deadline of `key`, `None` if it never expires
+    pub(crate) fn deadline(&self, key: &[u8]) -> Option<u64> {
+        self.deadlines.get(key).copied()
+    }
+
+    /// Forget and return every key whose deadline is at or before `now`
+    pub(crate) fn take_expired(&mut self, now: u64) -> Vec<Vec<u8>> {
+        let mut expired = Vec::new();
+        while self
+            .queue
+            .first()
+            .is_some_and(|(deadline, _)| *deadline <= now)
+        {
+            let (_, key) = self.queue.pop_first().unwrap();
+            self.deadlines.remove(&key);
+            expired.push(key);
+        }
+        expired
+    }
+}
+
+// Persistent backends keep the same two indexes in a dedicated tree, column
+// family or table: `d<key>` holds the deadline of a key and
+// `q<deadline><key>` orders keys by deadline, with deadlines big-endian so
+// byte order matches numeric order. Every other write to a key deletes its
+// `d` record, making the key permanent; the `q` entry left behind is skipped
+// once its record is gone. Next to the deadline they store a fingerprint of
+// the value written with the TTL, so a record that outlived a write, such as
+// one from before writes cleared them, never expires a newer value.
+
+/// Index key holding the expiry record of `key`
+pub(crate) fn deadline_key(key: &[u8]) -> Vec<u8> {
+    let mut index_key = Vec::with_capacity(key.len() + 1);
+    index_key.push(b'd');
+    index_key.extend_from_slice(key);
+    index_key
+}
+
+/// Index key ordering `key` by `deadline`
+pub(crate) fn queue_key(deadline: u64, key: &[u8]) -> Vec<u8> {
+    let mut index_key = Vec::with_capacity(key.len() + 9);
+    index_key.push(b'q');
+    index_key.extend_from_slice(&deadline.to_be_bytes());
+    index_key.extend_from_slice(key);
+    index_key
+}
+
+/// The deadline and data key a queue key refers to
+pub(crate) fn decode_queue_key(queue_key: &[u8]) -> Option<(u64, &[u8])> {
+    let deadline = queue_key.get(1..9)?.try_into().ok()?;
+    Some((u64::from_be_bytes(deadline), &queue_key[9..]))
+}
+
+/// Queue keys [start, end) of every entry expired at `now`
+pub(crate) fn expired_queue_range(now: u64) -> (Vec<u8>, Vec<u8>) {
+    (vec![b'q'], queue_key(now.saturating_add(1), &[]))
+}
+
+/// A stable 64-bit FNV-1a hash identifying the value an expiry applies to
+pub(crate) fn fingerprint(value: &[u8]) -> u64 {
+    value.iter().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ u64::from(*byte)).wrapping_mul(0x0000_0100_0000_01b3)
+    })
+}
+
+/// Encode the expiry record stored under a `deadline_key`
+pub(crate) fn encode_expiry(deadline: u64, value: &[u8]) -> Vec<u8> {
+    let mut record = Vec::with_capacity(16);
+    record.extend_from_slice(&deadline.to_be_bytes());
+    record.extend_from_slice(&fingerprint(value).to_be_bytes());
+    record
+}
+
+/// Decode an expiry record into its deadline and value fingerprint
+pub(crate) fn decode_expiry(record: &[u8]) -> Option<(u64, u64)> {
+    let deadline = record.get(0..8)?.try_into().ok()?;
+    let fingerprint = record.get(8..16)?.try_into().ok()?;
+    Some((
+        u64::from_be_bytes(deadline),
+        u64::from_be_bytes(fingerprint),
+    ))
+}
+
+/// Whether `value` has run out according to `record`, the expiry record
+/// stored for its key
+pub(crate) fn has_expired(record: Option<&[u8]>, value: &[u8], now: u64) -> bool {
+    record
+        .and_then(decode_expiry)
+        .is_some_and(|(deadline, hash)| deadline <= now && hash == fingerprint(value))
+}
+
+/// The keys expired at some instant, gathered from the queue in one pass so
+/// a range read can skip them without looking up each key's record
+#[derive(Debug, Default)]
+pub(crate) struct ExpiredKeys(HashMap<Vec<u8>, u64>);
+
+impl ExpiredKeys {
+    /// Gather the keys of `queue`, the queue entries in `expired_queue_range`,
+    /// reading the current record of each through `record`. Stale entries,
+    /// whose key got a new deadline or none since, are left out.
+    pub(crate) fn collect(
+        queue: impl IntoIterator<Item = Result<Vec<u8>>>,
+        record: impl Fn(&[u8]) -> Result<Option<Vec<u8>>>,
+    ) -> Result<Self> {
+        let mut keys = HashMap::new();
+        for queue_key in queue {
+            let queue_key = queue_key?;
+            let Some((deadline, key)) = decode_queue_key(&queue_key) else {
+                continue;
+            };
+            match record(&deadline_key(key))?
+                .as_deref()
+                .and_then(decode_expiry)
+            {
+                Some((current, hash)) if current == deadline => {
+                    keys.insert(key.to_vec(), hash);
+                }
+                _ => {}
+            }
+        }
+        Ok(Self(keys))
+    }
+
+    /// Whether `value`, stored under `key`, is one of the expired entries
+    pub(crate) fn contains(&self, key: &[u8], value: &[u8]) -> bool {
+        self.0.get(key) == Some(&fingerprint(value))
+    }
+}
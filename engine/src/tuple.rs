@@ -0,0 +1,385 @@
+use crate::collection::OrderedKey;
+use crate::database::Result;
+use anyhow::{anyhow, bail};
+use chrono::{DateTime, Utc};
+use std::fmt;
+
+// Type codes, following the FoundationDB tuple layer so that byte order
+// matches the order of the values; elements of different types sort by code
+const NULL: u8 = 0x00;
+const BYTES: u8 = 0x01;
+const STRING: u8 = 0x02;
+const NESTED: u8 = 0x05;
+const NEGATIVE_BIG_INT: u8 = 0x0b;
+const INT_ZERO: u8 = 0x14;
+const POSITIVE_BIG_INT: u8 = 0x1d;
+const FLOAT: u8 = 0x20;
+const DOUBLE: u8 = 0x21;
+const FALSE: u8 = 0x26;
+const TRUE: u8 = 0x27;
+/// From the range FoundationDB leaves to applications
+const TIMESTAMP: u8 = 0x40;
+
+/// Marks a zero byte inside a byte string, or a null inside a nested tuple,
+/// as data rather than a terminator
+const ESCAPE: u8 = 0xff;
+
+/// One value in a `Tuple`
+#[derive(Debug, Clone, PartialEq)]
+pub enum Element {
+    Null,
+    Bytes(Vec<u8>),
+    String(String),
+    Tuple(Tuple),
+    /// Any integer, signed or unsigned, up to 128 bits
+    Int(i128),
+    Float(f32),
+    Double(f64),
+    Bool(bool),
+    Timestamp(DateTime<Utc>),
+}
+
+/// A sequence of elements packed into a key whose bytes sort in the same
+/// order as the elements, compared left to right. The packed form of a tuple
+/// is a prefix of the packed form of every tuple extending it.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Tuple(Vec<Element>);
+
+impl Tuple {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Append `element`, for building a tuple in one expression
+    pub fn push(mut self, element: impl Into<Element>) -> Self {
+        self.0.push(element.into());
+        self
+    }
+
+    pub fn elements(&self) -> &[Element] {
+        &self.0
+    }
+
+    pub fn into_elements(self) -> Vec<Element> {
+        self.0
+    }
+
+    /// Encode the tuple into bytes
+    pub fn pack(&self) -> Vec<u8> {
+        let mut out = Vec::new();
+        for element in &self.0 {
+            encode_element(element, &mut out, false);
+        }
+        out
+    }
+
+    /// Decode bytes written by `pack`
+    pub fn unpack(bytes: &[u8]) -> Result<Self> {
+        let mut decoder = Decoder { bytes, pos: 0 };
+        let mut elements = Vec::new();
+        while decoder.pos < bytes.len() {
+            elements.push(decoder.element(false)?);
+        }
+        Ok(Self(elements))
+    }
+
+    /// Key range [start, end) holding every packed tuple that extends this
+    /// one with at least one more element
+    pub fn range(&self) -> (Vec<u8>, Vec<u8>) {
+        let packed = self.pack();
+        let mut start = packed.clone();
+        start.push(0x00);
+        let mut end = packed;
+        end.push(0xff);
+        (start, end)
+    }
+}
+
+impl From<Vec<Element>> for Tuple {
+    fn from(elements: Vec<Element>) -> Self {
+        Self(elements)
+    }
+}
+
+impl OrderedKey for Tuple {
+    fn encode_key(&self) -> Vec<u8> {
+        self.pack()
+    }
+
+    fn decode_key(bytes: &[u8]) -> Result<Self> {
+        Self::unpack(bytes)
+    }
+}
+
+impl fmt::Display for Tuple {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "(")?;
+        for (i, element) in self.0.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            match element {
+                Element::Null => write!(f, "null")?,
+                Element::Bytes(bytes) => write!(f, "b{:?}", String::from_utf8_lossy(bytes))?,
+                Element::String(string) => write!(f, "{:?}", string)?,
+                Element::Tuple(tuple) => write!(f, "{}", tuple)?,
+                Element::Int(int) => write!(f, "{}", int)?,
+                Element::Float(float) => write!(f, "{}", float)?,
+                Element::Double(double) => write!(f, "{}", double)?,
+                Element::Bool(bool) => write!(f, "{}", bool)?,
+                Element::Timestamp(timestamp) => write!(f, "{}", timestamp.to_rfc3339())?,
+            }
+        }
+        write!(f, ")")
+    }
+}
+
+macro_rules! element_from {
+    ($($ty:ty => $variant:ident),* $(,)?) => {$(
+        impl From<$ty> for Element {
+            fn from(value: $ty) -> Self {
+                Element::$variant(value.into())
+            }
+        }
+    )*};
+}
+
+element_from!(
+    Vec<u8> => Bytes,
+    &[u8] => Bytes,
+    String => String,
+    &str => String,
+    Tuple => Tuple,
+    i8 => Int, i16 => Int, i32 => Int, i64 => Int, i128 => Int,
+    u8 => Int, u16 => Int, u32 => Int, u64 => Int,
+    f32 => Float,
+    f64 => Double,
+    bool => Bool,
+    DateTime<Utc> => Timestamp,
+);
+
+/// Append `bytes` with every zero byte escaped, then the terminator
+fn encode_escaped(bytes: &[u8], out: &mut Vec<u8>) {
+    for &byte in bytes {
+        out.push(byte);
+        if byte == 0x00 {
+            out.push(ESCAPE);
+        }
+    }
+    out.push(0x00);
+}
+
+fn encode_int(value: i128, out: &mut Vec<u8>) {
+    if value == 0 {
+        out.push(INT_ZERO);
+        return;
+    }
+    let magnitude = value.unsigned_abs();
+    let len = 16 - magnitude.leading_zeros() as usize / 8;
+    let be = magnitude.to_be_bytes();
+    let digits = &be[16 - len..];
+
+    // Negative numbers store the one's complement of their magnitude, so a
+    // larger magnitude sorts lower
+    match (value > 0, len <= 8) {
+        (true, true) => {
+            out.push(INT_ZERO + len as u8);
+            out.extend_from_slice(digits);
+        }
+        (false, true) => {
+            out.push(INT_ZERO - len as u8);
+            out.extend(digits.iter().map(|digit| !digit));
+        }
+        (true, false) => {
+            out.push(POSITIVE_BIG_INT);
+            out.push(len as u8);
+            out.extend_from_slice(digits);
+        }
+        (false, false) => {
+            out.push(NEGATIVE_BIG_INT);
+            out.push(!(len as u8));
+            out.extend(digits.iter().map(|digit| !digit));
+        }
+    }
+}
+
+fn encode_element(element: &Element, out: &mut Vec<u8>, nested: bool) {
+    match element {
+        Element::Null => {
+            out.push(NULL);
+            if nested {
+                out.push(ESCAPE);
+            }
+        }
+        Element::Bytes(bytes) => {
+            out.push(BYTES);
+            encode_escaped(bytes, out);
+        }
+        Element::String(string) => {
+            out.push(STRING);
+            encode_escaped(string.as_bytes(), out);
+        }
+        Element::Tuple(tuple) => {
+            out.push(NESTED);
+            for element in &tuple.0 {
+                encode_element(element, out, true);
+            }
+            out.push(0x00);
+        }
+        Element::Int(int) => encode_int(*int, out),
+        // Flipping the sign bit of positive floats, and every bit of negative
+        // ones, makes their bits sort like their values
+        Element::Float(float) => {
+            let bits = float.to_bits();
+            let bits = if bits >> 31 == 1 {
+                !bits
+            } else {
+                bits ^ (1 << 31)
+            };
+            out.push(FLOAT);
+            out.extend_from_slice(&bits.to_be_bytes());
+        }
+        Element::Double(double) => {
+            let bits = double.to_bits();
+            let bits = if bits >> 63 == 1 {
+                !bits
+            } else {
+                bits ^ (1 << 63)
+            };
+            out.push(DOUBLE);
+            out.extend_from_slice(&bits.to_be_bytes());
+        }
+        Element::Bool(false) => out.push(FALSE),
+        Element::Bool(true) => out.push(TRUE),
+        Element::Timestamp(timestamp) => {
+            let seconds = (timestamp.timestamp() as u64) ^ (1 << 63);
+            out.push(TIMESTAMP);
+            out.extend_from_slice(&seconds.to_be_bytes());
+            out.extend_from_slice(&timestamp.timestamp_subsec_nanos().to_be_bytes());
+        }
+    }
+}
+
+struct Decoder<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl Decoder<'_> {
+    fn take(&mut self, len: usize) -> Result<&[u8]> {
+        let end = self.pos + len;
+        let taken = self
+            .bytes
+            .get(self.pos..end)
+            .ok_or_else(|| anyhow!("tuple truncated at byte {}", self.pos))?;
+        self.pos = end;
+        Ok(taken)
+    }
+
+    fn byte(&mut self) -> Result<u8> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.bytes.get(self.pos).copied()
+    }
+
+    fn escaped(&mut self) -> Result<Vec<u8>> {
+        let mut out = Vec::new();
+        loop {
+            match self.byte()? {
+                0x00 if self.peek() == Some(ESCAPE) => {
+                    self.pos += 1;
+                    out.push(0x00);
+                }
+                0x00 => return Ok(out),
+                byte => out.push(byte),
+            }
+        }
+    }
+
+    fn magnitude(&mut self, len: usize, negative: bool) -> Result<u128> {
+        if len > 16 {
+            bail!("tuple integer of {} bytes is too large", len);
+        }
+        let mut magnitude = 0u128;
+        for &digit in self.take(len)? {
+            let digit = if negative { !digit } else { digit };
+            magnitude = (magnitude << 8) | digit as u128;
+        }
+        Ok(magnitude)
+    }
+
+    fn element(&mut self, nested: bool) -> Result<Element> {
+        let code = self.byte()?;
+        Ok(match code {
+            NULL => {
+                if nested {
+                    self.take(1)?;
+                }
+                Element::Null
+            }
+            BYTES => Element::Bytes(self.escaped()?),
+            STRING => Element::String(String::from_utf8(self.escaped()?)?),
+            NESTED => {
+                let mut elements = Vec::new();
+                loop {
+                    match (self.peek(), self.bytes.get(self.pos + 1)) {
+                        (Some(0x00), Some(&ESCAPE)) => {}
+                        (Some(0x00), _) => {
+                            self.pos += 1;
+                            break;
+                        }
+                        (None, _) => bail!("unterminated nested tuple"),
+                        _ => {}
+                    }
+                    elements.push(self.element(true)?);
+                }
+                Element::Tuple(Tuple(elements))
+            }
+            NEGATIVE_BIG_INT | POSITIVE_BIG_INT | 0x0c..=0x1c => {
+                let negative = code < INT_ZERO;
+                let len = match code {
+                    NEGATIVE_BIG_INT => !self.byte()? as usize,
+                    POSITIVE_BIG_INT => self.byte()? as usize,
+                    _ => code.abs_diff(INT_ZERO) as usize,
+                };
+                let magnitude = self.magnitude(len, negative)?;
+                let int = if negative {
+                    0i128.checked_sub_unsigned(magnitude)
+                } else {
+                    i128::try_from(magnitude).ok()
+                };
+                Element::Int(int.ok_or_else(|| anyhow!("tuple integer out of range"))?)
+            }
+            FLOAT => {
+                let bits = u32::from_be_bytes(self.take(4)?.try_into()?);
+                let bits = if bits >> 31 == 1 {
+                    bits ^ (1 << 31)
+                } else {
+                    !bits
+                };
+                Element::Float(f32::from_bits(bits))
+            }
+            DOUBLE => {
+                let bits = u64::from_be_bytes(self.take(8)?.try_into()?);
+                let bits = if bits >> 63 == 1 {
+                    bits ^ (1 << 63)
+                } else {
+                    !bits
+                };
+                Element::Double(f64::from_bits(bits))
+            }
+            FALSE => Element::Bool(false),
+            TRUE => Element::Bool(true),
+            TIMESTAMP => {
+                let seconds = u64::from_be_bytes(self.take(8)?.try_into()?) ^ (1 << 63);
+                let nanos = u32::from_be_bytes(self.take(4)?.try_into()?);
+                let timestamp = DateTime::from_timestamp(seconds as i64, nanos)
+                    .ok_or_else(|| anyhow!("tuple timestamp out of range"))?;
+                Element::Timestamp(timestamp)
+            }
+            code => bail!("unknown tuple type code {:#04x}", code),
+        })
+    }
+}
@@ -0,0 +1,303 @@
+use crate::batch::{BatchOp, WriteBatch};
+use crate::database::{DatabaseError, Result};
+use crate::durable::sibling;
+use crate::ttl::fingerprint;
+use anyhow::bail;
+use std::collections::BTreeMap;
+use std::fs::{self, File, OpenOptions};
+use std::io::{ErrorKind, Write};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+const PUT: u8 = 0;
+const DELETE: u8 = 1;
+const DELETE_RANGE: u8 = 2;
+
+/// Length and checksum in front of every logged batch
+const FRAME_HEADER: usize = 12;
+
+/// Write-ahead logging for the file-backed databases: every write is
+/// appended and synced to a log next to the main file before it is applied,
+/// and replayed on `open`, so writes made since the last flush survive a crash
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WalOptions {
+    /// Number of logged writes after which the log is compacted: the main
+    /// file is rewritten with every entry and the log starts over
+    pub compact_after: usize,
+}
+
+impl Default for WalOptions {
+    fn default() -> Self {
+        Self {
+            compact_after: 1000,
+        }
+    }
+}
+
+/// Log kept for the database at `path`: `data.json.wal`
+pub(crate) fn wal_path(path: &Path) -> PathBuf {
+    sibling(path, ".wal")
+}
+
+fn put_bytes(frame: &mut Vec<u8>, bytes: &[u8]) {
+    frame.extend_from_slice(&(bytes.len() as u32).to_le_bytes());
+    frame.extend_from_slice(bytes);
+}
+
+fn encode_batch(batch: &WriteBatch) -> Vec<u8> {
+    let mut payload = Vec::new();
+    for op in batch.ops() {
+        match op {
+            BatchOp::Put { key, value } => {
+                payload.push(PUT);
+                put_bytes(&mut payload, key);
+                put_bytes(&mut payload, value);
+            }
+            BatchOp::Delete { key } => {
+                payload.push(DELETE);
+                put_bytes(&mut payload, key);
+            }
+            BatchOp::DeleteRange { start, end } => {
+                payload.push(DELETE_RANGE);
+                put_bytes(&mut payload, start);
+                put_bytes(&mut payload, end);
+            }
+        }
+    }
+
+    let mut frame = Vec::with_capacity(FRAME_HEADER + payload.len());
+    frame.extend_from_slice(&(payload.len() as u32).to_le_bytes());
+    frame.extend_from_slice(&fingerprint(&payload).to_le_bytes());
+    frame.extend_from_slice(&payload);
+    frame
+}
+
+fn take_bytes<'a>(payload: &mut &'a [u8]) -> Result<&'a [u8]> {
+    let Some((len, rest)) = payload.split_first_chunk::<4>() else {
+        bail!(DatabaseError::Corruption(
+            "truncated write-ahead log entry".to_string()
+        ));
+    };
+    let len = u32::from_le_bytes(*len) as usize;
+    if rest.len() < len {
+        bail!(DatabaseError::Corruption(
+            "truncated write-ahead log entry".to_string()
+        ));
+    }
+    let (bytes, rest) = rest.split_at(len);
+    *payload = rest;
+    Ok(bytes)
+}
+
+fn decode_batch(mut payload: &[u8]) -> Result<WriteBatch> {
+    let mut batch = WriteBatch::new();
+    while let Some((&tag, rest)) = payload.split_first() {
+        payload = rest;
+        match tag {
+            PUT => {
+                let key = take_bytes(&mut payload)?;
+                batch.put(key, take_bytes(&mut payload)?);
+            }
+            DELETE => {
+                batch.delete(take_bytes(&mut payload)?);
+            }
+            DELETE_RANGE => {
+                let start = take_bytes(&mut payload)?;
+                batch.delete_range(start, take_bytes(&mut payload)?);
+            }
+            tag => bail!(DatabaseError::Corruption(format!(
+                "unknown write-ahead log operation {}",
+                tag
+            ))),
+        }
+    }
+    Ok(batch)
+}
+
+/// The batches logged for the database at `path`, in the order they were
+/// written, and the length of the log they fill. A crash in the middle of an
+/// append leaves a partial last frame, which fails its checksum; it was never
+/// applied, so it is skipped.
+pub(crate) fn replay(path: &Path) -> Result<(Vec<WriteBatch>, u64)> {
+    let log = match fs::read(wal_path(path)) {
+        Ok(log) => log,
+        Err(e) if e.kind() == ErrorKind::NotFound => return Ok((Vec::new(), 0)),
+        Err(e) => return Err(e.into()),
+    };
+
+    let mut batches = Vec::new();
+    let mut offset = 0;
+    while let Some(header) = log.get(offset..offset + FRAME_HEADER) {
+        let len = u32::from_le_bytes(header[..4].try_into()?) as usize;
+        let checksum = u64::from_le_bytes(header[4..].try_into()?);
+        let start = offset + FRAME_HEADER;
+        let Some(payload) = log.get(start..start + len) else {
+            break;
+        };
+        if fingerprint(payload) != checksum {
+            break;
+        }
+        batches.push(decode_batch(payload)?);
+        offset = start + len;
+    }
+    Ok((batches, offset as u64))
+}
+
+/// Log of a database opened with a `WalOptions`, appended to until the next
+/// compaction
+pub(crate) struct WriteAheadLog {
+    file: File,
+    options: WalOptions,
+    /// Batches logged since the main file was last written
+    logged: usize,
+}
+
+impl WriteAheadLog {
+    /// Open the log of the database at `path` for appending, after `logged`
+    /// batches filling its first `intact` bytes were replayed. Whatever
+    /// follows them is a torn frame and is cut off.
+    pub(crate) fn open(
+        path: &Path,
+        options: WalOptions,
+        logged: usize,
+        intact: u64,
+    ) -> Result<Self> {
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(wal_path(path))?;
+        if file.metadata()?.len() > intact {
+            file.set_len(intact)?;
+            file.sync_all()?;
+        }
+        Ok(Self {
+            file,
+            options,
+            logged,
+        })
+    }
+
+    /// Append `batch` and wait for it to reach the disk
+    pub(crate) fn append(&mut self, batch: &WriteBatch) -> Result<()> {
+        if batch.is_empty() {
+            return Ok(());
+        }
+        let len = self.file.metadata()?.len();
+        let written = self
+            .file
+            .write_all(&encode_batch(batch))
+            .and_then(|()| self.file.sync_data());
+        if let Err(e) = written {
+            // The caller won't apply a batch that failed, so cut off whatever
+            // part of it reached the log rather than replay it on open, or
+            // leave a torn frame hiding the batches after it
+            let _ = self.file.set_len(len);
+            return Err(e.into());
+        }
+        self.logged += 1;
+        Ok(())
+    }
+
+    /// Whether enough has been logged that the main file should be rewritten
+    pub(crate) fn needs_compaction(&self) -> bool {
+        self.logged >= self.options.compact_after
+    }
+
+    /// Empty the log once the main file holds everything in it
+    pub(crate) fn reset(&mut self) -> Result<()> {
+        self.file.set_len(0)?;
+        self.file.sync_all()?;
+        self.logged = 0;
+        Ok(())
+    }
+}
+
+/// Log shared by the handles on one tree of a file-backed database, `None`
+/// outside WAL mode
+pub(crate) type SharedLog = Option<Arc<Mutex<WriteAheadLog>>>;
+
+/// Replay the log of the database at `path` onto `data`, loaded from the main
+/// file, and open the log for appending if `options` ask for WAL mode.
+/// A log is replayed even outside WAL mode, and deleted by the next `checkpoint`.
+pub(crate) fn recover<V>(
+    path: &Path,
+    data: &mut BTreeMap<Vec<u8>, V>,
+    options: Option<WalOptions>,
+    convert: impl Fn(&[u8]) -> Result<V>,
+) -> Result<SharedLog> {
+    let (batches, intact) = replay(path)?;
+    let logged = batches.len();
+    for batch in batches {
+        batch.apply_to_map(data, &convert)?;
+    }
+    options
+        .map(|options| {
+            let log = WriteAheadLog::open(path, options, logged, intact)?;
+            Ok(Arc::new(Mutex::new(log)))
+        })
+        .transpose()
+}
+
+/// Append `batch` to `log` in WAL mode. Callers hold the data lock and apply
+/// the batch only once it is logged.
+pub(crate) fn append(log: &SharedLog, batch: &WriteBatch) -> Result<()> {
+    match log {
+        Some(log) => log.lock().unwrap().append(batch),
+        None => Ok(()),
+    }
+}
+
+/// Whether `log` has grown long enough to be compacted
+pub(crate) fn compaction_due(log: &SharedLog) -> bool {
+    log.as_ref()
+        .is_some_and(|log| log.lock().unwrap().needs_compaction())
+}
+
+/// Fold `log` into the main file at `path` through `persist` once it has
+/// grown long enough. The write that made it due is already durable in the
+/// log, so a failure is only reported and compaction is retried on the next
+/// write.
+pub(crate) fn compact_if_due(log: &SharedLog, path: &Path, persist: impl FnOnce() -> Result<()>) {
+    if compaction_due(log)
+        && let Err(e) = persist()
+    {
+        eprintln!(
+            "Failed to compact the write-ahead log of {}: {}",
+            path.display(),
+            e
+        );
+    }
+}
+
+/// Start `log` over once the main file at `path` holds every entry, or
+/// delete the log an earlier session in WAL mode left behind
+pub(crate) fn checkpoint(log: &SharedLog, path: &Path) -> Result<()> {
+    match log {
+        Some(log) => log.lock().unwrap().reset(),
+        None => match fs::remove_file(wal_path(path)) {
+            Err(e) if e.kind() != ErrorKind::NotFound => Err(e.into()),
+            _ => Ok(()),
+        },
+    }
+}
+
+/// Batch deleting each of `keys`, to log a removal by the keys it found
+pub(crate) fn removal_batch(keys: &[Vec<u8>]) -> WriteBatch {
+    let mut batch = WriteBatch::new();
+    for key in keys {
+        batch.delete(key);
+    }
+    batch
+}
+
+/// Batch of the writes a transaction made, `None` marking a removal
+pub(crate) fn writes_batch(writes: &BTreeMap<Vec<u8>, Option<Vec<u8>>>) -> WriteBatch {
+    let mut batch = WriteBatch::new();
+    for (key, value) in writes {
+        match value {
+            Some(value) => batch.put(key, value),
+            None => batch.delete(key),
+        };
+    }
+    batch
+}
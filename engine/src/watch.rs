@@ -0,0 +1,93 @@
+use futures::channel::mpsc::{self, UnboundedSender};
+use std::collections::BTreeMap;
+use std::sync::{Arc, Mutex};
+
+/// A change to a watched key, reported by `Database::watch_prefix`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WatchEvent {
+    /// A key-value pair was added or overwritten
+    Put { key: Vec<u8>, value: Vec<u8> },
+    /// A key was removed
+    Delete { key: Vec<u8> },
+}
+
+impl WatchEvent {
+    /// The key the event applies to
+    pub fn key(&self) -> &[u8] {
+        match self {
+            WatchEvent::Put { key, .. } | WatchEvent::Delete { key } => key,
+        }
+    }
+}
+
+/// Stream of changes returned by `Database::watch_prefix`
+#[cfg(not(target_arch = "wasm32"))]
+pub type WatchStream = futures::stream::BoxStream<'static, WatchEvent>;
+
+/// Stream of changes returned by `Database::watch_prefix`
+#[cfg(target_arch = "wasm32")]
+pub type WatchStream = futures::stream::LocalBoxStream<'static, WatchEvent>;
+
+#[derive(Debug)]
+struct Subscriber {
+    prefix: Vec<u8>,
+    sender: UnboundedSender<WatchEvent>,
+}
+
+/// In-process fan-out of committed writes to `watch_prefix` streams, for
+/// backends without a native change feed. Clones share their subscribers.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct ChangeBroadcaster {
+    subscribers: Arc<Mutex<Vec<Subscriber>>>,
+}
+
+impl ChangeBroadcaster {
+    /// Stream every change to a key starting with `prefix` from now on
+    pub(crate) fn subscribe(&self, prefix: &[u8]) -> WatchStream {
+        let (sender, receiver) = mpsc::unbounded();
+        self.subscribers.lock().unwrap().push(Subscriber {
+            prefix: prefix.to_vec(),
+            sender,
+        });
+        Box::pin(receiver)
+    }
+
+    /// Whether any stream is listening, so callers can skip working out
+    /// changes nobody will see
+    pub(crate) fn is_watched(&self) -> bool {
+        !self.subscribers.lock().unwrap().is_empty()
+    }
+
+    /// Report that `key` now holds `value`, or was removed when it is `None`.
+    /// Streams that have been dropped are forgotten along the way.
+    pub(crate) fn publish(&self, key: &[u8], value: Option<&[u8]>) {
+        let mut subscribers = self.subscribers.lock().unwrap();
+        subscribers.retain(|subscriber| {
+            if !key.starts_with(&subscriber.prefix) {
+                return !subscriber.sender.is_closed();
+            }
+            let event = match value {
+                Some(value) => WatchEvent::Put {
+                    key: key.to_vec(),
+                    value: value.to_vec(),
+                },
+                None => WatchEvent::Delete { key: key.to_vec() },
+            };
+            subscriber.sender.unbounded_send(event).is_ok()
+        });
+    }
+
+    /// Report every change in `writes`, as produced by a transaction or batch
+    pub(crate) fn publish_all(&self, writes: &BTreeMap<Vec<u8>, Option<Vec<u8>>>) {
+        for (key, value) in writes {
+            self.publish(key, value.as_deref());
+        }
+    }
+
+    /// Report the removal of each of `entries`
+    pub(crate) fn publish_removed(&self, entries: &[(Vec<u8>, Vec<u8>)]) {
+        for (key, _) in entries {
+            self.publish(key, None);
+        }
+    }
+}
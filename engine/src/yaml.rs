@@ -1,36 +1,112 @@
-use super::database::{Database, Result};
+use super::batch::WriteBatch;
+use super::database::{
+    CompareAndSwapError, Database, DatabaseError, KvStream, MergeOperator, Result, ScanOptions,
+    Transaction,
+};
+use super::durable::{recover_file, remove_file, write_file};
+use super::lock::FileLock;
+use super::merge::{increment_in, merge_in};
+use super::scan::{chunked_scan, prefix_bounds, select_from_map};
+use super::snapshot::{MapSnapshot, Snapshot};
+use super::transaction::{OverlayTransaction, compare_and_swap_in};
+use super::tree::{TreeRegistry, check_tree_name, tree_file_path, tree_files};
+use super::ttl::{Expiry, deadline_after, now_millis, remaining_until};
+use super::wal::{self, SharedLog, WalOptions};
+use super::watch::{ChangeBroadcaster, WatchStream};
+use anyhow::bail;
 use async_trait::async_trait;
+use futures::future;
 use serde_yaml::{self, Value};
 use std::collections::BTreeMap;
 use std::error::Error;
 use std::fs;
 use std::path::Path;
-use std::sync::Mutex;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::Duration;
+
+/// How a `YamlDatabase` persists its writes
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct YamlOptions {
+    /// Log every write ahead of applying it, instead of only writing the
+    /// file on `flush`
+    pub wal: Option<WalOptions>,
+    /// Share the lock on the file with other read-only handles, even in
+    /// other processes, and refuse every write
+    pub read_only: bool,
+}
 
 pub struct YamlDatabase {
-    data: Mutex<BTreeMap<Vec<u8>, Vec<u8>>>,
+    /// Shared with the other handles on the same tree
+    data: Arc<Mutex<BTreeMap<Vec<u8>, Vec<u8>>>>,
     path: String,
+    options: YamlOptions,
+    /// Shared with the other handles on the same tree
+    wal: SharedLog,
+    /// Held until every handle on the file, clones included, is dropped
+    lock: Arc<FileLock>,
+    merge_operator: Option<MergeOperator>,
+    /// Deadlines of entries added with a TTL, kept in memory only: they are
+    /// not written to the file and don't survive reopening it
+    expiry: Arc<Mutex<Expiry>>,
+    watchers: ChangeBroadcaster,
+    trees: TreeRegistry<YamlDatabase>,
 }
 
 impl Clone for YamlDatabase {
+    /// Another handle on the same tree, sharing its data, log and file, so
+    /// writes through either one reach the file together
     fn clone(&self) -> Self {
         Self {
-            data: Mutex::new(self.data.lock().unwrap().clone()),
-            path: self.path.clone(),
+            merge_operator: self.merge_operator.clone(),
+            ..self.share(&self.trees)
         }
     }
 }
 
-#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
-#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
-impl Database for YamlDatabase {
-    async fn open(path: &str) -> Result<Self> {
+impl YamlDatabase {
+    /// A new handle on the same tree as `self`, within the database owning `trees`
+    fn share(&self, trees: &TreeRegistry<YamlDatabase>) -> Self {
+        Self {
+            path: self.path.clone(),
+            data: Arc::clone(&self.data),
+            options: self.options,
+            wal: self.wal.clone(),
+            lock: Arc::clone(&self.lock),
+            merge_operator: None,
+            expiry: Arc::clone(&self.expiry),
+            watchers: self.watchers.clone(),
+            trees: Arc::clone(trees),
+        }
+    }
+
+    /// Drop every entry whose TTL has run out, returning how many were removed
+    fn evict_expired(&self, data: &mut BTreeMap<Vec<u8>, Vec<u8>>) -> usize {
+        let expired = self.expiry.lock().unwrap().take_expired(now_millis());
+        expired
+            .iter()
+            .filter(|key| data.remove(key.as_slice()).is_some())
+            .inspect(|key| self.watchers.publish(key, None))
+            .count()
+    }
+
+    /// Lock the data for an operation, so expired entries are never observed
+    fn lock_data(&self) -> MutexGuard<'_, BTreeMap<Vec<u8>, Vec<u8>>> {
+        let mut data = self.data.lock().unwrap();
+        self.evict_expired(&mut data);
+        data
+    }
+
+    /// Open the file at `path`, logging writes ahead if `options` ask to
+    pub async fn open_with(path: &str, options: YamlOptions) -> Result<Self> {
+        let lock = FileLock::acquire(Path::new(path), options.read_only)?;
+        let source = recover_file(Path::new(path), !options.read_only)?;
         let mut data = BTreeMap::new();
 
-        if Path::new(path).exists() {
-            let contents = fs::read_to_string(path)?;
+        if source.exists() {
+            let contents = fs::read_to_string(&source)?;
             if !contents.trim().is_empty() {
-                let yaml: Value = serde_yaml::from_str(&contents)?;
+                let yaml: Value = serde_yaml::from_str(&contents)
+                    .map_err(|e| DatabaseError::Corruption(e.to_string()))?;
                 if let Value::Mapping(map) = yaml {
                     for (key, value) in map {
                         if let (Value::String(k), Value::String(v)) = (key, value) {
@@ -40,31 +116,104 @@ impl Database for YamlDatabase {
                 }
             }
         }
+        let wal = wal::recover(
+            Path::new(path),
+            &mut data,
+            options.wal.filter(|_| !options.read_only),
+            |value| Ok(value.to_vec()),
+        )?;
 
         Ok(Self {
-            data: Mutex::new(data),
+            data: Arc::new(Mutex::new(data)),
             path: path.to_string(),
+            options,
+            wal,
+            lock,
+            merge_operator: None,
+            expiry: Arc::default(),
+            watchers: ChangeBroadcaster::default(),
+            trees: TreeRegistry::default(),
         })
     }
 
+    /// Write every entry to the file, which then holds everything logged
+    fn persist(&self, data: &BTreeMap<Vec<u8>, Vec<u8>>) -> Result<()> {
+        self.lock.check_held()?;
+        let mut map = serde_yaml::Mapping::new();
+
+        for (key, value) in data.iter() {
+            let key_str = String::from_utf8(key.clone())?;
+            let value_str = String::from_utf8(value.clone())?;
+            map.insert(Value::String(key_str), Value::String(value_str));
+        }
+
+        let yaml = Value::Mapping(map);
+        let yaml_str = serde_yaml::to_string(&yaml)?;
+        write_file(Path::new(&self.path), yaml_str.as_bytes())?;
+        wal::checkpoint(&self.wal, Path::new(&self.path))
+    }
+
+    /// Check a write may be made and log it ahead in WAL mode. Writes that
+    /// change nothing are let through in read-only mode.
+    fn log_write(&self, batch: &WriteBatch) -> Result<()> {
+        if self.options.read_only && !batch.is_empty() {
+            bail!(DatabaseError::ReadOnly(
+                "the database was opened read-only".to_string()
+            ));
+        }
+        if !batch.is_empty() {
+            self.lock.check_held()?;
+        }
+        wal::append(&self.wal, batch)
+    }
+
+    /// Fold the write-ahead log into the file once it has grown long enough,
+    /// without failing the write that made it due
+    fn compact_if_due(&self, data: &BTreeMap<Vec<u8>, Vec<u8>>) {
+        wal::compact_if_due(&self.wal, Path::new(&self.path), || self.persist(data));
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl Database for YamlDatabase {
+    async fn open(path: &str) -> Result<Self> {
+        Self::open_with(path, YamlOptions::default()).await
+    }
+
     async fn close(&mut self) -> Result<()> {
-        self.flush().await
+        if !self.lock.is_held() {
+            return Ok(());
+        }
+        self.flush().await?;
+        // Let the file be opened again, from this process or another one
+        self.lock.release(self.options.read_only);
+        Ok(())
     }
 
     async fn add(&mut self, key: &[u8], value: &[u8]) -> Result<()> {
-        self.data
-            .lock()
-            .unwrap()
-            .insert(key.to_vec(), value.to_vec());
+        let mut data = self.lock_data();
+        self.log_write(WriteBatch::new().put(key, value))?;
+        self.expiry.lock().unwrap().clear(key);
+        self.watchers.publish(key, Some(value));
+        data.insert(key.to_vec(), value.to_vec());
+        self.compact_if_due(&data);
         Ok(())
     }
 
     async fn select(&self, key: &[u8]) -> Result<Option<Vec<u8>>> {
-        Ok(self.data.lock().unwrap().get(key).cloned())
+        Ok(self.lock_data().get(key).cloned())
     }
 
     async fn remove(&mut self, key: &[u8]) -> Result<()> {
-        self.data.lock().unwrap().remove(key);
+        let mut data = self.lock_data();
+        if !data.contains_key(key) {
+            return Ok(());
+        }
+        self.log_write(WriteBatch::new().delete(key))?;
+        data.remove(key);
+        self.watchers.publish(key, None);
+        self.compact_if_due(&data);
         Ok(())
     }
 
@@ -72,7 +221,7 @@ impl Database for YamlDatabase {
         let mut result = Vec::new();
         let start_vec = start.to_vec();
         let end_vec = end.to_vec();
-        let data = self.data.lock().unwrap();
+        let data = self.lock_data();
         for (key, value) in data.iter() {
             if key >= &start_vec && key < &end_vec {
                 result.push((key.clone(), value.clone()));
@@ -85,7 +234,7 @@ impl Database for YamlDatabase {
         let mut result = Vec::new();
         let start_vec = start.to_vec();
         let end_vec = end.to_vec();
-        let mut data = self.data.lock().unwrap();
+        let mut data = self.lock_data();
 
         // Collect keys to remove and their values
         let keys_to_remove: Vec<Vec<u8>> = data
@@ -96,31 +245,224 @@ impl Database for YamlDatabase {
                 key.clone()
             })
             .collect();
+        self.log_write(&wal::removal_batch(&keys_to_remove))?;
 
         // Remove the collected keys
         for key in keys_to_remove {
             data.remove(&key);
         }
+        self.watchers.publish_removed(&result);
+        self.compact_if_due(&data);
 
         Ok(result)
     }
 
+    async fn scan_prefix(&self, prefix: &[u8]) -> Result<Vec<(Vec<u8>, Vec<u8>)>> {
+        let data = self.lock_data();
+        Ok(data
+            .range(prefix_bounds(prefix))
+            .map(|(key, value)| (key.clone(), value.clone()))
+            .collect())
+    }
+
+    async fn remove_prefix(&self, prefix: &[u8]) -> Result<Vec<(Vec<u8>, Vec<u8>)>> {
+        let mut data = self.lock_data();
+        let keys: Vec<Vec<u8>> = data
+            .range(prefix_bounds(prefix))
+            .map(|(key, _)| key.clone())
+            .collect();
+        self.log_write(&wal::removal_batch(&keys))?;
+        let removed: Vec<(Vec<u8>, Vec<u8>)> = keys
+            .into_iter()
+            .filter_map(|key| data.remove_entry(&key))
+            .collect();
+        self.watchers.publish_removed(&removed);
+        self.compact_if_due(&data);
+        Ok(removed)
+    }
+
     async fn flush(&mut self) -> Result<()> {
-        let mut map = serde_yaml::Mapping::new();
-        let data = self.data.lock().unwrap();
+        // Nothing can have changed in read-only mode
+        if self.options.read_only {
+            return Ok(());
+        }
+        self.persist(&self.lock_data())
+    }
 
-        for (key, value) in data.iter() {
-            let key_str = String::from_utf8(key.clone())?;
-            let value_str = String::from_utf8(value.clone())?;
-            map.insert(Value::String(key_str), Value::String(value_str));
+    async fn transaction<F, R>(&mut self, f: F) -> Result<R>
+    where
+        F: Fn(&mut dyn Transaction) -> Result<R> + Send,
+        R: Send,
+    {
+        let mut data = self.lock_data();
+        let read = |key: &[u8]| Ok(data.get(key).cloned());
+        let mut tx = OverlayTransaction::new(&read);
+        let result = f(&mut tx)?;
+        let writes = tx.into_writes();
+        self.log_write(&wal::writes_batch(&writes))?;
+
+        // Only touch the map once the closure has succeeded
+        let mut expiry = self.expiry.lock().unwrap();
+        for (key, value) in writes {
+            match value {
+                Some(value) => {
+                    expiry.clear(&key);
+                    self.watchers.publish(&key, Some(&value));
+                    data.insert(key, value)
+                }
+                None => {
+                    self.watchers.publish(&key, None);
+                    data.remove(&key)
+                }
+            };
         }
+        self.compact_if_due(&data);
 
-        let yaml = Value::Mapping(map);
-        let yaml_str = serde_yaml::to_string(&yaml)?;
-        fs::write(&self.path, yaml_str)?;
+        Ok(result)
+    }
+
+    async fn compare_and_swap(
+        &mut self,
+        key: &[u8],
+        expected: Option<&[u8]>,
+        new: Option<&[u8]>,
+    ) -> Result<std::result::Result<(), CompareAndSwapError>> {
+        self.transaction(|tx| compare_and_swap_in(tx, key, expected, new))
+            .await
+    }
+
+    async fn increment(&mut self, key: &[u8], delta: i64) -> Result<i64> {
+        self.transaction(|tx| increment_in(tx, key, delta)).await
+    }
+
+    fn set_merge_operator<F>(&mut self, operator: F)
+    where
+        F: Fn(&[u8], Option<&[u8]>, &[u8]) -> Vec<u8> + Send + Sync + 'static,
+    {
+        self.merge_operator = Some(Arc::new(operator));
+    }
 
+    async fn merge(&mut self, key: &[u8], operand: &[u8]) -> Result<()> {
+        let operator = self.merge_operator.clone();
+        self.transaction(|tx| merge_in(tx, operator.as_ref(), key, operand))
+            .await
+    }
+
+    async fn add_with_ttl(&mut self, key: &[u8], value: &[u8], ttl: Duration) -> Result<()> {
+        let mut data = self.lock_data();
+        self.log_write(WriteBatch::new().put(key, value))?;
+        self.expiry.lock().unwrap().set(key, deadline_after(ttl));
+        self.watchers.publish(key, Some(value));
+        data.insert(key.to_vec(), value.to_vec());
+        self.compact_if_due(&data);
         Ok(())
     }
+
+    async fn ttl(&self, key: &[u8]) -> Result<Option<Duration>> {
+        if !self.lock_data().contains_key(key) {
+            return Ok(None);
+        }
+        let deadline = self.expiry.lock().unwrap().deadline(key);
+        Ok(deadline.and_then(remaining_until))
+    }
+
+    async fn purge_expired(&self) -> Result<usize> {
+        let mut data = self.data.lock().unwrap();
+        Ok(self.evict_expired(&mut data))
+    }
+
+    async fn open_tree(&self, name: &str) -> Result<Self> {
+        check_tree_name(name)?;
+        let opened = self
+            .trees
+            .lock()
+            .unwrap()
+            .get(name)
+            .map(|tree| tree.share(&self.trees));
+        if let Some(tree) = opened {
+            return Ok(tree);
+        }
+
+        // Each tree lives in a file of its own next to this one, written when
+        // its handle is flushed
+        let path = tree_file_path(&self.path, name);
+        let tree = Self::open_with(&path.to_string_lossy(), self.options).await?;
+        let mut trees = self.trees.lock().unwrap();
+        let tree = trees.entry(name.to_string()).or_insert(tree);
+        Ok(tree.share(&self.trees))
+    }
+
+    async fn list_trees(&self) -> Result<Vec<String>> {
+        let mut names = tree_files(&self.path)?;
+        names.extend(self.trees.lock().unwrap().keys().cloned());
+        names.sort();
+        names.dedup();
+        Ok(names)
+    }
+
+    async fn drop_tree(&self, name: &str) -> Result<bool> {
+        check_tree_name(name)?;
+        if self.options.read_only {
+            bail!(DatabaseError::ReadOnly(
+                "the database was opened read-only".to_string()
+            ));
+        }
+        let opened = self.trees.lock().unwrap().remove(name).is_some();
+        let stored = remove_file(&tree_file_path(&self.path, name))?;
+        Ok(opened || stored)
+    }
+
+    async fn write_batch(&mut self, batch: WriteBatch) -> Result<()> {
+        let written = batch.put_keys();
+        let mut data = self.lock_data();
+        let changes = if self.watchers.is_watched() {
+            batch.changes_in_map(&data)?
+        } else {
+            BTreeMap::new()
+        };
+        self.log_write(&batch)?;
+        batch.apply_to_map(&mut data, |value| Ok(value.to_vec()))?;
+
+        let mut expiry = self.expiry.lock().unwrap();
+        for key in written {
+            expiry.clear(&key);
+        }
+        self.watchers.publish_all(&changes);
+        self.compact_if_due(&data);
+        Ok(())
+    }
+
+    async fn select_range_with(
+        &self,
+        start: &[u8],
+        end: &[u8],
+        options: &ScanOptions,
+    ) -> Result<Vec<(Vec<u8>, Vec<u8>)>> {
+        let data = self.lock_data();
+        Ok(select_from_map(&data, start, end, options, |value| {
+            Some(value.clone())
+        }))
+    }
+
+    fn scan(&self, start: &[u8], end: &[u8]) -> KvStream<'_> {
+        Box::pin(chunked_scan(start, end, move |from, end, limit| {
+            let data = self.lock_data();
+            let chunk = data
+                .range(from..end)
+                .take(limit)
+                .map(|(key, value)| (key.clone(), value.clone()))
+                .collect();
+            future::ready(Ok(chunk))
+        }))
+    }
+
+    async fn snapshot(&self) -> Result<Box<dyn Snapshot>> {
+        Ok(Box::new(MapSnapshot::new(self.lock_data().clone())))
+    }
+
+    fn watch_prefix(&self, prefix: &[u8]) -> WatchStream {
+        self.watchers.subscribe(prefix)
+    }
 }
 
 // SAFETY: YamlDatabase is safe to share between threads because data access is protected by Mutex
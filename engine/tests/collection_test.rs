@@ -0,0 +1,138 @@
+use serde::{Deserialize, Serialize};
+use std::ops::Bound;
+use versedb::collection::{Bincode, Collection, Flexbuffers, Json, OrderedKey};
+use versedb::database::{Database, DatabaseError};
+use versedb::memory::MemoryDatabase;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct Reading {
+    sensor: String,
+    celsius: f64,
+}
+
+fn reading(sensor: &str, celsius: f64) -> Reading {
+    Reading {
+        sensor: sensor.to_string(),
+        celsius,
+    }
+}
+
+#[test]
+fn test_ordered_key_encoding() {
+    let numbers = [i64::MIN, -300, -1, 0, 1, 255, 256, i64::MAX];
+    let encoded: Vec<Vec<u8>> = numbers.iter().map(OrderedKey::encode_key).collect();
+    assert!(encoded.windows(2).all(|pair| pair[0] < pair[1]));
+    for (number, bytes) in numbers.iter().zip(&encoded) {
+        assert_eq!(i64::decode_key(bytes).unwrap(), *number);
+    }
+
+    assert!(10u32.encode_key() < 200u32.encode_key());
+    assert!(u32::decode_key(b"abc").is_err());
+    assert_eq!(
+        String::decode_key(&"héllo".to_string().encode_key()).unwrap(),
+        "héllo"
+    );
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[tokio::test]
+async fn test_collection_insert_get_remove_range() {
+    let db = MemoryDatabase::open("").await.unwrap();
+    let mut readings: Collection<_, i64, Reading> = Collection::new(db, "readings").unwrap();
+
+    for (time, celsius) in [(-20, -4.5), (5, 12.0), (-3, 1.5), (100, 30.25)] {
+        readings
+            .insert(&time, &reading("roof", celsius))
+            .await
+            .unwrap();
+    }
+    assert_eq!(readings.get(&5).await.unwrap(), Some(reading("roof", 12.0)));
+    assert_eq!(readings.get(&6).await.unwrap(), None);
+
+    // Negative keys sort before positive ones
+    let times: Vec<i64> = readings
+        .range(..)
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|(time, _)| time)
+        .collect();
+    assert_eq!(times, vec![-20, -3, 5, 100]);
+
+    let window = readings.range(-3..=5).await.unwrap();
+    assert_eq!(
+        window,
+        vec![(-3, reading("roof", 1.5)), (5, reading("roof", 12.0))]
+    );
+    let reversed = (Bound::Included(5), Bound::Excluded(-3));
+    assert!(readings.range(reversed).await.unwrap().is_empty());
+
+    readings.remove(&-3).await.unwrap();
+    assert_eq!(readings.get(&-3).await.unwrap(), None);
+    assert_eq!(readings.range(..5).await.unwrap().len(), 1);
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[tokio::test]
+async fn test_collection_codecs_and_names() {
+    let db = MemoryDatabase::open("").await.unwrap();
+
+    let mut json: Collection<_, String, Reading, Json> = Collection::new(db, "json").unwrap();
+    json.insert(&"a".to_string(), &reading("a", 1.0))
+        .await
+        .unwrap();
+    let db = json.into_inner();
+    assert_eq!(
+        db.select(b"json\0a").await.unwrap(),
+        Some(br#"{"sensor":"a","celsius":1.0}"#.to_vec())
+    );
+
+    let mut bincode: Collection<_, String, Reading, Bincode> = Collection::new(db, "bin").unwrap();
+    bincode
+        .insert(&"a".to_string(), &reading("b", 2.0))
+        .await
+        .unwrap();
+    assert_eq!(
+        bincode.get(&"a".to_string()).await.unwrap(),
+        Some(reading("b", 2.0))
+    );
+    let db = bincode.into_inner();
+
+    let mut flex: Collection<_, String, Reading, Flexbuffers> =
+        Collection::new(db, "flex").unwrap();
+    flex.insert(&"a".to_string(), &reading("c", 3.0))
+        .await
+        .unwrap();
+    assert_eq!(
+        flex.get(&"a".to_string()).await.unwrap(),
+        Some(reading("c", 3.0))
+    );
+    assert_eq!(flex.range(..).await.unwrap().len(), 1);
+
+    // Collections with different names keep their entries apart, even when
+    // one name is a prefix of the other
+    let db = flex.into_inner();
+    let mut short: Collection<_, String, u32> = Collection::new(db, "user").unwrap();
+    short.insert(&"x".to_string(), &1).await.unwrap();
+    let mut long: Collection<_, String, u32> =
+        Collection::new(short.into_inner(), "users").unwrap();
+    long.insert(&"y".to_string(), &2).await.unwrap();
+    assert_eq!(long.range(..).await.unwrap(), vec![("y".to_string(), 2)]);
+    assert_eq!(
+        long.database()
+            .select_range(b"", b"\xff")
+            .await
+            .unwrap()
+            .len(),
+        5
+    );
+
+    // A zero byte would end the name early and let it overlap another
+    let error = Collection::<_, String, u32>::new(long.into_inner(), "user\0x")
+        .err()
+        .unwrap();
+    assert!(matches!(
+        DatabaseError::of(&error),
+        DatabaseError::InvalidArgument(_)
+    ));
+}
@@ -1,9 +1,12 @@
 #[cfg(not(target_arch = "wasm32"))]
 use std::fs;
 #[cfg(not(target_arch = "wasm32"))]
+use std::path::Path;
+#[cfg(not(target_arch = "wasm32"))]
 use tempfile::NamedTempFile;
-use versedb::csv::CsvDatabase;
-use versedb::database::Database;
+use versedb::csv::{CsvDatabase, CsvEncoding, CsvOptions};
+use versedb::database::{Database, DatabaseError, WriteBatch};
+use versedb::wal::WalOptions;
 
 #[cfg(not(target_arch = "wasm32"))]
 #[tokio::test]
@@ -139,3 +142,429 @@ async fn test_csv_database_flush() {
     db.close().await.unwrap();
     fs::remove_file(path).unwrap();
 }
+
+#[cfg(not(target_arch = "wasm32"))]
+#[tokio::test]
+async fn test_csv_database_transaction() {
+    let temp_file = NamedTempFile::new().unwrap();
+    let path = temp_file.path().to_str().unwrap();
+
+    let mut db = CsvDatabase::open(path).await.unwrap();
+    db.add(b"old", b"record").await.unwrap();
+
+    db.transaction(|tx| {
+        let value = tx.get(b"old")?.unwrap();
+        tx.delete(b"old")?;
+        tx.put(b"new", &value)?;
+        Ok(())
+    })
+    .await
+    .unwrap();
+    assert_eq!(db.select(b"old").await.unwrap(), None);
+    assert_eq!(db.select(b"new").await.unwrap(), Some(b"record".to_vec()));
+
+    // A failing closure leaves the database untouched
+    let result: versedb::database::Result<()> = db
+        .transaction(|tx| {
+            tx.delete(b"new")?;
+            tx.put(b"other", b"value")?;
+            Err(anyhow::anyhow!("abort"))
+        })
+        .await;
+    assert!(result.is_err());
+    assert_eq!(db.select(b"new").await.unwrap(), Some(b"record".to_vec()));
+    assert_eq!(db.select(b"other").await.unwrap(), None);
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[tokio::test]
+async fn test_csv_database_write_batch() {
+    let temp_file = NamedTempFile::new().unwrap();
+    let path = temp_file.path().to_str().unwrap();
+
+    let mut db = CsvDatabase::open(path).await.unwrap();
+    db.add(b"key0", b"value0").await.unwrap();
+
+    // Operations apply in order: the range delete also drops earlier puts
+    let mut batch = WriteBatch::new();
+    batch
+        .put(b"key1", b"value1")
+        .put(b"key2", b"value2")
+        .delete(b"key0")
+        .delete_range(b"key2", b"key3");
+    db.write_batch(batch).await.unwrap();
+    db.flush().await.unwrap();
+    drop(db);
+
+    let db = CsvDatabase::open(path).await.unwrap();
+    assert_eq!(
+        db.select_range(b"key0", b"key9").await.unwrap(),
+        vec![(b"key1".to_vec(), b"value1".to_vec())]
+    );
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[tokio::test]
+async fn test_csv_database_increment() {
+    let temp_file = NamedTempFile::new().unwrap();
+    let path = temp_file.path().to_str().unwrap();
+
+    let mut db = CsvDatabase::open(path).await.unwrap();
+    assert_eq!(db.increment(b"hits", 2).await.unwrap(), 2);
+    assert_eq!(db.increment(b"hits", 40).await.unwrap(), 42);
+
+    // Counters are stored as text, so they survive a round trip through the file
+    db.flush().await.unwrap();
+    drop(db);
+    let mut reopened = CsvDatabase::open(path).await.unwrap();
+    assert_eq!(reopened.increment(b"hits", 1).await.unwrap(), 43);
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[tokio::test]
+async fn test_csv_database_trees() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let path = temp_dir.path().join("data.csv");
+    let path = path.to_str().unwrap();
+
+    let mut db = CsvDatabase::open(path).await.unwrap();
+    db.add(b"key", b"root").await.unwrap();
+    let mut users = db.open_tree("users").await.unwrap();
+    users.add(b"key", b"alice").await.unwrap();
+    assert_eq!(db.select(b"key").await.unwrap(), Some(b"root".to_vec()));
+    assert_eq!(db.list_trees().await.unwrap(), vec!["users"]);
+
+    // Each tree is written to its own file next to the database
+    users.flush().await.unwrap();
+    db.flush().await.unwrap();
+    assert!(temp_dir.path().join("data.users.csv").exists());
+    drop((db, users));
+
+    let db = CsvDatabase::open(path).await.unwrap();
+    assert_eq!(db.list_trees().await.unwrap(), vec!["users"]);
+    let users = db.open_tree("users").await.unwrap();
+    assert_eq!(users.select(b"key").await.unwrap(), Some(b"alice".to_vec()));
+
+    assert!(db.drop_tree("users").await.unwrap());
+    assert!(!temp_dir.path().join("data.users.csv").exists());
+    assert!(db.list_trees().await.unwrap().is_empty());
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[tokio::test]
+async fn test_csv_database_escaping() {
+    let temp_file = NamedTempFile::new().unwrap();
+    let path = temp_file.path().to_str().unwrap();
+
+    let entries: [(&[u8], &[u8]); 5] = [
+        (b"plain", b"value"),
+        (b"comma,key", b"a, b, c"),
+        (b"quote", b"say \"hi\""),
+        (b"multi\nline", b"first\r\nsecond"),
+        (b"empty", b""),
+    ];
+    let mut db = CsvDatabase::open(path).await.unwrap();
+    for (key, value) in entries {
+        db.add(key, value).await.unwrap();
+    }
+    db.close().await.unwrap();
+
+    let contents = fs::read_to_string(path).unwrap();
+    assert!(contents.contains("\"comma,key\",\"a, b, c\"\r\n"));
+    assert!(contents.contains("quote,\"say \"\"hi\"\"\"\r\n"));
+
+    let db = CsvDatabase::open(path).await.unwrap();
+    for (key, value) in entries {
+        assert_eq!(db.select(key).await.unwrap(), Some(value.to_vec()));
+    }
+
+    // Text can't hold bytes that aren't UTF-8, so they are refused up front
+    // and the file is left alone
+    let mut db = CsvDatabase::open(path).await.unwrap();
+    let error = db.add(b"\xff", b"binary").await.unwrap_err();
+    assert!(matches!(
+        DatabaseError::of(&error),
+        DatabaseError::InvalidArgument(_)
+    ));
+    assert_eq!(db.select(b"\xff").await.unwrap(), None);
+    db.flush().await.unwrap();
+    assert_eq!(fs::read_to_string(path).unwrap(), contents);
+
+    // Files written before quoting hold raw fields: they are read the way
+    // they were written and rewritten quoted on the next flush
+    fs::write(path, "a,1\nb,2,3\nc,{\"x\": \"y\"}\nno comma\n").unwrap();
+    let mut db = CsvDatabase::open(path).await.unwrap();
+    assert_eq!(db.select(b"b").await.unwrap(), Some(b"2,3".to_vec()));
+    assert_eq!(
+        db.select(b"c").await.unwrap(),
+        Some(br#"{"x": "y"}"#.to_vec())
+    );
+    assert_eq!(db.select_range(b"", b"\xff").await.unwrap().len(), 3);
+    db.flush().await.unwrap();
+    drop(db);
+    assert_eq!(
+        fs::read_to_string(path).unwrap(),
+        "a,1\r\nb,\"2,3\"\r\nc,\"{\"\"x\"\": \"\"y\"\"}\"\r\n"
+    );
+
+    // Encoded fields have no such history, so malformed records are corrupt
+    let options = CsvOptions {
+        encoding: CsvEncoding::Hex,
+        ..CsvOptions::default()
+    };
+    fs::write(path, "61,31\n62,32,33\n").unwrap();
+    let error = CsvDatabase::open_with(path, options).await.err().unwrap();
+    assert!(matches!(
+        DatabaseError::of(&error),
+        DatabaseError::Corruption(_)
+    ));
+    fs::write(path, "61,\"unterminated\n").unwrap();
+    let error = CsvDatabase::open_with(path, options).await.err().unwrap();
+    assert!(matches!(
+        DatabaseError::of(&error),
+        DatabaseError::Corruption(_)
+    ));
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[tokio::test]
+async fn test_csv_database_encodings() {
+    let entries: [(&[u8], &[u8]); 4] = [
+        (b"\x00\xff", b"\xc3\x28"),
+        (b"k", b""),
+        (b"key,with\"quote", b"\n"),
+        (b"\xfe\xfd\xfc\xfb", b"abcd"),
+    ];
+    for encoding in [CsvEncoding::Hex, CsvEncoding::Base64] {
+        let temp_file = NamedTempFile::new().unwrap();
+        let path = temp_file.path().to_str().unwrap();
+        let options = CsvOptions {
+            header: true,
+            encoding,
+            ..CsvOptions::default()
+        };
+
+        let mut db = CsvDatabase::open_with(path, options).await.unwrap();
+        for (key, value) in entries {
+            db.add(key, value).await.unwrap();
+        }
+        db.flush().await.unwrap();
+
+        let contents = fs::read_to_string(path).unwrap();
+        assert!(contents.starts_with("key,value\r\n"));
+        match encoding {
+            CsvEncoding::Hex => assert!(contents.contains("00ff,c328\r\n")),
+            _ => assert!(contents.contains("AP8=,wyg=\r\n")),
+        }
+
+        let db = CsvDatabase::open_with(path, options).await.unwrap();
+        for (key, value) in entries {
+            assert_eq!(db.select(key).await.unwrap(), Some(value.to_vec()));
+        }
+        assert_eq!(db.select_range(b"", b"\xff\xff").await.unwrap().len(), 4);
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[tokio::test]
+async fn test_csv_database_crash_recovery() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let path = temp_dir.path().join("data.csv");
+    let backup = temp_dir.path().join("data.csv.bak");
+    let temp = temp_dir.path().join("data.csv.tmp");
+    let path = path.to_str().unwrap();
+
+    let mut db = CsvDatabase::open(path).await.unwrap();
+    db.add(b"key", b"first").await.unwrap();
+    db.flush().await.unwrap();
+    let first = fs::read(path).unwrap();
+    db.add(b"key", b"second").await.unwrap();
+    db.flush().await.unwrap();
+    let second = fs::read(path).unwrap();
+
+    // The previous generation is kept as a backup, and no temp file is left
+    assert_eq!(fs::read(&backup).unwrap(), first);
+    assert!(!temp.exists());
+
+    // A temp file next to the database may be partial, so it is dropped
+    fs::write(&temp, &second[..second.len() / 2]).unwrap();
+    let db = CsvDatabase::open(path).await.unwrap();
+    assert_eq!(db.select(b"key").await.unwrap(), Some(b"second".to_vec()));
+    assert!(!temp.exists());
+
+    // Without the database, a crash came after the temp file was complete
+    fs::rename(path, &backup).unwrap();
+    fs::write(&temp, &second).unwrap();
+
+    // Read-only handles don't hold the exclusive lock, so they read the
+    // complete generation and leave the files for the writer to recover
+    let read_only = CsvOptions {
+        read_only: true,
+        ..CsvOptions::default()
+    };
+    let db = CsvDatabase::open_with(path, read_only).await.unwrap();
+    assert_eq!(db.select(b"key").await.unwrap(), Some(b"second".to_vec()));
+    assert!(!Path::new(path).exists());
+    assert!(temp.exists());
+    drop(db);
+
+    let db = CsvDatabase::open(path).await.unwrap();
+    assert_eq!(db.select(b"key").await.unwrap(), Some(b"second".to_vec()));
+    assert_eq!(fs::read(path).unwrap(), second);
+    assert!(!temp.exists());
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[tokio::test]
+async fn test_csv_database_wal() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let path = temp_dir.path().join("data.csv");
+    let log = temp_dir.path().join("data.csv.wal");
+    let path = path.to_str().unwrap();
+    let options = CsvOptions {
+        encoding: CsvEncoding::Hex,
+        wal: Some(WalOptions { compact_after: 5 }),
+        ..CsvOptions::default()
+    };
+
+    let mut db = CsvDatabase::open_with(path, options).await.unwrap();
+    db.add(b"user:\xff", b"\x00").await.unwrap();
+    let mut batch = WriteBatch::new();
+    batch.put(b"user:1", b"ann").put(b"user:2", b"bob");
+    db.write_batch(batch).await.unwrap();
+    db.remove_prefix(b"user:2").await.unwrap();
+    db.increment(b"visits", 3).await.unwrap();
+    let mut tree = db.open_tree("orders").await.unwrap();
+    tree.add(b"order:1", b"pending").await.unwrap();
+    drop((db, tree));
+    assert!(!Path::new(path).exists());
+
+    // Everything written since the last flush comes back from the logs,
+    // trees included
+    let mut db = CsvDatabase::open_with(path, options).await.unwrap();
+    assert_eq!(
+        db.select_range(b"user:", b"user;").await.unwrap(),
+        vec![
+            (b"user:1".to_vec(), b"ann".to_vec()),
+            (b"user:\xff".to_vec(), b"\x00".to_vec())
+        ]
+    );
+    assert_eq!(db.select(b"visits").await.unwrap(), Some(b"3".to_vec()));
+    let tree = db.open_tree("orders").await.unwrap();
+    assert_eq!(
+        tree.select(b"order:1").await.unwrap(),
+        Some(b"pending".to_vec())
+    );
+
+    // The fifth logged write compacts the log into the file
+    assert!(fs::metadata(&log).unwrap().len() > 0);
+    db.remove(b"user:1").await.unwrap();
+    assert_eq!(fs::metadata(&log).unwrap().len(), 0);
+    assert_eq!(
+        fs::read_to_string(path).unwrap(),
+        "757365723aff,00\r\n766973697473,33\r\n"
+    );
+
+    // A clone writes through the same data and log, so flushing it keeps
+    // what the original logs afterwards
+    let mut clone = db.clone();
+    clone.add(b"user:3", b"cy").await.unwrap();
+    assert_eq!(db.select(b"user:3").await.unwrap(), Some(b"cy".to_vec()));
+    clone.flush().await.unwrap();
+    db.add(b"user:4", b"di").await.unwrap();
+    drop((db, clone, tree));
+    let db = CsvDatabase::open_with(path, options).await.unwrap();
+    assert_eq!(db.select(b"user:3").await.unwrap(), Some(b"cy".to_vec()));
+    assert_eq!(db.select(b"user:4").await.unwrap(), Some(b"di".to_vec()));
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[tokio::test]
+async fn test_csv_database_locking() {
+    use std::io::Write;
+
+    let temp_dir = tempfile::tempdir().unwrap();
+    let path = temp_dir.path().join("data.csv");
+    let lock = temp_dir.path().join("data.csv.lock");
+    let path = path.to_str().unwrap();
+    // A lock file opened separately stands in for another process
+    let other_process = || {
+        fs::OpenOptions::new()
+            .read(true)
+            .write(true)
+            .open(&lock)
+            .unwrap()
+    };
+
+    // A writer locks the file exclusively and leaves its PID in it, and its
+    // clones share the lock
+    let db = CsvDatabase::open(path).await.unwrap();
+    assert_eq!(
+        fs::read_to_string(&lock).unwrap(),
+        std::process::id().to_string()
+    );
+    let mut clone = db.clone();
+    clone.add(b"key", b"value").await.unwrap();
+    clone.flush().await.unwrap();
+    assert!(other_process().try_lock_shared().is_err());
+
+    // Opening it for writing again in this process takes the lock over, so
+    // the earlier handles can't write over the new one
+    let mut again = CsvDatabase::open(path).await.unwrap();
+    let error = clone.add(b"key", b"stale").await.unwrap_err();
+    assert!(matches!(
+        DatabaseError::of(&error),
+        DatabaseError::Conflict(_)
+    ));
+    assert!(clone.flush().await.is_err());
+    assert_eq!(again.select(b"key").await.unwrap(), Some(b"value".to_vec()));
+
+    // Closing the writer gives the lock up and clears its PID
+    again.close().await.unwrap();
+    assert_eq!(fs::read_to_string(&lock).unwrap(), "");
+    assert!(other_process().try_lock().is_ok());
+    drop((db, clone, again));
+
+    // Held by another writer, the file can't be opened at all
+    let mut holder = other_process();
+    holder.try_lock().unwrap();
+    write!(holder, "4242").unwrap();
+    let error = CsvDatabase::open(path).await.err().unwrap();
+    assert!(error.to_string().contains("is locked by pid 4242"));
+    assert!(matches!(
+        DatabaseError::of(&error),
+        DatabaseError::Conflict(_)
+    ));
+    let read_only = CsvOptions {
+        read_only: true,
+        ..CsvOptions::default()
+    };
+    assert!(CsvDatabase::open_with(path, read_only).await.is_err());
+    // Writers clear their PID when they let go; readers leave the file alone
+    holder.set_len(0).unwrap();
+    drop(holder);
+
+    // Read-only handles share the lock, even across processes, and refuse writes
+    let mut reader = CsvDatabase::open_with(path, read_only).await.unwrap();
+    let other_reader = other_process();
+    other_reader.try_lock_shared().unwrap();
+    assert_eq!(
+        reader.select(b"key").await.unwrap(),
+        Some(b"value".to_vec())
+    );
+    let error = reader.add(b"key", b"changed").await.unwrap_err();
+    assert!(matches!(
+        DatabaseError::of(&error),
+        DatabaseError::ReadOnly(_)
+    ));
+    assert!(reader.remove_range(b"a", b"z").await.is_err());
+    assert!(reader.drop_tree("orders").await.is_err());
+    reader.remove(b"missing").await.unwrap();
+    let error = CsvDatabase::open(path).await.err().unwrap();
+    assert!(error.to_string().contains("read-only in this process"));
+    reader.close().await.unwrap();
+    let error = CsvDatabase::open(path).await.err().unwrap();
+    assert!(error.to_string().contains("read-only in another process"));
+    drop(other_reader);
+    CsvDatabase::open(path).await.unwrap();
+}
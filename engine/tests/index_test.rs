@@ -0,0 +1,190 @@
+#[cfg(not(target_arch = "wasm32"))]
+use tempfile::tempdir;
+use versedb::database::Database;
+use versedb::index::{IndexedDatabase, json_field};
+use versedb::memory::MemoryDatabase;
+#[cfg(not(target_arch = "wasm32"))]
+use versedb::sled::SledDatabase;
+use versedb::tuple::Element;
+
+fn user(email: &str, age: i64, tags: &[&str]) -> Vec<u8> {
+    serde_json::json!({ "email": email, "age": age, "tags": tags })
+        .to_string()
+        .into_bytes()
+}
+
+fn keys(records: Vec<(Vec<u8>, Vec<u8>)>) -> Vec<Vec<u8>> {
+    records.into_iter().map(|(key, _)| key).collect()
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[tokio::test]
+async fn test_index_find_and_range() {
+    let db = MemoryDatabase::open("").await.unwrap();
+    let mut indexed = IndexedDatabase::new(db);
+    indexed.register_index("email", json_field("/email"));
+    indexed.register_index("age", json_field("/age"));
+    indexed.register_index("tags", json_field("/tags"));
+
+    indexed
+        .add(b"user:1", &user("ann@example.com", 31, &["admin", "dev"]))
+        .await
+        .unwrap();
+    indexed
+        .add(b"user:2", &user("bob@example.com", 25, &["dev"]))
+        .await
+        .unwrap();
+    indexed
+        .add(b"user:3", &user("cy@example.com", -4, &[]))
+        .await
+        .unwrap();
+    indexed.add(b"note", b"not json").await.unwrap();
+
+    let found = indexed
+        .find_by_index("email", "bob@example.com")
+        .await
+        .unwrap();
+    assert_eq!(
+        found,
+        vec![(b"user:2".to_vec(), user("bob@example.com", 25, &["dev"]))]
+    );
+    assert_eq!(
+        keys(indexed.find_by_index("tags", "dev").await.unwrap()),
+        vec![b"user:1".to_vec(), b"user:2".to_vec()]
+    );
+
+    // Ranges follow the order of the values, not of their text
+    let ages = indexed
+        .range_by_index("age", Element::Int(-10)..=Element::Int(25))
+        .await
+        .unwrap();
+    assert_eq!(keys(ages), vec![b"user:3".to_vec(), b"user:2".to_vec()]);
+    let ages = indexed
+        .range_by_index("age", Element::Int(25)..)
+        .await
+        .unwrap();
+    assert_eq!(keys(ages), vec![b"user:2".to_vec(), b"user:1".to_vec()]);
+
+    // Overwriting a record moves its entries, removing it drops them
+    indexed
+        .add(b"user:2", &user("bob@example.org", 26, &[]))
+        .await
+        .unwrap();
+    assert!(
+        indexed
+            .find_by_index("email", "bob@example.com")
+            .await
+            .unwrap()
+            .is_empty()
+    );
+    assert_eq!(
+        keys(
+            indexed
+                .find_by_index("email", "bob@example.org")
+                .await
+                .unwrap()
+        ),
+        vec![b"user:2".to_vec()]
+    );
+    indexed.remove(b"user:1").await.unwrap();
+    assert!(
+        indexed
+            .find_by_index("tags", "admin")
+            .await
+            .unwrap()
+            .is_empty()
+    );
+
+    assert!(indexed.find_by_index("missing", "x").await.is_err());
+    assert!(indexed.add(&[0xff, 1], b"reserved").await.is_err());
+
+    // Records written before the index existed are picked up by a rebuild
+    indexed.register_index("domain", |_, value| {
+        let value = String::from_utf8_lossy(value);
+        match value.split_once("@example.") {
+            Some((_, rest)) => vec![Element::from(&rest[..3])],
+            None => Vec::new(),
+        }
+    });
+    assert!(
+        indexed
+            .find_by_index("domain", "org")
+            .await
+            .unwrap()
+            .is_empty()
+    );
+    assert_eq!(indexed.rebuild_index("domain").await.unwrap(), 2);
+    assert_eq!(
+        keys(indexed.find_by_index("domain", "org").await.unwrap()),
+        vec![b"user:2".to_vec()]
+    );
+    assert_eq!(indexed.rebuild_index("domain").await.unwrap(), 2);
+    assert_eq!(
+        keys(indexed.range_by_index("domain", ..).await.unwrap()),
+        vec![b"user:3".to_vec(), b"user:2".to_vec()]
+    );
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[tokio::test]
+async fn test_sled_index() {
+    let temp_dir = tempdir().unwrap();
+    let path = temp_dir.path().to_str().unwrap();
+
+    let db = SledDatabase::open(path).await.unwrap();
+    let mut indexed = IndexedDatabase::new(db);
+    indexed.register_index("email", json_field("/email"));
+    indexed
+        .add(b"user:1", &user("ann@example.com", 31, &[]))
+        .await
+        .unwrap();
+    indexed
+        .add(b"user:1", &user("ann@example.net", 31, &[]))
+        .await
+        .unwrap();
+
+    assert!(
+        indexed
+            .find_by_index("email", "ann@example.com")
+            .await
+            .unwrap()
+            .is_empty()
+    );
+    assert_eq!(
+        keys(
+            indexed
+                .find_by_index("email", "ann@example.net")
+                .await
+                .unwrap()
+        ),
+        vec![b"user:1".to_vec()]
+    );
+    assert_eq!(
+        indexed.select(b"user:1").await.unwrap(),
+        Some(user("ann@example.net", 31, &[]))
+    );
+
+    // A record rewritten around the indexes no longer matches its old entry
+    let mut records = indexed.database().clone();
+    records
+        .add(b"user:1", &user("ann@example.org", 31, &[]))
+        .await
+        .unwrap();
+    assert!(
+        indexed
+            .find_by_index("email", "ann@example.net")
+            .await
+            .unwrap()
+            .is_empty()
+    );
+
+    indexed.remove(b"user:1").await.unwrap();
+    assert!(
+        indexed
+            .find_by_index("email", "ann@example.net")
+            .await
+            .unwrap()
+            .is_empty()
+    );
+    assert_eq!(indexed.rebuild_index("email").await.unwrap(), 0);
+}
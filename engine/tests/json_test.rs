@@ -2,8 +2,9 @@
 use std::fs;
 #[cfg(not(target_arch = "wasm32"))]
 use tempfile::NamedTempFile;
-use versedb::database::Database;
-use versedb::json::JsonDatabase;
+use versedb::database::{Database, DatabaseError, WriteBatch};
+use versedb::json::{JsonDatabase, JsonOptions};
+use versedb::wal::WalOptions;
 
 #[cfg(not(target_arch = "wasm32"))]
 #[tokio::test]
@@ -160,3 +161,373 @@ async fn test_json_database_flush() {
     // Clean up
     db.close().await.unwrap();
 }
+
+#[cfg(not(target_arch = "wasm32"))]
+#[tokio::test]
+async fn test_json_database_transaction() {
+    let temp_file = NamedTempFile::new().unwrap();
+    let path = temp_file.path().to_str().unwrap();
+
+    let mut db = JsonDatabase::open(path).await.unwrap();
+    db.add(b"old", b"record").await.unwrap();
+
+    db.transaction(|tx| {
+        let value = tx.get(b"old")?.unwrap();
+        tx.delete(b"old")?;
+        tx.put(b"new", &value)?;
+        Ok(())
+    })
+    .await
+    .unwrap();
+    assert_eq!(db.select(b"old").await.unwrap(), None);
+    assert_eq!(db.select(b"new").await.unwrap(), Some(b"record".to_vec()));
+
+    // A non-UTF-8 value rejects the whole transaction, not just that write
+    let result = db
+        .transaction(|tx| {
+            tx.delete(b"new")?;
+            tx.put(b"binary", &[0xff, 0xfe])
+        })
+        .await;
+    assert!(result.is_err());
+    assert_eq!(db.select(b"new").await.unwrap(), Some(b"record".to_vec()));
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[tokio::test]
+async fn test_json_database_write_batch() {
+    let temp_file = NamedTempFile::new().unwrap();
+    let path = temp_file.path().to_str().unwrap();
+
+    let mut db = JsonDatabase::open(path).await.unwrap();
+    db.add(b"key0", b"value0").await.unwrap();
+
+    let mut batch = WriteBatch::new();
+    batch.put(b"key1", b"value1").delete(b"key0");
+    db.write_batch(batch).await.unwrap();
+    assert_eq!(db.select(b"key0").await.unwrap(), None);
+    assert_eq!(db.select(b"key1").await.unwrap(), Some(b"value1".to_vec()));
+
+    // A non-UTF-8 value rejects the whole batch
+    let mut batch = WriteBatch::new();
+    batch.delete(b"key1").put(b"binary", &[0xff, 0xfe]);
+    assert!(db.write_batch(batch).await.is_err());
+    assert_eq!(db.select(b"key1").await.unwrap(), Some(b"value1".to_vec()));
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[tokio::test]
+async fn test_json_database_increment() {
+    let temp_file = NamedTempFile::new().unwrap();
+    let path = temp_file.path().to_str().unwrap();
+
+    let mut db = JsonDatabase::open(path).await.unwrap();
+    assert_eq!(db.increment(b"hits", 2).await.unwrap(), 2);
+    assert_eq!(db.increment(b"hits", 40).await.unwrap(), 42);
+
+    // Counters are stored as text, so they survive a round trip through the file
+    db.flush().await.unwrap();
+    let mut reopened = JsonDatabase::open(path).await.unwrap();
+    assert_eq!(reopened.increment(b"hits", 1).await.unwrap(), 43);
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[tokio::test]
+async fn test_json_database_trees() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let path = temp_dir.path().join("data.json");
+    let path = path.to_str().unwrap();
+
+    let mut db = JsonDatabase::open(path).await.unwrap();
+    db.add(b"key", b"root").await.unwrap();
+    let mut users = db.open_tree("users").await.unwrap();
+    users.add(b"key", b"alice").await.unwrap();
+    assert_eq!(db.select(b"key").await.unwrap(), Some(b"root".to_vec()));
+    assert_eq!(db.list_trees().await.unwrap(), vec!["users"]);
+
+    // Each tree is written to its own file next to the database
+    users.flush().await.unwrap();
+    db.flush().await.unwrap();
+    assert!(temp_dir.path().join("data.users.json").exists());
+
+    let db = JsonDatabase::open(path).await.unwrap();
+    assert_eq!(db.list_trees().await.unwrap(), vec!["users"]);
+    let users = db.open_tree("users").await.unwrap();
+    assert_eq!(users.select(b"key").await.unwrap(), Some(b"alice".to_vec()));
+
+    assert!(db.drop_tree("users").await.unwrap());
+    assert!(!temp_dir.path().join("data.users.json").exists());
+    assert!(db.list_trees().await.unwrap().is_empty());
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[tokio::test]
+async fn test_json_database_documents() {
+    use serde_json::json;
+    use versedb::document::{Comparison, Filter};
+
+    let temp_file = NamedTempFile::new().unwrap();
+    let path = temp_file.path().to_str().unwrap();
+
+    let mut db = JsonDatabase::open_documents(path).await.unwrap();
+    let ann = json!({ "name": "Ann", "age": 31, "address": { "city": "Oslo" } });
+    db.add(b"user:1", ann.to_string().as_bytes()).await.unwrap();
+    db.add(
+        b"user:2",
+        br#"{"name": "Bob", "age": 25, "address": {"city": "Rome"}}"#,
+    )
+    .await
+    .unwrap();
+    db.add(b"user:3", br#"{"name": "Cy", "age": 47.5}"#)
+        .await
+        .unwrap();
+    assert!(db.add(b"user:4", b"not json").await.is_err());
+
+    // Compare-and-swap matches documents, whatever their formatting
+    db.compare_and_swap(
+        b"user:3",
+        Some(br#"{ "age": 47.5, "name": "Cy" }"#),
+        Some(br#"{"name": "Cy", "age": 48}"#),
+    )
+    .await
+    .unwrap()
+    .unwrap();
+    let conflict = db
+        .compare_and_swap(b"user:3", Some(br#"{"name": "Cy", "age": 47.5}"#), None)
+        .await
+        .unwrap()
+        .unwrap_err();
+    assert_eq!(
+        serde_json::from_slice::<serde_json::Value>(&conflict.current.unwrap()).unwrap(),
+        json!({ "name": "Cy", "age": 48 })
+    );
+    db.add(b"user:3", br#"{"name": "Cy", "age": 47.5}"#)
+        .await
+        .unwrap();
+
+    let stored = db.select(b"user:1").await.unwrap().unwrap();
+    assert_eq!(
+        serde_json::from_slice::<serde_json::Value>(&stored).unwrap(),
+        ann
+    );
+    assert_eq!(
+        db.get_field(b"user:1", "/address/city").await.unwrap(),
+        Some(json!("Oslo"))
+    );
+    assert_eq!(db.get_field(b"user:1", "/missing").await.unwrap(), None);
+    assert_eq!(db.get_field(b"user:9", "/name").await.unwrap(), None);
+
+    // Merge patches update members in place and remove those set to null
+    let patched = db
+        .patch(
+            b"user:2",
+            &json!({ "age": 26, "address": { "city": null, "zip": "00100" } }),
+        )
+        .await
+        .unwrap();
+    assert_eq!(
+        patched,
+        json!({ "name": "Bob", "age": 26, "address": { "zip": "00100" } })
+    );
+    db.patch(b"user:5", &json!({ "name": "Di", "age": null }))
+        .await
+        .unwrap();
+    assert_eq!(
+        db.get_field(b"user:5", "").await.unwrap(),
+        Some(json!({ "name": "Di" }))
+    );
+
+    let names = |documents: Vec<(Vec<u8>, serde_json::Value)>| -> Vec<String> {
+        documents
+            .into_iter()
+            .map(|(_, document)| document["name"].as_str().unwrap().to_string())
+            .collect()
+    };
+    let adults: Filter = "age >= 26".parse().unwrap();
+    assert_eq!(
+        names(
+            db.filter_range(b"user:", b"user;", std::slice::from_ref(&adults))
+                .await
+                .unwrap()
+        ),
+        vec!["Ann", "Bob", "Cy"]
+    );
+    let not_oslo = Filter::new("/address/city", Comparison::Ne, "Oslo");
+    assert_eq!(
+        names(
+            db.filter_range(b"user:", b"user;", &[adults, not_oslo])
+                .await
+                .unwrap()
+        ),
+        Vec::<String>::new()
+    );
+    let named: Filter = r#"name == "Cy""#.parse().unwrap();
+    assert_eq!(
+        names(db.filter_range(b"user:", b"user;", &[named]).await.unwrap()),
+        vec!["Cy"]
+    );
+    let city: Filter = "address.city == Oslo".parse().unwrap();
+    assert_eq!(city, Filter::new("/address/city", Comparison::Eq, "Oslo"));
+    assert!("age".parse::<Filter>().is_err());
+
+    // Documents are written to the file nested, not as strings
+    db.flush().await.unwrap();
+    let contents = fs::read_to_string(path).unwrap();
+    let file: serde_json::Value = serde_json::from_str(&contents).unwrap();
+    assert_eq!(file["user:1"]["address"]["city"], "Oslo");
+    let reopened = JsonDatabase::open_documents(path).await.unwrap();
+    assert_eq!(
+        reopened.get_field(b"user:3", "/age").await.unwrap(),
+        Some(json!(47.5))
+    );
+
+    let plain = JsonDatabase::open(path).await.unwrap();
+    assert!(plain.get_field(b"user:1", "/name").await.is_err());
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[tokio::test]
+async fn test_json_database_crash_recovery() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let path = temp_dir.path().join("data.json");
+    let backup = temp_dir.path().join("data.json.bak");
+    let temp = temp_dir.path().join("data.json.tmp");
+    let path = path.to_str().unwrap();
+
+    let mut db = JsonDatabase::open(path).await.unwrap();
+    db.add(b"key", b"first").await.unwrap();
+    db.flush().await.unwrap();
+    let first = fs::read(path).unwrap();
+    db.add(b"key", b"second").await.unwrap();
+    db.flush().await.unwrap();
+    let second = fs::read(path).unwrap();
+
+    // The previous generation is kept as a backup, and no temp file is left
+    assert_eq!(fs::read(&backup).unwrap(), first);
+    assert!(!temp.exists());
+
+    // A temp file next to the database may be partial, so it is dropped
+    fs::write(&temp, &second[..second.len() / 2]).unwrap();
+    let db = JsonDatabase::open(path).await.unwrap();
+    assert_eq!(db.select(b"key").await.unwrap(), Some(b"second".to_vec()));
+    assert!(!temp.exists());
+
+    // Without the database, a crash came after the temp file was complete
+    fs::rename(path, &backup).unwrap();
+    fs::write(&temp, &second).unwrap();
+    let db = JsonDatabase::open(path).await.unwrap();
+    assert_eq!(db.select(b"key").await.unwrap(), Some(b"second".to_vec()));
+    assert_eq!(fs::read(path).unwrap(), second);
+    assert!(!temp.exists());
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[tokio::test]
+async fn test_json_database_wal() {
+    use serde_json::json;
+
+    let temp_dir = tempfile::tempdir().unwrap();
+    let path = temp_dir.path().join("data.json");
+    let log = temp_dir.path().join("data.json.wal");
+    let path = path.to_str().unwrap();
+    let options = JsonOptions {
+        documents: true,
+        wal: Some(WalOptions::default()),
+        ..JsonOptions::default()
+    };
+
+    let mut db = JsonDatabase::open_with(path, options).await.unwrap();
+    db.add(b"user:1", br#"{"name": "Ann", "age": 31}"#)
+        .await
+        .unwrap();
+    db.add(b"user:2", br#"{"name": "Bob"}"#).await.unwrap();
+    db.patch(b"user:1", &json!({ "age": 32 })).await.unwrap();
+    db.compare_and_swap(b"user:2", Some(br#"{"name":"Bob"}"#), None)
+        .await
+        .unwrap()
+        .unwrap();
+    // Invalid writes fail before they are logged
+    assert!(db.add(b"user:3", b"not json").await.is_err());
+    drop(db);
+
+    let db = JsonDatabase::open_with(path, options).await.unwrap();
+    assert_eq!(
+        db.get_field(b"user:1", "/age").await.unwrap(),
+        Some(json!(32))
+    );
+    assert_eq!(db.select(b"user:2").await.unwrap(), None);
+    assert_eq!(db.select(b"user:3").await.unwrap(), None);
+
+    // Opened without WAL mode the log is still replayed, then folded into
+    // the file by the next flush
+    let mut db = JsonDatabase::open_documents(path).await.unwrap();
+    assert_eq!(
+        db.get_field(b"user:1", "/name").await.unwrap(),
+        Some(json!("Ann"))
+    );
+    db.flush().await.unwrap();
+    assert!(!log.exists());
+    let db = JsonDatabase::open_documents(path).await.unwrap();
+    assert_eq!(
+        db.get_field(b"user:1", "/age").await.unwrap(),
+        Some(json!(32))
+    );
+
+    // A clone writes through the same data and log, so flushing it keeps
+    // what the original logs afterwards
+    drop(db);
+    let mut db = JsonDatabase::open_with(path, options).await.unwrap();
+    let mut clone = db.clone();
+    clone.add(b"user:4", br#"{"name": "Cy"}"#).await.unwrap();
+    assert!(db.select(b"user:4").await.unwrap().is_some());
+    clone.flush().await.unwrap();
+    db.add(b"user:5", br#"{"name": "Di"}"#).await.unwrap();
+    drop((db, clone));
+    let db = JsonDatabase::open_with(path, options).await.unwrap();
+    assert!(db.select(b"user:4").await.unwrap().is_some());
+    assert!(db.select(b"user:5").await.unwrap().is_some());
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[tokio::test]
+async fn test_json_database_locking() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let path = temp_dir.path().join("data.json");
+    let path = path.to_str().unwrap();
+    let read_only = JsonOptions {
+        read_only: true,
+        ..JsonOptions::default()
+    };
+
+    let mut db = JsonDatabase::open(path).await.unwrap();
+    db.add(b"key", b"value").await.unwrap();
+    db.flush().await.unwrap();
+    // Another process sees the lock held by this one,
+    let lock = fs::File::open(temp_dir.path().join("data.json.lock")).unwrap();
+    assert!(lock.try_lock_shared().is_err());
+    // while a read-only handle from this one shares it. Opening it for
+    // writing again takes the lock over, so the earlier handle can't write.
+    let reader = JsonDatabase::open_with(path, read_only).await.unwrap();
+    let mut again = JsonDatabase::open(path).await.unwrap();
+    let error = db.add(b"key", b"stale").await.unwrap_err();
+    assert!(matches!(
+        DatabaseError::of(&error),
+        DatabaseError::Conflict(_)
+    ));
+    assert_eq!(again.select(b"key").await.unwrap(), Some(b"value".to_vec()));
+    // Closing the writer gives the lock up
+    again.close().await.unwrap();
+    assert!(lock.try_lock_shared().is_ok());
+    drop((db, reader, again));
+
+    let mut reader = JsonDatabase::open_with(path, read_only).await.unwrap();
+    assert_eq!(
+        reader.select(b"key").await.unwrap(),
+        Some(b"value".to_vec())
+    );
+    assert!(reader.add(b"key", b"changed").await.is_err());
+    reader.close().await.unwrap();
+    drop(reader);
+    let error = JsonDatabase::open(path).await.err().unwrap();
+    assert!(error.to_string().contains("read-only in another process"));
+}
@@ -1,87 +1,19 @@
+mod suite;
+
 use versedb::database::Database;
 use versedb::memory::MemoryDatabase;
+use versedb::scan::prefix_successor;
 
 #[cfg(not(target_arch = "wasm32"))]
 #[tokio::test]
 async fn test_memory_database() {
-    // Create a new memory database
-    let mut db = MemoryDatabase::open("").await.unwrap();
-
-    // Test add and select
-    db.add("key1".as_bytes(), "value1".as_bytes())
-        .await
-        .unwrap();
-    db.add("key2".as_bytes(), "value2".as_bytes())
-        .await
-        .unwrap();
-    db.add("key3".as_bytes(), "value3".as_bytes())
-        .await
-        .unwrap();
-
-    // Test select
-    assert_eq!(
-        db.select("key1".as_bytes()).await.unwrap(),
-        Some("value1".as_bytes().to_vec())
-    );
-    assert_eq!(
-        db.select("key2".as_bytes()).await.unwrap(),
-        Some("value2".as_bytes().to_vec())
-    );
-    assert_eq!(db.select("nonexistent".as_bytes()).await.unwrap(), None);
-
-    // Test select_range (inclusive of key1, exclusive of key3)
-    let range = db
-        .select_range("key1".as_bytes(), "key3".as_bytes())
-        .await
-        .unwrap();
-    assert_eq!(range.len(), 2);
-    assert!(range.contains(&("key1".as_bytes().to_vec(), "value1".as_bytes().to_vec())));
-    assert!(range.contains(&("key2".as_bytes().to_vec(), "value2".as_bytes().to_vec())));
-
-    // Test remove
-    db.remove("key1".as_bytes()).await.unwrap();
-    assert_eq!(db.select("key1".as_bytes()).await.unwrap(), None);
+    suite::basic(MemoryDatabase::open("").await.unwrap()).await;
 }
 
 #[cfg(not(target_arch = "wasm32"))]
 #[tokio::test]
 async fn test_memory_database_remove_range() {
-    // Create a new memory database
-    let mut db = MemoryDatabase::open("").await.unwrap();
-
-    // Add entries with ordered keys
-    let entries = vec![
-        ("key1", "value1"),
-        ("key2", "value2"),
-        ("key3", "value3"),
-        ("key4", "value4"),
-        ("key5", "value5"),
-    ];
-
-    for (key, value) in &entries {
-        db.add(key.as_bytes(), value.as_bytes()).await.unwrap();
-    }
-
-    // Test remove_range from key2 to key4 (inclusive of key2, exclusive of key4)
-    let removed = db
-        .remove_range("key2".as_bytes(), "key4".as_bytes())
-        .await
-        .unwrap();
-
-    // Verify the removed entries
-    assert_eq!(removed.len(), 2);
-    assert!(removed.contains(&("key2".as_bytes().to_vec(), "value2".as_bytes().to_vec())));
-    assert!(removed.contains(&("key3".as_bytes().to_vec(), "value3".as_bytes().to_vec())));
-
-    // Verify the remaining entries
-    let remaining = db
-        .select_range("key1".as_bytes(), "key6".as_bytes())
-        .await
-        .unwrap();
-    assert_eq!(remaining.len(), 3);
-    assert!(remaining.contains(&("key1".as_bytes().to_vec(), "value1".as_bytes().to_vec())));
-    assert!(remaining.contains(&("key4".as_bytes().to_vec(), "value4".as_bytes().to_vec())));
-    assert!(remaining.contains(&("key5".as_bytes().to_vec(), "value5".as_bytes().to_vec())));
+    suite::remove_range(MemoryDatabase::open("").await.unwrap()).await;
 }
 
 #[cfg(not(target_arch = "wasm32"))]
@@ -118,3 +50,100 @@ async fn test_memory_database_clone() {
         Some("value1".as_bytes().to_vec())
     );
 }
+
+#[cfg(not(target_arch = "wasm32"))]
+#[tokio::test]
+async fn test_memory_database_transaction() {
+    let mut db = MemoryDatabase::open("").await.unwrap();
+    db.add(b"old", b"record").await.unwrap();
+
+    // Move the record to a new key atomically
+    db.transaction(|tx| {
+        let value = tx.get(b"old")?.unwrap();
+        tx.delete(b"old")?;
+        tx.put(b"new", &value)?;
+        // Reads inside the transaction see its own writes
+        assert_eq!(tx.get(b"old")?, None);
+        Ok(())
+    })
+    .await
+    .unwrap();
+    assert_eq!(db.select(b"old").await.unwrap(), None);
+    assert_eq!(db.select(b"new").await.unwrap(), Some(b"record".to_vec()));
+
+    // A failing closure leaves the database untouched
+    let result: versedb::database::Result<()> = db
+        .transaction(|tx| {
+            tx.delete(b"new")?;
+            tx.put(b"other", b"value")?;
+            Err(anyhow::anyhow!("abort"))
+        })
+        .await;
+    assert!(result.is_err());
+    assert_eq!(db.select(b"new").await.unwrap(), Some(b"record".to_vec()));
+    assert_eq!(db.select(b"other").await.unwrap(), None);
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[tokio::test]
+async fn test_memory_database_write_batch() {
+    suite::write_batch(MemoryDatabase::open("").await.unwrap()).await;
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[tokio::test]
+async fn test_memory_database_scan() {
+    suite::scan(MemoryDatabase::open("").await.unwrap()).await;
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[tokio::test]
+async fn test_memory_database_select_range_with() {
+    suite::select_range_with(MemoryDatabase::open("").await.unwrap()).await;
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[tokio::test]
+async fn test_memory_database_scan_prefix() {
+    suite::scan_prefix(MemoryDatabase::open("").await.unwrap()).await;
+
+    // The whole keyspace for an empty or all-0xff prefix
+    assert_eq!(prefix_successor(b"ab\xff"), Some(b"ac".to_vec()));
+    assert_eq!(prefix_successor(b"\xff\xff"), None);
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[tokio::test]
+async fn test_memory_database_compare_and_swap() {
+    suite::compare_and_swap(MemoryDatabase::open("").await.unwrap()).await;
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[tokio::test]
+async fn test_memory_database_increment_and_merge() {
+    suite::increment_and_merge(MemoryDatabase::open("").await.unwrap()).await;
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[tokio::test]
+async fn test_memory_database_ttl() {
+    suite::ttl(MemoryDatabase::open("").await.unwrap()).await;
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[tokio::test]
+async fn test_memory_database_watch_prefix() {
+    suite::watch_prefix(MemoryDatabase::open("").await.unwrap()).await;
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[tokio::test]
+async fn test_memory_database_trees() {
+    suite::trees(MemoryDatabase::open("").await.unwrap()).await;
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[tokio::test]
+async fn test_memory_database_snapshot() {
+    suite::snapshot(MemoryDatabase::open("").await.unwrap()).await;
+}
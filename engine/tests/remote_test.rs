@@ -0,0 +1,133 @@
+mod suite;
+
+#[cfg(not(target_arch = "wasm32"))]
+use versedb::database::{Database, DatabaseError};
+#[cfg(not(target_arch = "wasm32"))]
+use versedb::memory::MemoryDatabase;
+#[cfg(not(target_arch = "wasm32"))]
+use versedb::remote::RemoteDatabase;
+#[cfg(not(target_arch = "wasm32"))]
+use versedb::server::serve;
+
+/// Serve a fresh in-memory database from a runtime of its own on a free
+/// local port, and connect to it
+#[cfg(not(target_arch = "wasm32"))]
+async fn open_remote() -> RemoteDatabase {
+    let db = MemoryDatabase::open("remote").await.unwrap();
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let addr = listener.local_addr().unwrap().to_string();
+    listener.set_nonblocking(true).unwrap();
+    std::thread::spawn(move || {
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+        runtime.block_on(async {
+            let listener = tokio::net::TcpListener::from_std(listener).unwrap();
+            serve(listener, db).await.unwrap();
+        });
+    });
+    RemoteDatabase::open(&addr).await.unwrap()
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[tokio::test]
+async fn test_remote_database() {
+    suite::basic(open_remote().await).await;
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[tokio::test]
+async fn test_remote_database_remove_range() {
+    suite::remove_range(open_remote().await).await;
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[tokio::test]
+async fn test_remote_database_clone() {
+    let mut db = open_remote().await;
+    db.add(b"key1", b"value1").await.unwrap();
+
+    // Clones share the connection and see the same server
+    let other = db.clone();
+    db.remove(b"key1").await.unwrap();
+    assert_eq!(other.select(b"key1").await.unwrap(), None);
+    db.close().await.unwrap();
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[tokio::test]
+async fn test_remote_database_transaction() {
+    let mut db = open_remote().await;
+    let error = db.transaction(|tx| tx.put(b"a", b"1")).await.unwrap_err();
+    assert!(matches!(
+        DatabaseError::of(&error),
+        DatabaseError::Unsupported(_)
+    ));
+    assert_eq!(db.select(b"a").await.unwrap(), None);
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[tokio::test]
+async fn test_remote_database_write_batch() {
+    suite::write_batch(open_remote().await).await;
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[tokio::test]
+async fn test_remote_database_scan() {
+    suite::scan(open_remote().await).await;
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[tokio::test]
+async fn test_remote_database_select_range_with() {
+    suite::select_range_with(open_remote().await).await;
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[tokio::test]
+async fn test_remote_database_scan_prefix() {
+    suite::scan_prefix(open_remote().await).await;
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[tokio::test]
+async fn test_remote_database_compare_and_swap() {
+    suite::compare_and_swap(open_remote().await).await;
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[tokio::test]
+async fn test_remote_database_increment_and_merge() {
+    suite::increment_and_merge(open_remote().await).await;
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[tokio::test]
+async fn test_remote_database_ttl() {
+    suite::ttl(open_remote().await).await;
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[tokio::test]
+async fn test_remote_database_watch_prefix() {
+    suite::watch_prefix(open_remote().await).await;
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[tokio::test]
+async fn test_remote_database_trees() {
+    suite::trees(open_remote().await).await;
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[tokio::test]
+async fn test_remote_database_snapshot() {
+    suite::snapshot(open_remote().await).await;
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[tokio::test]
+async fn test_remote_database_unreachable() {
+    // Nothing can listen on port 0, so the connection is always refused
+    let error = RemoteDatabase::open("127.0.0.1:0").await.err().unwrap();
+    assert!(matches!(DatabaseError::of(&error), DatabaseError::Io(_)));
+}
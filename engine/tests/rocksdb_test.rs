@@ -1,7 +1,10 @@
 #![cfg(not(target_arch = "wasm32"))]
 
+use futures::StreamExt;
+use std::time::Duration;
 use tempfile::tempdir;
-use versedb::{Database, RocksDbDatabase};
+use versedb::database::{CompareAndSwapError, DatabaseError};
+use versedb::{Database, RocksDbDatabase, ScanOptions, WatchEvent, WriteBatch};
 
 #[tokio::test]
 async fn test_rocksdb_basic_operations() -> anyhow::Result<()> {
@@ -206,3 +209,450 @@ async fn test_rocksdb_binary_keys() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_rocksdb_transaction() -> anyhow::Result<()> {
+    let temp_dir = tempdir()?;
+    let path = temp_dir.path().to_str().unwrap();
+
+    let mut db = RocksDbDatabase::open(path).await?;
+    db.add(b"old", b"record").await?;
+
+    // Move the record to a new key atomically
+    db.transaction(|tx| {
+        let value = tx.get(b"old")?.unwrap();
+        tx.delete(b"old")?;
+        tx.put(b"new", &value)?;
+        Ok(())
+    })
+    .await?;
+    assert_eq!(db.select(b"old").await?, None);
+    assert_eq!(db.select(b"new").await?, Some(b"record".to_vec()));
+
+    // A failing closure leaves the database untouched
+    let result: anyhow::Result<()> = db
+        .transaction(|tx| {
+            tx.delete(b"new")?;
+            Err(anyhow::anyhow!("abort"))
+        })
+        .await;
+    assert!(result.is_err());
+    assert_eq!(db.select(b"new").await?, Some(b"record".to_vec()));
+
+    db.close().await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_rocksdb_write_batch() -> anyhow::Result<()> {
+    let temp_dir = tempdir()?;
+    let path = temp_dir.path().to_str().unwrap();
+
+    let mut db = RocksDbDatabase::open(path).await?;
+    db.add(b"key0", b"value0").await?;
+
+    // Operations apply in order: the range delete also drops earlier puts
+    let mut batch = WriteBatch::new();
+    batch
+        .put(b"key1", b"value1")
+        .put(b"key2", b"value2")
+        .put(b"key3", b"value3")
+        .put(b"key4", b"value4")
+        .delete(b"key1")
+        .delete_range(b"key3", b"key5");
+    db.write_batch(batch).await?;
+
+    let remaining = db.select_range(b"key0", b"key9").await?;
+    assert_eq!(
+        remaining,
+        vec![
+            (b"key0".to_vec(), b"value0".to_vec()),
+            (b"key2".to_vec(), b"value2".to_vec()),
+        ]
+    );
+
+    db.close().await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_rocksdb_scan() -> anyhow::Result<()> {
+    let temp_dir = tempdir()?;
+    let path = temp_dir.path().to_str().unwrap();
+
+    let mut db = RocksDbDatabase::open(path).await?;
+    let mut batch = WriteBatch::new();
+    for i in 0..3000u32 {
+        batch.put(format!("item:{:05}", i).as_bytes(), &i.to_be_bytes());
+    }
+    batch.put(b"other", b"value");
+    db.write_batch(batch).await?;
+
+    // Spans several cursor chunks and stops at the end bound
+    let mut stream = db.scan(b"item:00100", b"item:02600");
+    let mut count = 0u32;
+    while let Some(item) = stream.next().await {
+        let (key, value) = item?;
+        assert_eq!(key, format!("item:{:05}", count + 100).into_bytes());
+        assert_eq!(value, (count + 100).to_be_bytes().to_vec());
+        count += 1;
+    }
+    drop(stream);
+    assert_eq!(count, 2500);
+
+    db.close().await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_rocksdb_select_range_with() -> anyhow::Result<()> {
+    let temp_dir = tempdir()?;
+    let path = temp_dir.path().to_str().unwrap();
+
+    let mut db = RocksDbDatabase::open(path).await?;
+    for key in ["cat_a", "cat_b", "cat_c", "cat_d", "cat_e", "dog_a"] {
+        db.add(key.as_bytes(), b"v").await?;
+    }
+
+    // The last two entries of the category, newest first
+    let options = ScanOptions {
+        reverse: true,
+        limit: Some(2),
+        ..Default::default()
+    };
+    let page = db.select_range_with(b"cat_", b"cat_\xff", &options).await?;
+    let keys: Vec<_> = page.into_iter().map(|(key, _)| key).collect();
+    assert_eq!(keys, vec![b"cat_e".to_vec(), b"cat_d".to_vec()]);
+
+    // Resume the reverse listing after the last key of the previous page
+    let options = ScanOptions {
+        after: Some(b"cat_d".to_vec()),
+        ..options
+    };
+    let page = db.select_range_with(b"cat_", b"cat_\xff", &options).await?;
+    let keys: Vec<_> = page.into_iter().map(|(key, _)| key).collect();
+    assert_eq!(keys, vec![b"cat_c".to_vec(), b"cat_b".to_vec()]);
+
+    // Exclusive start and inclusive end
+    let options = ScanOptions {
+        start_inclusive: false,
+        end_inclusive: true,
+        ..Default::default()
+    };
+    let page = db.select_range_with(b"cat_b", b"cat_d", &options).await?;
+    let keys: Vec<_> = page.into_iter().map(|(key, _)| key).collect();
+    assert_eq!(keys, vec![b"cat_c".to_vec(), b"cat_d".to_vec()]);
+
+    db.close().await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_rocksdb_scan_prefix() -> anyhow::Result<()> {
+    let temp_dir = tempdir()?;
+    let path = temp_dir.path().to_str().unwrap();
+
+    let mut db = RocksDbDatabase::open(path).await?;
+    let entries: Vec<(&[u8], &[u8])> = vec![
+        (b"products:apple", b"1"),
+        (b"products:\xff\xff", b"2"),
+        (b"products;", b"3"),
+        (b"product", b"4"),
+    ];
+    for (key, value) in &entries {
+        db.add(key, value).await?;
+    }
+
+    // Keys with 0xff bytes after the prefix are included, neighbours are not
+    let found = db.scan_prefix(b"products:").await?;
+    assert_eq!(
+        found,
+        vec![
+            (b"products:apple".to_vec(), b"1".to_vec()),
+            (b"products:\xff\xff".to_vec(), b"2".to_vec()),
+        ]
+    );
+
+    let removed = db.remove_prefix(b"products:").await?;
+    assert_eq!(removed.len(), 2);
+    assert!(db.scan_prefix(b"products:").await?.is_empty());
+    assert_eq!(db.scan_prefix(b"product").await?.len(), 2);
+
+    db.close().await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_rocksdb_compare_and_swap() -> anyhow::Result<()> {
+    let temp_dir = tempdir()?;
+    let path = temp_dir.path().to_str().unwrap();
+
+    let mut db = RocksDbDatabase::open(path).await?;
+    // Create only if absent
+    assert_eq!(
+        db.compare_and_swap(b"lock", None, Some(b"a")).await?,
+        Ok(())
+    );
+    assert_eq!(
+        db.compare_and_swap(b"lock", None, Some(b"b")).await?,
+        Err(CompareAndSwapError {
+            current: Some(b"a".to_vec())
+        })
+    );
+
+    // Swap on a matching value, then delete
+    assert_eq!(
+        db.compare_and_swap(b"lock", Some(b"a"), Some(b"b")).await?,
+        Ok(())
+    );
+    assert_eq!(db.select(b"lock").await?, Some(b"b".to_vec()));
+    assert_eq!(
+        db.compare_and_swap(b"lock", Some(b"b"), None).await?,
+        Ok(())
+    );
+    assert_eq!(db.select(b"lock").await?, None);
+
+    // A stale expectation reports the key as absent
+    assert_eq!(
+        db.compare_and_swap(b"lock", Some(b"b"), Some(b"c")).await?,
+        Err(CompareAndSwapError { current: None })
+    );
+
+    db.close().await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_rocksdb_increment_and_merge() -> anyhow::Result<()> {
+    let temp_dir = tempdir()?;
+    let path = temp_dir.path().to_str().unwrap();
+
+    let mut db = RocksDbDatabase::open(path).await?;
+    assert_eq!(db.increment(b"hits", 5).await?, 5);
+    assert_eq!(db.increment(b"hits", -2).await?, 3);
+    assert_eq!(db.select(b"hits").await?, Some(b"3".to_vec()));
+
+    db.add(b"name", b"alice").await?;
+    assert!(db.increment(b"name", 1).await.is_err());
+
+    // Merges need a registered operator, which RocksDB applies to the operands
+    let error = db.merge(b"tags", b"a").await.unwrap_err();
+    assert!(matches!(
+        DatabaseError::of(&error),
+        DatabaseError::InvalidArgument(_)
+    ));
+    let append = |_key: &[u8], existing: Option<&[u8]>, operand: &[u8]| {
+        let mut value = existing.map(|v| v.to_vec()).unwrap_or_default();
+        if !value.is_empty() {
+            value.push(b',');
+        }
+        value.extend_from_slice(operand);
+        value
+    };
+    db.set_merge_operator(append);
+    db.merge(b"tags", b"a").await?;
+    db.merge(b"tags", b"b").await?;
+    assert_eq!(db.select(b"tags").await?, Some(b"a,b".to_vec()));
+
+    // Counters and merged values can be combined with other operations
+    assert_eq!(db.increment(b"hits", 1).await?, 4);
+    db.close().await?;
+    drop(db);
+
+    // Operands left unresolved read back once the operator is set again
+    let mut db = RocksDbDatabase::open(path).await?;
+    assert!(db.merge(b"tags", b"c").await.is_err());
+    db.set_merge_operator(append);
+    db.merge(b"tags", b"c").await?;
+    assert_eq!(db.select(b"tags").await?, Some(b"a,b,c".to_vec()));
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_rocksdb_ttl() -> anyhow::Result<()> {
+    let temp_dir = tempdir()?;
+    let path = temp_dir.path().to_str().unwrap();
+
+    let mut db = RocksDbDatabase::open(path).await?;
+    db.add_with_ttl(b"session", b"abc", Duration::from_millis(50))
+        .await?;
+    db.add_with_ttl(b"token", b"xyz", Duration::from_millis(50))
+        .await?;
+    db.add(b"user", b"alice").await?;
+
+    assert!(db.ttl(b"session").await?.unwrap() <= Duration::from_millis(50));
+    assert_eq!(db.ttl(b"user").await?, None);
+
+    // Writing the key again makes it permanent
+    db.add(b"token", b"xyz2").await?;
+    assert_eq!(db.ttl(b"token").await?, None);
+
+    tokio::time::sleep(Duration::from_millis(80)).await;
+    // Reads skip the expired entry, which stays until it is purged
+    assert_eq!(db.select(b"session").await?, None);
+    assert!(db.scan_prefix(b"s").await?.is_empty());
+    assert_eq!(db.purge_expired().await?, 1);
+    assert_eq!(db.ttl(b"session").await?, None);
+    assert_eq!(db.select(b"session").await?, None);
+    assert_eq!(db.select(b"token").await?, Some(b"xyz2".to_vec()));
+    assert_eq!(db.select_range(b"a", b"z").await?.len(), 2);
+
+    // Rewriting the same bytes through any method makes a key permanent too
+    for key in [b"a", b"b", b"c", b"d"] {
+        db.add_with_ttl(key, b"same", Duration::from_millis(50))
+            .await?;
+    }
+    db.transaction(|tx| tx.put(b"a", b"same")).await?;
+    let mut batch = WriteBatch::new();
+    batch.put(b"b", b"same");
+    db.write_batch(batch).await?;
+    db.set_merge_operator(|_key, _existing, operand| operand.to_vec());
+    db.merge(b"c", b"same").await?;
+    assert!(
+        db.compare_and_swap(b"d", Some(b"same"), Some(b"same"))
+            .await?
+            .is_ok()
+    );
+    for key in [b"a", b"b", b"c", b"d"] {
+        assert_eq!(db.ttl(key).await?, None);
+    }
+    tokio::time::sleep(Duration::from_millis(80)).await;
+    assert_eq!(db.purge_expired().await?, 0);
+    assert_eq!(db.select_range(b"a", b"e").await?.len(), 4);
+
+    // Expiry metadata lives in its own column family and survives reopening
+    db.add_with_ttl(b"later", b"soon", Duration::from_secs(60))
+        .await?;
+    db.close().await?;
+    let db = RocksDbDatabase::open(path).await?;
+    assert!(db.ttl(b"later").await?.is_some());
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_rocksdb_watch_prefix() -> anyhow::Result<()> {
+    let temp_dir = tempdir()?;
+    let path = temp_dir.path().to_str().unwrap();
+
+    let mut db = RocksDbDatabase::open(path).await?;
+    let events = db.watch_prefix(b"user:");
+
+    db.add(b"user:1", b"alice").await?;
+    db.add(b"order:1", b"book").await?;
+    db.increment(b"user:visits", 1).await?;
+    db.remove(b"user:1").await?;
+    let mut batch = WriteBatch::new();
+    batch.put(b"user:2", b"bob").delete(b"user:visits");
+    db.write_batch(batch).await?;
+
+    let events: Vec<WatchEvent> = events.take(5).collect().await;
+    assert_eq!(
+        events,
+        vec![
+            WatchEvent::Put {
+                key: b"user:1".to_vec(),
+                value: b"alice".to_vec()
+            },
+            WatchEvent::Put {
+                key: b"user:visits".to_vec(),
+                value: b"1".to_vec()
+            },
+            WatchEvent::Delete {
+                key: b"user:1".to_vec()
+            },
+            WatchEvent::Put {
+                key: b"user:2".to_vec(),
+                value: b"bob".to_vec()
+            },
+            WatchEvent::Delete {
+                key: b"user:visits".to_vec()
+            },
+        ]
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_rocksdb_trees() -> anyhow::Result<()> {
+    let temp_dir = tempdir()?;
+    let path = temp_dir.path().to_str().unwrap();
+
+    let mut db = RocksDbDatabase::open(path).await?;
+    db.add(b"key", b"root").await?;
+
+    let mut users = db.open_tree("users").await?;
+    users.add(b"key", b"alice").await?;
+    users
+        .add_with_ttl(b"session", b"abc", Duration::from_secs(60))
+        .await?;
+    assert_eq!(db.select(b"key").await?, Some(b"root".to_vec()));
+    assert_eq!(db.ttl(b"session").await?, None);
+
+    // Each tree's column family takes its own merge operator
+    let mut counters = db.open_tree("counters").await?;
+    assert!(counters.merge(b"tags", b"a").await.is_err());
+    counters.set_merge_operator(|_key, existing, operand| {
+        let mut value = existing.map(|v| v.to_vec()).unwrap_or_default();
+        value.extend_from_slice(operand);
+        value
+    });
+    counters.merge(b"tags", b"a").await?;
+    counters.merge(b"tags", b"b").await?;
+    assert_eq!(counters.select(b"tags").await?, Some(b"ab".to_vec()));
+    assert!(db.merge(b"tags", b"a").await.is_err());
+
+    // Column families are opened again along with the database
+    db.close().await?;
+    drop((db, users, counters));
+    let db = RocksDbDatabase::open(path).await?;
+    assert_eq!(db.list_trees().await?, vec!["counters", "users"]);
+    let users = db.open_tree("users").await?;
+    assert_eq!(users.select(b"key").await?, Some(b"alice".to_vec()));
+    assert!(users.ttl(b"session").await?.is_some());
+
+    assert!(db.drop_tree("users").await?);
+    assert!(!db.drop_tree("users").await?);
+    assert_eq!(db.list_trees().await?, vec!["counters"]);
+    assert!(users.select(b"key").await.is_err());
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_rocksdb_snapshot() -> anyhow::Result<()> {
+    let temp_dir = tempdir()?;
+    let path = temp_dir.path().to_str().unwrap();
+
+    let mut db = RocksDbDatabase::open(path).await?;
+    db.add(b"a", b"1").await?;
+    db.add(b"b", b"2").await?;
+    let snapshot = db.snapshot().await?;
+
+    db.add(b"a", b"changed").await?;
+    db.remove(b"b").await?;
+    db.add(b"c", b"3").await?;
+
+    assert_eq!(snapshot.select(b"a").await?, Some(b"1".to_vec()));
+    assert_eq!(snapshot.select(b"c").await?, None);
+    assert_eq!(
+        snapshot.select_range(b"a", b"z").await?,
+        vec![
+            (b"a".to_vec(), b"1".to_vec()),
+            (b"b".to_vec(), b"2".to_vec())
+        ]
+    );
+    assert_eq!(db.select_range(b"a", b"z").await?.len(), 2);
+
+    // A snapshot of a tree reads that tree's column family
+    let mut users = db.open_tree("users").await?;
+    users.add(b"a", b"alice").await?;
+    let snapshot = users.snapshot().await?;
+    users.remove(b"a").await?;
+    assert_eq!(snapshot.select(b"a").await?, Some(b"alice".to_vec()));
+    Ok(())
+}
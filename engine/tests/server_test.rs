@@ -0,0 +1,254 @@
+#[cfg(not(target_arch = "wasm32"))]
+use futures::StreamExt;
+#[cfg(not(target_arch = "wasm32"))]
+use futures::future;
+#[cfg(not(target_arch = "wasm32"))]
+use tempfile::tempdir;
+#[cfg(not(target_arch = "wasm32"))]
+use versedb::client::{ClientError, connect};
+#[cfg(not(target_arch = "wasm32"))]
+use versedb::csv::{CsvDatabase, CsvOptions};
+#[cfg(not(target_arch = "wasm32"))]
+use versedb::database::{Database, DatabaseError, WatchEvent};
+#[cfg(not(target_arch = "wasm32"))]
+use versedb::server::{serve, serve_with_workers};
+#[cfg(not(target_arch = "wasm32"))]
+use versedb::sled::SledDatabase;
+
+/// Serve `db` from a runtime of its own on a free local port, on the one
+/// `LocalSet` or spread over a pool of `workers` threads if there are several
+#[cfg(not(target_arch = "wasm32"))]
+fn start_server<T: Database + Clone + Send + Sync + 'static>(db: T, workers: usize) -> String {
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let addr = listener.local_addr().unwrap().to_string();
+    listener.set_nonblocking(true).unwrap();
+    std::thread::spawn(move || {
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+        runtime.block_on(async {
+            let listener = tokio::net::TcpListener::from_std(listener).unwrap();
+            if workers > 1 {
+                serve_with_workers(listener, db, workers).await.unwrap();
+            } else {
+                serve(listener, db).await.unwrap();
+            }
+        });
+    });
+    addr
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[tokio::test]
+async fn test_server_concurrent_clients() {
+    let temp_dir = tempdir().unwrap();
+    let db = SledDatabase::open(temp_dir.path().to_str().unwrap())
+        .await
+        .unwrap();
+    let addr = start_server(db, 1);
+
+    let local = tokio::task::LocalSet::new();
+    local
+        .run_until(async move {
+            let clients = future::try_join_all((0..8).map(|_| connect(&addr)))
+                .await
+                .unwrap();
+
+            // Every connection bumps a shared counter and writes keys of its own
+            let work = clients.iter().enumerate().map(|(i, client)| async move {
+                for n in 0..25 {
+                    client.increment(b"counter", 1).await?;
+                    let key = format!("client:{}:{:02}", i, n);
+                    client.add(key.as_bytes(), b"x").await?;
+                    assert_eq!(client.select(key.as_bytes()).await?, Some(b"x".to_vec()));
+                }
+                Ok::<_, ClientError>(())
+            });
+            future::try_join_all(work).await.unwrap();
+
+            assert_eq!(clients[0].increment(b"counter", 0).await.unwrap(), 200);
+            let written = clients[7].scan_prefix(b"client:").await.unwrap();
+            assert_eq!(written.len(), 200);
+        })
+        .await;
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[tokio::test]
+async fn test_server_worker_pool() {
+    let temp_dir = tempdir().unwrap();
+    let db = SledDatabase::open(temp_dir.path().to_str().unwrap())
+        .await
+        .unwrap();
+    let addr = start_server(db, 4);
+
+    let local = tokio::task::LocalSet::new();
+    local
+        .run_until(async move {
+            // Connections are handed to the workers in turn, so neighbouring
+            // clients are served by different threads sharing the one store
+            let clients = future::try_join_all((0..8).map(|_| connect(&addr)))
+                .await
+                .unwrap();
+            let events = clients[1].watch_prefix(b"client:0:").await.unwrap();
+
+            let work = clients.iter().enumerate().map(|(i, client)| async move {
+                for n in 0..25 {
+                    client.increment(b"counter", 1).await?;
+                    let key = format!("client:{}:{:02}", i, n);
+                    client.add(key.as_bytes(), b"x").await?;
+                    assert_eq!(client.select(key.as_bytes()).await?, Some(b"x".to_vec()));
+                }
+                Ok::<_, ClientError>(())
+            });
+            future::try_join_all(work).await.unwrap();
+
+            assert_eq!(clients[0].increment(b"counter", 0).await.unwrap(), 200);
+            let written = clients[7].scan_prefix(b"client:").await.unwrap();
+            assert_eq!(written.len(), 200);
+
+            let events: Vec<WatchEvent> = events.take(25).collect().await;
+            assert_eq!(
+                events[0],
+                WatchEvent::Put {
+                    key: b"client:0:00".to_vec(),
+                    value: b"x".to_vec()
+                }
+            );
+            assert!(
+                events
+                    .iter()
+                    .all(|event| event.key().starts_with(b"client:0:"))
+            );
+        })
+        .await;
+}
+
+/// Server answering `select` the way servers did before `found`: the value
+/// alone, left unset for a missing key
+#[cfg(not(target_arch = "wasm32"))]
+struct LegacyServer;
+
+#[cfg(not(target_arch = "wasm32"))]
+impl versedb::versedb_capnp::versedb::Server for LegacyServer {
+    fn select(
+        &mut self,
+        params: versedb::versedb_capnp::versedb::SelectParams,
+        mut results: versedb::versedb_capnp::versedb::SelectResults,
+    ) -> capnp::capability::Promise<(), capnp::Error> {
+        let key = capnp_rpc::pry!(capnp_rpc::pry!(params.get()).get_key());
+        if key == b"present" {
+            results.get().set_value(b"value");
+        }
+        capnp::capability::Promise::ok(())
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[tokio::test]
+async fn test_server_select_found() {
+    let temp_dir = tempdir().unwrap();
+    let mut db = SledDatabase::open(temp_dir.path().to_str().unwrap())
+        .await
+        .unwrap();
+    db.add(b"empty", b"").await.unwrap();
+    let addr = start_server(db, 1);
+
+    let local = tokio::task::LocalSet::new();
+    local
+        .run_until(async move {
+            let client = connect(&addr).await.unwrap();
+            assert_eq!(client.select(b"empty").await.unwrap(), Some(Vec::new()));
+            assert_eq!(client.select(b"missing").await.unwrap(), None);
+
+            // A server that predates `found` never sets it, so whatever it
+            // answers reads as found, as it did before
+            let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+            let legacy_addr = listener.local_addr().unwrap().to_string();
+            tokio::task::spawn_local(async move {
+                let (stream, _) = listener.accept().await.unwrap();
+                let stream = tokio_util::compat::TokioAsyncReadCompatExt::compat(stream);
+                let (reader, writer) = futures::AsyncReadExt::split(stream);
+                let network = Box::new(capnp_rpc::twoparty::VatNetwork::new(
+                    reader,
+                    writer,
+                    capnp_rpc::rpc_twoparty_capnp::Side::Server,
+                    Default::default(),
+                ));
+                let client: versedb::versedb_capnp::versedb::Client =
+                    capnp_rpc::new_client(LegacyServer);
+                capnp_rpc::RpcSystem::new(network, Some(client.client)).await
+            });
+            let legacy = connect(&legacy_addr).await.unwrap();
+            assert_eq!(
+                legacy.select(b"present").await.unwrap(),
+                Some(b"value".to_vec())
+            );
+            assert_eq!(legacy.select(b"missing").await.unwrap(), Some(Vec::new()));
+        })
+        .await;
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[tokio::test]
+async fn test_server_error_codes() {
+    let temp_dir = tempdir().unwrap();
+    let path = temp_dir.path().join("data.csv");
+    let path = path.to_str().unwrap();
+    let mut db = CsvDatabase::open(path).await.unwrap();
+    db.add(b"name", b"alice").await.unwrap();
+    db.close().await.unwrap();
+    drop(db);
+    let read_only = CsvOptions {
+        read_only: true,
+        ..CsvOptions::default()
+    };
+    let addr = start_server(CsvDatabase::open_with(path, read_only).await.unwrap(), 1);
+
+    let local = tokio::task::LocalSet::new();
+    local
+        .run_until(async move {
+            let client = connect(&addr).await.unwrap();
+            match client.increment(b"name", 1).await {
+                Err(ClientError::Database(DatabaseError::InvalidArgument(message))) => {
+                    assert_eq!(message, "value is not a counter: \"alice\"")
+                }
+                other => panic!("unexpected {:?}", other),
+            }
+            match client.add(b"name", b"bob").await {
+                Err(ClientError::Database(DatabaseError::ReadOnly(_))) => {}
+                other => panic!("unexpected {:?}", other),
+            }
+
+            // A malformed request is answered with an error rather than
+            // panicking the task serving the connection
+            let stream = tokio::net::TcpStream::connect(&addr).await.unwrap();
+            let stream = tokio_util::compat::TokioAsyncReadCompatExt::compat(stream);
+            let (reader, writer) = futures::AsyncReadExt::split(stream);
+            let network = Box::new(capnp_rpc::twoparty::VatNetwork::new(
+                reader,
+                writer,
+                capnp_rpc::rpc_twoparty_capnp::Side::Client,
+                Default::default(),
+            ));
+            let mut rpc_system = capnp_rpc::RpcSystem::new(network, None);
+            let raw: versedb::versedb_capnp::versedb::Client =
+                rpc_system.bootstrap(capnp_rpc::rpc_twoparty_capnp::Side::Server);
+            tokio::task::spawn_local(rpc_system);
+            let mut request = raw.helloworld_request();
+            request
+                .get()
+                .set_input(capnp::text::Reader::from(&b"\xff"[..]));
+            let error = request.send().promise.await.err().unwrap();
+            match ClientError::from(error) {
+                ClientError::Database(DatabaseError::InvalidArgument(message)) => {
+                    assert!(message.starts_with("malformed request"))
+                }
+                other => panic!("unexpected {:?}", other),
+            }
+            assert_eq!(client.helloworld("again").await.unwrap(), "Hello, again!");
+            assert_eq!(
+                client.select(b"name").await.unwrap(),
+                Some(b"alice".to_vec())
+            );
+        })
+        .await;
+}
@@ -1,8 +1,12 @@
 #[cfg(not(target_arch = "wasm32"))]
 mod sled_tests {
+    use futures::{StreamExt, TryStreamExt};
     use std::fs;
+    use std::time::Duration;
     use tempfile::tempdir;
-    use versedb::database::Database;
+    use versedb::database::{
+        CompareAndSwapError, Database, DatabaseError, ScanOptions, WatchEvent, WriteBatch,
+    };
     use versedb::sled::SledDatabase;
 
     #[tokio::test]
@@ -191,4 +195,456 @@ mod sled_tests {
         // Clean up
         temp_dir.close().unwrap();
     }
+
+    #[tokio::test]
+    async fn test_sled_database_transaction() {
+        let temp_dir = tempdir().unwrap();
+        let db_path = temp_dir.path().to_str().unwrap();
+        let mut db = SledDatabase::open(db_path).await.unwrap();
+        db.add(b"old", b"record").await.unwrap();
+
+        // Move the record to a new key atomically
+        db.transaction(|tx| {
+            let value = tx.get(b"old")?.unwrap();
+            tx.delete(b"old")?;
+            tx.put(b"new", &value)?;
+            Ok(())
+        })
+        .await
+        .unwrap();
+        assert_eq!(db.select(b"old").await.unwrap(), None);
+        assert_eq!(db.select(b"new").await.unwrap(), Some(b"record".to_vec()));
+
+        // A failing closure rolls back every write
+        let result: versedb::database::Result<()> = db
+            .transaction(|tx| {
+                tx.delete(b"new")?;
+                tx.put(b"other", b"value")?;
+                Err(anyhow::anyhow!("abort"))
+            })
+            .await;
+        assert!(result.is_err());
+        assert_eq!(db.select(b"new").await.unwrap(), Some(b"record".to_vec()));
+        assert_eq!(db.select(b"other").await.unwrap(), None);
+
+        // Clean up
+        db.close().await.unwrap();
+        temp_dir.close().unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_sled_database_write_batch() {
+        let temp_dir = tempdir().unwrap();
+        let db_path = temp_dir.path().to_str().unwrap();
+        let mut db = SledDatabase::open(db_path).await.unwrap();
+        db.add(b"key0", b"value0").await.unwrap();
+        db.add(b"key4", b"stale").await.unwrap();
+
+        // Operations apply in order: the range delete drops both stored keys
+        // and puts made earlier in the same batch
+        let mut batch = WriteBatch::new();
+        batch
+            .put(b"key1", b"value1")
+            .put(b"key2", b"value2")
+            .put(b"key3", b"value3")
+            .delete(b"key1")
+            .delete_range(b"key3", b"key5")
+            .put(b"key4", b"value4");
+        db.write_batch(batch).await.unwrap();
+
+        let remaining = db.select_range(b"key0", b"key9").await.unwrap();
+        assert_eq!(
+            remaining,
+            vec![
+                (b"key0".to_vec(), b"value0".to_vec()),
+                (b"key2".to_vec(), b"value2".to_vec()),
+                (b"key4".to_vec(), b"value4".to_vec()),
+            ]
+        );
+
+        // Clean up
+        db.close().await.unwrap();
+        temp_dir.close().unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_sled_database_scan() {
+        let temp_dir = tempdir().unwrap();
+        let db_path = temp_dir.path().to_str().unwrap();
+        let mut db = SledDatabase::open(db_path).await.unwrap();
+
+        for i in 0..100u32 {
+            let key = format!("item:{:03}", i);
+            db.add(key.as_bytes(), &i.to_be_bytes()).await.unwrap();
+        }
+        db.add(b"other", b"value").await.unwrap();
+
+        // The stream walks the range lazily and stops at the end bound
+        let first: Vec<_> = db
+            .scan(b"item:010", b"item:090")
+            .take(3)
+            .try_collect()
+            .await
+            .unwrap();
+        assert_eq!(first.len(), 3);
+        assert_eq!(
+            first[0],
+            (b"item:010".to_vec(), 10u32.to_be_bytes().to_vec())
+        );
+        assert_eq!(first[2].0, b"item:012".to_vec());
+
+        let all: Vec<_> = db
+            .scan(b"item:010", b"item:090")
+            .try_collect()
+            .await
+            .unwrap();
+        assert_eq!(all.len(), 80);
+        assert_eq!(all[79].0, b"item:089".to_vec());
+
+        // Clean up
+        db.close().await.unwrap();
+        temp_dir.close().unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_sled_database_select_range_with() {
+        let temp_dir = tempdir().unwrap();
+        let db_path = temp_dir.path().to_str().unwrap();
+        let mut db = SledDatabase::open(db_path).await.unwrap();
+
+        for key in ["cat_a", "cat_b", "cat_c", "cat_d", "cat_e", "dog_a"] {
+            db.add(key.as_bytes(), b"v").await.unwrap();
+        }
+
+        // The last two entries of the category, newest first
+        let options = ScanOptions {
+            reverse: true,
+            limit: Some(2),
+            ..Default::default()
+        };
+        let page = db
+            .select_range_with(b"cat_", b"cat_\xff", &options)
+            .await
+            .unwrap();
+        let keys: Vec<_> = page.into_iter().map(|(key, _)| key).collect();
+        assert_eq!(keys, vec![b"cat_e".to_vec(), b"cat_d".to_vec()]);
+
+        // Resume the reverse listing after the last key of the previous page
+        let options = ScanOptions {
+            after: Some(b"cat_d".to_vec()),
+            ..options
+        };
+        let page = db
+            .select_range_with(b"cat_", b"cat_\xff", &options)
+            .await
+            .unwrap();
+        let keys: Vec<_> = page.into_iter().map(|(key, _)| key).collect();
+        assert_eq!(keys, vec![b"cat_c".to_vec(), b"cat_b".to_vec()]);
+
+        // Exclusive start and inclusive end
+        let options = ScanOptions {
+            start_inclusive: false,
+            end_inclusive: true,
+            ..Default::default()
+        };
+        let page = db
+            .select_range_with(b"cat_b", b"cat_d", &options)
+            .await
+            .unwrap();
+        let keys: Vec<_> = page.into_iter().map(|(key, _)| key).collect();
+        assert_eq!(keys, vec![b"cat_c".to_vec(), b"cat_d".to_vec()]);
+
+        // Clean up
+        db.close().await.unwrap();
+        temp_dir.close().unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_sled_database_scan_prefix() {
+        let temp_dir = tempdir().unwrap();
+        let db_path = temp_dir.path().to_str().unwrap();
+        let mut db = SledDatabase::open(db_path).await.unwrap();
+
+        let entries: Vec<(&[u8], &[u8])> = vec![
+            (b"products:apple", b"1"),
+            (b"products:\xff\xff", b"2"),
+            (b"products;", b"3"),
+            (b"product", b"4"),
+        ];
+        for (key, value) in &entries {
+            db.add(key, value).await.unwrap();
+        }
+
+        // Keys with 0xff bytes after the prefix are included, neighbours are not
+        let found = db.scan_prefix(b"products:").await.unwrap();
+        assert_eq!(
+            found,
+            vec![
+                (b"products:apple".to_vec(), b"1".to_vec()),
+                (b"products:\xff\xff".to_vec(), b"2".to_vec()),
+            ]
+        );
+
+        let removed = db.remove_prefix(b"products:").await.unwrap();
+        assert_eq!(removed.len(), 2);
+        assert!(db.scan_prefix(b"products:").await.unwrap().is_empty());
+        assert_eq!(db.scan_prefix(b"product").await.unwrap().len(), 2);
+
+        // Clean up
+        db.close().await.unwrap();
+        temp_dir.close().unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_sled_database_compare_and_swap() {
+        let temp_dir = tempdir().unwrap();
+        let db_path = temp_dir.path().to_str().unwrap();
+        let mut db = SledDatabase::open(db_path).await.unwrap();
+
+        // Create only if absent
+        assert_eq!(
+            db.compare_and_swap(b"lock", None, Some(b"a"))
+                .await
+                .unwrap(),
+            Ok(())
+        );
+        assert_eq!(
+            db.compare_and_swap(b"lock", None, Some(b"b"))
+                .await
+                .unwrap(),
+            Err(CompareAndSwapError {
+                current: Some(b"a".to_vec())
+            })
+        );
+
+        // Swap on a matching value, then delete
+        assert_eq!(
+            db.compare_and_swap(b"lock", Some(b"a"), Some(b"b"))
+                .await
+                .unwrap(),
+            Ok(())
+        );
+        assert_eq!(db.select(b"lock").await.unwrap(), Some(b"b".to_vec()));
+        assert_eq!(
+            db.compare_and_swap(b"lock", Some(b"b"), None)
+                .await
+                .unwrap(),
+            Ok(())
+        );
+        assert_eq!(db.select(b"lock").await.unwrap(), None);
+
+        // A stale expectation reports the key as absent
+        assert_eq!(
+            db.compare_and_swap(b"lock", Some(b"b"), Some(b"c"))
+                .await
+                .unwrap(),
+            Err(CompareAndSwapError { current: None })
+        );
+
+        // Clean up
+        db.close().await.unwrap();
+        temp_dir.close().unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_sled_database_increment_and_merge() {
+        let temp_dir = tempdir().unwrap();
+        let db_path = temp_dir.path().to_str().unwrap();
+        let mut db = SledDatabase::open(db_path).await.unwrap();
+
+        // Counters go through update_and_fetch, starting at 0 when absent
+        assert_eq!(db.increment(b"hits", 5).await.unwrap(), 5);
+        assert_eq!(db.increment(b"hits", -2).await.unwrap(), 3);
+        assert_eq!(db.select(b"hits").await.unwrap(), Some(b"3".to_vec()));
+
+        db.add(b"name", b"alice").await.unwrap();
+        assert!(db.increment(b"name", 1).await.is_err());
+        assert_eq!(db.select(b"name").await.unwrap(), Some(b"alice".to_vec()));
+
+        // Merges go through sled's own merge operator
+        let error = db.merge(b"tags", b"a").await.unwrap_err();
+        assert!(matches!(
+            DatabaseError::of(&error),
+            DatabaseError::InvalidArgument(_)
+        ));
+        db.set_merge_operator(|_key, existing, operand| {
+            let mut value = existing.map(|v| v.to_vec()).unwrap_or_default();
+            if !value.is_empty() {
+                value.push(b',');
+            }
+            value.extend_from_slice(operand);
+            value
+        });
+        db.merge(b"tags", b"a").await.unwrap();
+        db.merge(b"tags", b"b").await.unwrap();
+        assert_eq!(db.select(b"tags").await.unwrap(), Some(b"a,b".to_vec()));
+
+        db.close().await.unwrap();
+        temp_dir.close().unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_sled_database_ttl() {
+        let temp_dir = tempdir().unwrap();
+        let db_path = temp_dir.path().to_str().unwrap();
+        let mut db = SledDatabase::open(db_path).await.unwrap();
+        db.add_with_ttl(b"session", b"abc", Duration::from_millis(50))
+            .await
+            .unwrap();
+        db.add_with_ttl(b"token", b"xyz", Duration::from_millis(50))
+            .await
+            .unwrap();
+        db.add(b"user", b"alice").await.unwrap();
+
+        assert!(db.ttl(b"session").await.unwrap().unwrap() <= Duration::from_millis(50));
+        assert_eq!(db.ttl(b"user").await.unwrap(), None);
+
+        // Writing the key again makes it permanent
+        db.add(b"token", b"xyz2").await.unwrap();
+        assert_eq!(db.ttl(b"token").await.unwrap(), None);
+
+        tokio::time::sleep(Duration::from_millis(80)).await;
+        // Reads skip the expired entry, which stays until it is purged
+        assert_eq!(db.select(b"session").await.unwrap(), None);
+        assert!(db.scan_prefix(b"s").await.unwrap().is_empty());
+        assert_eq!(db.purge_expired().await.unwrap(), 1);
+        assert_eq!(db.ttl(b"session").await.unwrap(), None);
+        assert_eq!(db.select(b"session").await.unwrap(), None);
+        assert_eq!(db.select(b"token").await.unwrap(), Some(b"xyz2".to_vec()));
+        assert_eq!(db.select_range(b"a", b"z").await.unwrap().len(), 2);
+
+        // Rewriting the same bytes through any method makes a key permanent too
+        for key in [b"a", b"b", b"c", b"d"] {
+            db.add_with_ttl(key, b"same", Duration::from_millis(50))
+                .await
+                .unwrap();
+        }
+        db.transaction(|tx| tx.put(b"a", b"same")).await.unwrap();
+        let mut batch = WriteBatch::new();
+        batch.put(b"b", b"same");
+        db.write_batch(batch).await.unwrap();
+        db.set_merge_operator(|_key, _existing, operand| operand.to_vec());
+        db.merge(b"c", b"same").await.unwrap();
+        assert!(
+            db.compare_and_swap(b"d", Some(b"same"), Some(b"same"))
+                .await
+                .unwrap()
+                .is_ok()
+        );
+        for key in [b"a", b"b", b"c", b"d"] {
+            assert_eq!(db.ttl(key).await.unwrap(), None);
+        }
+        tokio::time::sleep(Duration::from_millis(80)).await;
+        assert_eq!(db.purge_expired().await.unwrap(), 0);
+        assert_eq!(db.select_range(b"a", b"e").await.unwrap().len(), 4);
+
+        db.close().await.unwrap();
+        temp_dir.close().unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_sled_database_watch_prefix() {
+        let temp_dir = tempdir().unwrap();
+        let db_path = temp_dir.path().to_str().unwrap();
+        let mut db = SledDatabase::open(db_path).await.unwrap();
+        let events = db.watch_prefix(b"user:");
+
+        db.add(b"user:1", b"alice").await.unwrap();
+        db.add(b"order:1", b"book").await.unwrap();
+        db.increment(b"user:visits", 1).await.unwrap();
+        db.remove(b"user:1").await.unwrap();
+        let mut batch = WriteBatch::new();
+        batch.put(b"user:2", b"bob").delete(b"user:visits");
+        db.write_batch(batch).await.unwrap();
+
+        let mut events: Vec<WatchEvent> = events.take(5).collect().await;
+        // sled reports the writes of one batch in no particular order
+        events[3..].sort_by(|a, b| a.key().cmp(b.key()));
+        assert_eq!(
+            events,
+            vec![
+                WatchEvent::Put {
+                    key: b"user:1".to_vec(),
+                    value: b"alice".to_vec()
+                },
+                WatchEvent::Put {
+                    key: b"user:visits".to_vec(),
+                    value: b"1".to_vec()
+                },
+                WatchEvent::Delete {
+                    key: b"user:1".to_vec()
+                },
+                WatchEvent::Put {
+                    key: b"user:2".to_vec(),
+                    value: b"bob".to_vec()
+                },
+                WatchEvent::Delete {
+                    key: b"user:visits".to_vec()
+                },
+            ]
+        );
+
+        db.close().await.unwrap();
+        temp_dir.close().unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_sled_database_trees() {
+        let temp_dir = tempdir().unwrap();
+        let db_path = temp_dir.path().to_str().unwrap();
+        let mut db = SledDatabase::open(db_path).await.unwrap();
+        db.add(b"key", b"root").await.unwrap();
+
+        let mut users = db.open_tree("users").await.unwrap();
+        users.add(b"key", b"alice").await.unwrap();
+        users
+            .add_with_ttl(b"session", b"abc", Duration::from_secs(60))
+            .await
+            .unwrap();
+        db.open_tree("orders").await.unwrap();
+        assert_eq!(db.select(b"key").await.unwrap(), Some(b"root".to_vec()));
+        assert_eq!(db.ttl(b"session").await.unwrap(), None);
+
+        // The TTL tree and sled's own default tree aren't listed
+        assert_eq!(db.list_trees().await.unwrap(), vec!["orders", "users"]);
+        assert!(users.ttl(b"session").await.unwrap().is_some());
+
+        assert!(db.drop_tree("users").await.unwrap());
+        assert!(!db.drop_tree("users").await.unwrap());
+        assert_eq!(db.list_trees().await.unwrap(), vec!["orders"]);
+        let users = db.open_tree("users").await.unwrap();
+        assert_eq!(users.select(b"key").await.unwrap(), None);
+
+        db.close().await.unwrap();
+        temp_dir.close().unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_sled_database_snapshot() {
+        let temp_dir = tempdir().unwrap();
+        let db_path = temp_dir.path().to_str().unwrap();
+        let mut db = SledDatabase::open(db_path).await.unwrap();
+        db.add(b"a", b"1").await.unwrap();
+        db.add(b"b", b"2").await.unwrap();
+        let snapshot = db.snapshot().await.unwrap();
+
+        db.add(b"a", b"changed").await.unwrap();
+        db.remove(b"b").await.unwrap();
+        db.add(b"c", b"3").await.unwrap();
+
+        assert_eq!(snapshot.select(b"a").await.unwrap(), Some(b"1".to_vec()));
+        assert_eq!(snapshot.select(b"b").await.unwrap(), Some(b"2".to_vec()));
+        assert_eq!(snapshot.select(b"c").await.unwrap(), None);
+        assert_eq!(
+            snapshot.select_range(b"a", b"z").await.unwrap(),
+            vec![
+                (b"a".to_vec(), b"1".to_vec()),
+                (b"b".to_vec(), b"2".to_vec())
+            ]
+        );
+        assert_eq!(db.select_range(b"a", b"z").await.unwrap().len(), 2);
+
+        db.close().await.unwrap();
+        temp_dir.close().unwrap();
+    }
 }
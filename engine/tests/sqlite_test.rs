@@ -1,8 +1,12 @@
 #[cfg(not(target_arch = "wasm32"))]
+use futures::StreamExt;
+#[cfg(not(target_arch = "wasm32"))]
 use std::fs;
 #[cfg(not(target_arch = "wasm32"))]
+use std::time::Duration;
+#[cfg(not(target_arch = "wasm32"))]
 use tempfile::NamedTempFile;
-use versedb::database::Database;
+use versedb::database::{CompareAndSwapError, Database, ScanOptions, WatchEvent, WriteBatch};
 #[cfg(not(target_arch = "wasm32"))]
 use versedb::sqlite::SqliteDatabase;
 
@@ -217,3 +221,600 @@ async fn test_sqlite_database_flush() {
     // Clean up the temporary file
     fs::remove_file(path).unwrap();
 }
+
+#[cfg(not(target_arch = "wasm32"))]
+#[tokio::test]
+async fn test_sqlite_database_transaction() {
+    let temp_file = NamedTempFile::new().unwrap();
+    let path = temp_file.path().to_str().unwrap();
+
+    let mut db = SqliteDatabase::open(path).await.unwrap();
+    db.add(b"old", b"record").await.unwrap();
+
+    // Move the record to a new key atomically
+    db.transaction(|tx| {
+        let value = tx.get(b"old")?.unwrap();
+        tx.delete(b"old")?;
+        tx.put(b"new", &value)?;
+        Ok(())
+    })
+    .await
+    .unwrap();
+    assert_eq!(db.select(b"old").await.unwrap(), None);
+    assert_eq!(db.select(b"new").await.unwrap(), Some(b"record".to_vec()));
+
+    // A failing closure rolls back every write
+    let result: versedb::database::Result<()> = db
+        .transaction(|tx| {
+            tx.delete(b"new")?;
+            tx.put(b"other", b"value")?;
+            Err(anyhow::anyhow!("abort"))
+        })
+        .await;
+    assert!(result.is_err());
+    assert_eq!(db.select(b"new").await.unwrap(), Some(b"record".to_vec()));
+    assert_eq!(db.select(b"other").await.unwrap(), None);
+
+    db.close().await.unwrap();
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[tokio::test]
+async fn test_sqlite_database_write_batch() {
+    let temp_file = NamedTempFile::new().unwrap();
+    let path = temp_file.path().to_str().unwrap();
+
+    let mut db = SqliteDatabase::open(path).await.unwrap();
+    db.add(b"key0", b"value0").await.unwrap();
+
+    // Operations apply in order: the range delete also drops earlier puts
+    let mut batch = WriteBatch::new();
+    batch
+        .put(b"key1", b"value1")
+        .put(b"key2", b"value2")
+        .put(b"key3", b"value3")
+        .put(b"key4", b"value4")
+        .delete(b"key1")
+        .delete_range(b"key3", b"key5");
+    db.write_batch(batch).await.unwrap();
+
+    let remaining = db.select_range(b"key0", b"key9").await.unwrap();
+    assert_eq!(
+        remaining,
+        vec![
+            (b"key0".to_vec(), b"value0".to_vec()),
+            (b"key2".to_vec(), b"value2".to_vec()),
+        ]
+    );
+
+    db.close().await.unwrap();
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[tokio::test]
+async fn test_sqlite_database_scan() {
+    let temp_file = NamedTempFile::new().unwrap();
+    let path = temp_file.path().to_str().unwrap();
+
+    let mut db = SqliteDatabase::open(path).await.unwrap();
+    let mut batch = WriteBatch::new();
+    for i in 0..3000u32 {
+        batch.put(format!("item:{:05}", i).as_bytes(), &i.to_be_bytes());
+    }
+    batch.put(b"other", b"value");
+    db.write_batch(batch).await.unwrap();
+
+    // Spans several cursor chunks and stops at the end bound
+    let mut stream = db.scan(b"item:00100", b"item:02600");
+    let mut count = 0u32;
+    while let Some(item) = stream.next().await {
+        let (key, value) = item.unwrap();
+        assert_eq!(key, format!("item:{:05}", count + 100).into_bytes());
+        assert_eq!(value, (count + 100).to_be_bytes().to_vec());
+        count += 1;
+    }
+    drop(stream);
+    assert_eq!(count, 2500);
+
+    db.close().await.unwrap();
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[tokio::test]
+async fn test_sqlite_database_select_range_with() {
+    let temp_file = NamedTempFile::new().unwrap();
+    let path = temp_file.path().to_str().unwrap();
+
+    let mut db = SqliteDatabase::open(path).await.unwrap();
+    for key in ["cat_a", "cat_b", "cat_c", "cat_d", "cat_e", "dog_a"] {
+        db.add(key.as_bytes(), b"v").await.unwrap();
+    }
+
+    // The last two entries of the category, newest first
+    let options = ScanOptions {
+        reverse: true,
+        limit: Some(2),
+        ..Default::default()
+    };
+    let page = db
+        .select_range_with(b"cat_", b"cat_\xff", &options)
+        .await
+        .unwrap();
+    let keys: Vec<_> = page.into_iter().map(|(key, _)| key).collect();
+    assert_eq!(keys, vec![b"cat_e".to_vec(), b"cat_d".to_vec()]);
+
+    // Resume the reverse listing after the last key of the previous page
+    let options = ScanOptions {
+        after: Some(b"cat_d".to_vec()),
+        ..options
+    };
+    let page = db
+        .select_range_with(b"cat_", b"cat_\xff", &options)
+        .await
+        .unwrap();
+    let keys: Vec<_> = page.into_iter().map(|(key, _)| key).collect();
+    assert_eq!(keys, vec![b"cat_c".to_vec(), b"cat_b".to_vec()]);
+
+    // Exclusive start and inclusive end
+    let options = ScanOptions {
+        start_inclusive: false,
+        end_inclusive: true,
+        ..Default::default()
+    };
+    let page = db
+        .select_range_with(b"cat_b", b"cat_d", &options)
+        .await
+        .unwrap();
+    let keys: Vec<_> = page.into_iter().map(|(key, _)| key).collect();
+    assert_eq!(keys, vec![b"cat_c".to_vec(), b"cat_d".to_vec()]);
+
+    db.close().await.unwrap();
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[tokio::test]
+async fn test_sqlite_database_scan_prefix() {
+    let temp_file = NamedTempFile::new().unwrap();
+    let path = temp_file.path().to_str().unwrap();
+
+    let mut db = SqliteDatabase::open(path).await.unwrap();
+    let entries = vec![
+        ("city:\u{7f}", "1"),
+        ("city:\u{7f}x", "2"),
+        ("city:\u{80}", "3"),
+        ("city:ñandú", "4"),
+        ("city;", "5"),
+    ];
+    for (key, value) in &entries {
+        db.add(key.as_bytes(), value.as_bytes()).await.unwrap();
+    }
+
+    // The successor of "city:\x7f" is not valid UTF-8, which must not widen the result
+    let found = db.scan_prefix("city:\u{7f}".as_bytes()).await.unwrap();
+    assert_eq!(found.len(), 2);
+
+    let found = db.scan_prefix(b"city:").await.unwrap();
+    assert_eq!(found.len(), 4);
+
+    let removed = db.remove_prefix(b"city:").await.unwrap();
+    assert_eq!(removed.len(), 4);
+    assert_eq!(db.select(b"city;").await.unwrap(), Some(b"5".to_vec()));
+
+    db.close().await.unwrap();
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[tokio::test]
+async fn test_sqlite_database_compare_and_swap() {
+    let temp_file = NamedTempFile::new().unwrap();
+    let path = temp_file.path().to_str().unwrap();
+
+    let mut db = SqliteDatabase::open(path).await.unwrap();
+    // Create only if absent
+    assert_eq!(
+        db.compare_and_swap(b"lock", None, Some(b"a"))
+            .await
+            .unwrap(),
+        Ok(())
+    );
+    assert_eq!(
+        db.compare_and_swap(b"lock", None, Some(b"b"))
+            .await
+            .unwrap(),
+        Err(CompareAndSwapError {
+            current: Some(b"a".to_vec())
+        })
+    );
+
+    // Swap on a matching value, then delete
+    assert_eq!(
+        db.compare_and_swap(b"lock", Some(b"a"), Some(b"b"))
+            .await
+            .unwrap(),
+        Ok(())
+    );
+    assert_eq!(db.select(b"lock").await.unwrap(), Some(b"b".to_vec()));
+    assert_eq!(
+        db.compare_and_swap(b"lock", Some(b"b"), None)
+            .await
+            .unwrap(),
+        Ok(())
+    );
+    assert_eq!(db.select(b"lock").await.unwrap(), None);
+
+    // A stale expectation reports the key as absent
+    assert_eq!(
+        db.compare_and_swap(b"lock", Some(b"b"), Some(b"c"))
+            .await
+            .unwrap(),
+        Err(CompareAndSwapError { current: None })
+    );
+
+    db.close().await.unwrap();
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[tokio::test]
+async fn test_sqlite_database_increment_and_merge() {
+    let temp_file = NamedTempFile::new().unwrap();
+    let path = temp_file.path().to_str().unwrap();
+
+    let mut db = SqliteDatabase::open(path).await.unwrap();
+    assert_eq!(db.increment(b"hits", 5).await.unwrap(), 5);
+    assert_eq!(db.increment(b"hits", -7).await.unwrap(), -2);
+    assert_eq!(db.select(b"hits").await.unwrap(), Some(b"-2".to_vec()));
+
+    db.add(b"name", b"alice").await.unwrap();
+    assert!(db.increment(b"name", 1).await.is_err());
+    assert_eq!(db.select(b"name").await.unwrap(), Some(b"alice".to_vec()));
+
+    assert!(db.merge(b"tags", b"a").await.is_err());
+    db.set_merge_operator(|_key, existing, operand| {
+        let mut value = existing.map(|v| v.to_vec()).unwrap_or_default();
+        value.extend_from_slice(operand);
+        value
+    });
+    db.merge(b"tags", b"a").await.unwrap();
+    db.merge(b"tags", b"b").await.unwrap();
+    assert_eq!(db.select(b"tags").await.unwrap(), Some(b"ab".to_vec()));
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[tokio::test]
+async fn test_sqlite_database_ttl() {
+    let temp_file = NamedTempFile::new().unwrap();
+    let path = temp_file.path().to_str().unwrap();
+
+    let mut db = SqliteDatabase::open(path).await.unwrap();
+    db.add_with_ttl(b"session", b"abc", Duration::from_millis(50))
+        .await
+        .unwrap();
+    db.add_with_ttl(b"token", b"xyz", Duration::from_millis(50))
+        .await
+        .unwrap();
+    db.add(b"user", b"alice").await.unwrap();
+
+    assert!(db.ttl(b"session").await.unwrap().unwrap() <= Duration::from_millis(50));
+    assert_eq!(db.ttl(b"user").await.unwrap(), None);
+
+    // Writing the key again makes it permanent
+    db.add(b"token", b"xyz2").await.unwrap();
+    assert_eq!(db.ttl(b"token").await.unwrap(), None);
+
+    tokio::time::sleep(Duration::from_millis(80)).await;
+    // Reads skip the expired entry, which stays until it is purged
+    assert_eq!(db.select(b"session").await.unwrap(), None);
+    assert!(db.scan_prefix(b"s").await.unwrap().is_empty());
+    assert_eq!(db.purge_expired().await.unwrap(), 1);
+    assert_eq!(db.ttl(b"session").await.unwrap(), None);
+    assert_eq!(db.select(b"session").await.unwrap(), None);
+    assert_eq!(db.select(b"token").await.unwrap(), Some(b"xyz2".to_vec()));
+    assert_eq!(db.select_range(b"a", b"z").await.unwrap().len(), 2);
+
+    // Rewriting the same bytes through any method makes a key permanent too
+    for key in [b"a", b"b", b"c", b"d"] {
+        db.add_with_ttl(key, b"same", Duration::from_millis(50))
+            .await
+            .unwrap();
+    }
+    db.transaction(|tx| tx.put(b"a", b"same")).await.unwrap();
+    let mut batch = WriteBatch::new();
+    batch.put(b"b", b"same");
+    db.write_batch(batch).await.unwrap();
+    db.set_merge_operator(|_key, _existing, operand| operand.to_vec());
+    db.merge(b"c", b"same").await.unwrap();
+    assert!(
+        db.compare_and_swap(b"d", Some(b"same"), Some(b"same"))
+            .await
+            .unwrap()
+            .is_ok()
+    );
+    for key in [b"a", b"b", b"c", b"d"] {
+        assert_eq!(db.ttl(key).await.unwrap(), None);
+    }
+    tokio::time::sleep(Duration::from_millis(80)).await;
+    assert_eq!(db.purge_expired().await.unwrap(), 0);
+    assert_eq!(db.select_range(b"a", b"e").await.unwrap().len(), 4);
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[tokio::test]
+async fn test_sqlite_database_watch_prefix() {
+    let temp_file = NamedTempFile::new().unwrap();
+    let path = temp_file.path().to_str().unwrap();
+
+    let mut db = SqliteDatabase::open(path).await.unwrap();
+    let events = db.watch_prefix(b"user:");
+
+    db.add(b"user:1", b"alice").await.unwrap();
+    db.add(b"order:1", b"book").await.unwrap();
+    db.increment(b"user:visits", 1).await.unwrap();
+    db.remove(b"user:1").await.unwrap();
+    let mut batch = WriteBatch::new();
+    batch.put(b"user:2", b"bob").delete(b"user:visits");
+    db.write_batch(batch).await.unwrap();
+
+    let events: Vec<WatchEvent> = events.take(5).collect().await;
+    assert_eq!(
+        events,
+        vec![
+            WatchEvent::Put {
+                key: b"user:1".to_vec(),
+                value: b"alice".to_vec()
+            },
+            WatchEvent::Put {
+                key: b"user:visits".to_vec(),
+                value: b"1".to_vec()
+            },
+            WatchEvent::Delete {
+                key: b"user:1".to_vec()
+            },
+            WatchEvent::Put {
+                key: b"user:2".to_vec(),
+                value: b"bob".to_vec()
+            },
+            WatchEvent::Delete {
+                key: b"user:visits".to_vec()
+            },
+        ]
+    );
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[tokio::test]
+async fn test_sqlite_database_trees() {
+    let temp_file = NamedTempFile::new().unwrap();
+    let path = temp_file.path().to_str().unwrap();
+
+    let mut db = SqliteDatabase::open(path).await.unwrap();
+    db.add(b"key", b"root").await.unwrap();
+
+    let mut users = db.open_tree("users").await.unwrap();
+    users.add(b"key", b"alice").await.unwrap();
+    users
+        .add_with_ttl(b"session", b"abc", Duration::from_secs(60))
+        .await
+        .unwrap();
+    let mut logs = db.open_tree("app.logs-2024").await.unwrap();
+    logs.add(b"key", b"started").await.unwrap();
+    assert_eq!(db.select(b"key").await.unwrap(), Some(b"root".to_vec()));
+    assert_eq!(db.ttl(b"session").await.unwrap(), None);
+
+    // Trees are tables in the same file, so they survive reopening
+    let db = SqliteDatabase::open(path).await.unwrap();
+    assert_eq!(
+        db.list_trees().await.unwrap(),
+        vec!["app.logs-2024", "users"]
+    );
+    let users = db.open_tree("users").await.unwrap();
+    assert_eq!(users.select(b"key").await.unwrap(), Some(b"alice".to_vec()));
+    assert!(users.ttl(b"session").await.unwrap().is_some());
+
+    assert!(db.drop_tree("users").await.unwrap());
+    assert!(!db.drop_tree("users").await.unwrap());
+    assert_eq!(db.list_trees().await.unwrap(), vec!["app.logs-2024"]);
+    assert_eq!(db.select(b"key").await.unwrap(), Some(b"root".to_vec()));
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[tokio::test]
+async fn test_sqlite_database_snapshot() {
+    let temp_file = NamedTempFile::new().unwrap();
+    let path = temp_file.path().to_str().unwrap();
+
+    let mut db = SqliteDatabase::open(path).await.unwrap();
+    db.add(b"a", b"1").await.unwrap();
+    db.add(b"b", b"2").await.unwrap();
+    let snapshot = db.snapshot().await.unwrap();
+
+    db.add(b"a", b"changed").await.unwrap();
+    db.remove(b"b").await.unwrap();
+    db.add(b"c", b"3").await.unwrap();
+
+    assert_eq!(snapshot.select(b"a").await.unwrap(), Some(b"1".to_vec()));
+    assert_eq!(snapshot.select(b"c").await.unwrap(), None);
+    assert_eq!(
+        snapshot.select_range(b"a", b"z").await.unwrap(),
+        vec![
+            (b"a".to_vec(), b"1".to_vec()),
+            (b"b".to_vec(), b"2".to_vec())
+        ]
+    );
+    assert_eq!(db.select_range(b"a", b"z").await.unwrap().len(), 2);
+
+    // Writers aren't held up by the open snapshot, and it ends when dropped
+    drop(snapshot);
+    db.add(b"d", b"4").await.unwrap();
+
+    // An in-memory database has no file to read the snapshot from
+    let mut db = SqliteDatabase::open(":memory:").await.unwrap();
+    db.add(b"a", b"1").await.unwrap();
+    let snapshot = db.snapshot().await.unwrap();
+    db.add(b"a", b"changed").await.unwrap();
+    assert_eq!(snapshot.select(b"a").await.unwrap(), Some(b"1".to_vec()));
+    assert_eq!(db.select(b"a").await.unwrap(), Some(b"changed".to_vec()));
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[tokio::test]
+async fn test_sqlite_database_binary_keys() {
+    let temp_file = NamedTempFile::new().unwrap();
+    let path = temp_file.path().to_str().unwrap();
+
+    let mut db = SqliteDatabase::open(path).await.unwrap();
+    let keys: [&[u8]; 6] = [b"", b"\x00", b"\x00\x00", b"B", b"a", b"\xff\xfe"];
+    for (i, key) in keys.iter().enumerate().rev() {
+        db.add(key, &[i as u8]).await.unwrap();
+    }
+    // Invalid UTF-8 is kept intact rather than replaced
+    db.add(b"\xc3\x28", b"invalid").await.unwrap();
+    db.add(b"\xc3\x29", b"also invalid").await.unwrap();
+    assert_eq!(
+        db.select(b"\xc3\x28").await.unwrap(),
+        Some(b"invalid".to_vec())
+    );
+    assert_eq!(
+        db.select(b"\xc3\x29").await.unwrap(),
+        Some(b"also invalid".to_vec())
+    );
+
+    // Ranges follow byte order, uppercase before lowercase
+    let all: Vec<Vec<u8>> = db
+        .select_range(b"", b"\xff\xff")
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|(key, _)| key)
+        .collect();
+    let expected: Vec<Vec<u8>> = [
+        b"".as_slice(),
+        b"\x00",
+        b"\x00\x00",
+        b"B",
+        b"a",
+        b"\xc3\x28",
+        b"\xc3\x29",
+        b"\xff\xfe",
+    ]
+    .iter()
+    .map(|key| key.to_vec())
+    .collect();
+    assert_eq!(all, expected);
+
+    assert_eq!(
+        db.scan_prefix(b"\x00").await.unwrap(),
+        vec![(b"\x00".to_vec(), vec![1]), (b"\x00\x00".to_vec(), vec![2])]
+    );
+    assert_eq!(
+        db.scan_prefix(b"\xff").await.unwrap(),
+        vec![(b"\xff\xfe".to_vec(), vec![5])]
+    );
+    let options = ScanOptions {
+        reverse: true,
+        limit: Some(2),
+        ..ScanOptions::default()
+    };
+    assert_eq!(
+        db.select_range_with(b"\x00", b"a", &options).await.unwrap(),
+        vec![(b"B".to_vec(), vec![3]), (b"\x00\x00".to_vec(), vec![2])]
+    );
+
+    db.add_with_ttl(b"\xfe", b"short", Duration::from_secs(60))
+        .await
+        .unwrap();
+    assert!(db.ttl(b"\xfe").await.unwrap().is_some());
+    db.remove(b"\xc3\x28").await.unwrap();
+    assert_eq!(db.select(b"\xc3\x28").await.unwrap(), None);
+    assert!(db.select(b"\xc3\x29").await.unwrap().is_some());
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[tokio::test]
+async fn test_sqlite_database_migrates_text_keys() {
+    let temp_file = NamedTempFile::new().unwrap();
+    let path = temp_file.path().to_str().unwrap();
+
+    // The schema from before keys were BLOBs
+    {
+        let conn = rusqlite::Connection::open(path).unwrap();
+        conn.execute_batch(
+            "CREATE TABLE kv_store (key TEXT PRIMARY KEY, value BLOB);
+             CREATE TABLE kv_expiry (
+                 key TEXT PRIMARY KEY,
+                 expires_at INTEGER NOT NULL,
+                 fingerprint INTEGER NOT NULL
+             );
+             CREATE INDEX kv_expiry_expires_at ON kv_expiry (expires_at);
+             CREATE TABLE kv_tree_users (key TEXT PRIMARY KEY, value BLOB);
+             INSERT INTO kv_store VALUES ('b', x'32'), ('a', x'31'), ('é', x'33');
+             INSERT INTO kv_expiry VALUES ('a', 9999999999999, 0);
+             INSERT INTO kv_tree_users VALUES ('alice', x'34');",
+        )
+        .unwrap();
+    }
+
+    let mut db = SqliteDatabase::open(path).await.unwrap();
+    assert_eq!(
+        db.select_range(b"a", b"\xff").await.unwrap(),
+        vec![
+            (b"a".to_vec(), b"1".to_vec()),
+            (b"b".to_vec(), b"2".to_vec()),
+            ("é".as_bytes().to_vec(), b"3".to_vec())
+        ]
+    );
+    db.add(b"\xff", b"binary").await.unwrap();
+    assert_eq!(db.select(b"\xff").await.unwrap(), Some(b"binary".to_vec()));
+
+    let users = db.open_tree("users").await.unwrap();
+    assert_eq!(users.select(b"alice").await.unwrap(), Some(b"4".to_vec()));
+
+    let conn = rusqlite::Connection::open(path).unwrap();
+    for table in ["kv_store", "kv_expiry", "kv_tree_users"] {
+        let key_type: String = conn
+            .query_row(
+                "SELECT type FROM pragma_table_info(?) WHERE name = 'key'",
+                [table],
+                |row| row.get(0),
+            )
+            .unwrap();
+        assert_eq!(key_type, "BLOB");
+    }
+    let index: bool = conn
+        .query_row(
+            "SELECT EXISTS(SELECT 1 FROM sqlite_master WHERE name = 'kv_expiry_expires_at')",
+            [],
+            |row| row.get(0),
+        )
+        .unwrap();
+    assert!(index);
+    let version = |conn: &rusqlite::Connection| -> i64 {
+        conn.query_row("PRAGMA user_version", [], |row| row.get(0))
+            .unwrap()
+    };
+    assert_eq!(version(&conn), 1);
+
+    // Opening an already migrated database leaves it as it is, without
+    // looking at its tables again
+    conn.execute_batch("CREATE TABLE kv_tree_legacy (key TEXT PRIMARY KEY, value BLOB)")
+        .unwrap();
+    let db = SqliteDatabase::open(path).await.unwrap();
+    assert_eq!(db.select(b"a").await.unwrap(), Some(b"1".to_vec()));
+    let key_type: String = conn
+        .query_row(
+            "SELECT type FROM pragma_table_info('kv_tree_legacy') WHERE name = 'key'",
+            [],
+            |row| row.get(0),
+        )
+        .unwrap();
+    assert_eq!(key_type, "TEXT");
+
+    // A new database starts out at the current version
+    let fresh = NamedTempFile::new().unwrap();
+    SqliteDatabase::open(fresh.path().to_str().unwrap())
+        .await
+        .unwrap();
+    assert_eq!(
+        version(&rusqlite::Connection::open(fresh.path()).unwrap()),
+        1
+    );
+}
@@ -0,0 +1,404 @@
+//! Checks every `Database` backend is expected to pass, each run on a fresh
+//! empty database. Included with `mod suite;` by the backend tests.
+#![cfg(not(target_arch = "wasm32"))]
+
+use futures::{StreamExt, TryStreamExt};
+use std::time::Duration;
+use versedb::database::{
+    CompareAndSwapError, Database, DatabaseError, ScanOptions, WatchEvent, WriteBatch,
+};
+
+pub async fn basic<D: Database>(mut db: D) {
+    // Test add and select
+    db.add("key1".as_bytes(), "value1".as_bytes())
+        .await
+        .unwrap();
+    db.add("key2".as_bytes(), "value2".as_bytes())
+        .await
+        .unwrap();
+    db.add("key3".as_bytes(), "value3".as_bytes())
+        .await
+        .unwrap();
+
+    // Test select
+    assert_eq!(
+        db.select("key1".as_bytes()).await.unwrap(),
+        Some("value1".as_bytes().to_vec())
+    );
+    assert_eq!(
+        db.select("key2".as_bytes()).await.unwrap(),
+        Some("value2".as_bytes().to_vec())
+    );
+    assert_eq!(db.select("nonexistent".as_bytes()).await.unwrap(), None);
+    db.add(b"empty", b"").await.unwrap();
+    assert_eq!(db.select(b"empty").await.unwrap(), Some(Vec::new()));
+
+    // Test select_range (inclusive of key1, exclusive of key3)
+    let range = db
+        .select_range("key1".as_bytes(), "key3".as_bytes())
+        .await
+        .unwrap();
+    assert_eq!(range.len(), 2);
+    assert!(range.contains(&("key1".as_bytes().to_vec(), "value1".as_bytes().to_vec())));
+    assert!(range.contains(&("key2".as_bytes().to_vec(), "value2".as_bytes().to_vec())));
+
+    // Test remove
+    db.remove("key1".as_bytes()).await.unwrap();
+    assert_eq!(db.select("key1".as_bytes()).await.unwrap(), None);
+    db.flush().await.unwrap();
+}
+
+pub async fn remove_range<D: Database>(mut db: D) {
+    // Add entries with ordered keys
+    let entries = vec![
+        ("key1", "value1"),
+        ("key2", "value2"),
+        ("key3", "value3"),
+        ("key4", "value4"),
+        ("key5", "value5"),
+    ];
+
+    for (key, value) in &entries {
+        db.add(key.as_bytes(), value.as_bytes()).await.unwrap();
+    }
+
+    // Test remove_range from key2 to key4 (inclusive of key2, exclusive of key4)
+    let removed = db
+        .remove_range("key2".as_bytes(), "key4".as_bytes())
+        .await
+        .unwrap();
+
+    // Verify the removed entries
+    assert_eq!(removed.len(), 2);
+    assert!(removed.contains(&("key2".as_bytes().to_vec(), "value2".as_bytes().to_vec())));
+    assert!(removed.contains(&("key3".as_bytes().to_vec(), "value3".as_bytes().to_vec())));
+
+    // Verify the remaining entries
+    let remaining = db
+        .select_range("key1".as_bytes(), "key6".as_bytes())
+        .await
+        .unwrap();
+    assert_eq!(remaining.len(), 3);
+    assert!(remaining.contains(&("key1".as_bytes().to_vec(), "value1".as_bytes().to_vec())));
+    assert!(remaining.contains(&("key4".as_bytes().to_vec(), "value4".as_bytes().to_vec())));
+    assert!(remaining.contains(&("key5".as_bytes().to_vec(), "value5".as_bytes().to_vec())));
+}
+
+pub async fn write_batch<D: Database>(mut db: D) {
+    db.add(b"key0", b"value0").await.unwrap();
+
+    // Operations apply in order: the range delete also drops earlier puts
+    let mut batch = WriteBatch::new();
+    batch
+        .put(b"key1", b"value1")
+        .put(b"key2", b"value2")
+        .put(b"key3", b"value3")
+        .put(b"key4", b"value4")
+        .delete(b"key1")
+        .delete_range(b"key3", b"key5");
+    db.write_batch(batch).await.unwrap();
+
+    let remaining = db.select_range(b"key0", b"key9").await.unwrap();
+    assert_eq!(
+        remaining,
+        vec![
+            (b"key0".to_vec(), b"value0".to_vec()),
+            (b"key2".to_vec(), b"value2".to_vec()),
+        ]
+    );
+}
+
+pub async fn scan<D: Database>(mut db: D) {
+    for i in 0..3000u32 {
+        let key = format!("item:{:05}", i);
+        db.add(key.as_bytes(), &i.to_be_bytes()).await.unwrap();
+    }
+    db.add(b"other", b"value").await.unwrap();
+
+    // Spans several cursor chunks and stops at the end bound
+    let mut stream = db.scan(b"item:00100", b"item:02600");
+    let mut count = 0u32;
+    while let Some(item) = stream.next().await {
+        let (key, value) = item.unwrap();
+        assert_eq!(key, format!("item:{:05}", count + 100).into_bytes());
+        assert_eq!(value, (count + 100).to_be_bytes().to_vec());
+        count += 1;
+    }
+    drop(stream);
+    assert_eq!(count, 2500);
+
+    // An inverted range yields nothing
+    let empty: Vec<_> = db
+        .scan(b"item:02000", b"item:01000")
+        .try_collect()
+        .await
+        .unwrap();
+    assert!(empty.is_empty());
+}
+
+pub async fn select_range_with<D: Database>(mut db: D) {
+    for key in ["cat_a", "cat_b", "cat_c", "cat_d", "cat_e", "dog_a"] {
+        db.add(key.as_bytes(), b"v").await.unwrap();
+    }
+
+    // The last two entries of the category, newest first
+    let options = ScanOptions {
+        reverse: true,
+        limit: Some(2),
+        ..Default::default()
+    };
+    let page = db
+        .select_range_with(b"cat_", b"cat_\xff", &options)
+        .await
+        .unwrap();
+    let keys: Vec<_> = page.into_iter().map(|(key, _)| key).collect();
+    assert_eq!(keys, vec![b"cat_e".to_vec(), b"cat_d".to_vec()]);
+
+    // Resume the reverse listing after the last key of the previous page
+    let options = ScanOptions {
+        after: Some(b"cat_d".to_vec()),
+        ..options
+    };
+    let page = db
+        .select_range_with(b"cat_", b"cat_\xff", &options)
+        .await
+        .unwrap();
+    let keys: Vec<_> = page.into_iter().map(|(key, _)| key).collect();
+    assert_eq!(keys, vec![b"cat_c".to_vec(), b"cat_b".to_vec()]);
+
+    // Exclusive start and inclusive end
+    let options = ScanOptions {
+        start_inclusive: false,
+        end_inclusive: true,
+        ..Default::default()
+    };
+    let page = db
+        .select_range_with(b"cat_b", b"cat_d", &options)
+        .await
+        .unwrap();
+    let keys: Vec<_> = page.into_iter().map(|(key, _)| key).collect();
+    assert_eq!(keys, vec![b"cat_c".to_vec(), b"cat_d".to_vec()]);
+}
+
+pub async fn scan_prefix<D: Database>(mut db: D) {
+    let entries: Vec<(&[u8], &[u8])> = vec![
+        (b"products:apple", b"1"),
+        (b"products:\xff\xff", b"2"),
+        (b"products;", b"3"),
+        (b"product", b"4"),
+    ];
+    for (key, value) in &entries {
+        db.add(key, value).await.unwrap();
+    }
+
+    // Keys with 0xff bytes after the prefix are included, neighbours are not
+    let found = db.scan_prefix(b"products:").await.unwrap();
+    assert_eq!(
+        found,
+        vec![
+            (b"products:apple".to_vec(), b"1".to_vec()),
+            (b"products:\xff\xff".to_vec(), b"2".to_vec()),
+        ]
+    );
+
+    let removed = db.remove_prefix(b"products:").await.unwrap();
+    assert_eq!(removed.len(), 2);
+    assert!(db.scan_prefix(b"products:").await.unwrap().is_empty());
+    assert_eq!(db.scan_prefix(b"product").await.unwrap().len(), 2);
+
+    // The whole keyspace for an empty prefix
+    assert_eq!(db.scan_prefix(b"").await.unwrap().len(), 2);
+}
+
+pub async fn compare_and_swap<D: Database>(mut db: D) {
+    // Create only if absent
+    assert_eq!(
+        db.compare_and_swap(b"lock", None, Some(b"a"))
+            .await
+            .unwrap(),
+        Ok(())
+    );
+    assert_eq!(
+        db.compare_and_swap(b"lock", None, Some(b"b"))
+            .await
+            .unwrap(),
+        Err(CompareAndSwapError {
+            current: Some(b"a".to_vec())
+        })
+    );
+
+    // Swap on a matching value, then delete
+    assert_eq!(
+        db.compare_and_swap(b"lock", Some(b"a"), Some(b"b"))
+            .await
+            .unwrap(),
+        Ok(())
+    );
+    assert_eq!(db.select(b"lock").await.unwrap(), Some(b"b".to_vec()));
+    assert_eq!(
+        db.compare_and_swap(b"lock", Some(b"b"), None)
+            .await
+            .unwrap(),
+        Ok(())
+    );
+    assert_eq!(db.select(b"lock").await.unwrap(), None);
+
+    // A stale expectation reports the key as absent
+    assert_eq!(
+        db.compare_and_swap(b"lock", Some(b"b"), Some(b"c"))
+            .await
+            .unwrap(),
+        Err(CompareAndSwapError { current: None })
+    );
+}
+
+pub async fn increment_and_merge<D: Database>(mut db: D) {
+    // An absent counter starts at 0
+    assert_eq!(db.increment(b"hits", 5).await.unwrap(), 5);
+    assert_eq!(db.increment(b"hits", -2).await.unwrap(), 3);
+    assert_eq!(db.select(b"hits").await.unwrap(), Some(b"3".to_vec()));
+
+    // Non-counter values and overflow are rejected without writing
+    db.add(b"name", b"alice").await.unwrap();
+    let error = db.increment(b"name", 1).await.unwrap_err();
+    assert_eq!(
+        DatabaseError::of(&error),
+        DatabaseError::InvalidArgument("value is not a counter: \"alice\"".to_string())
+    );
+    assert_eq!(db.select(b"name").await.unwrap(), Some(b"alice".to_vec()));
+    db.add(b"max", i64::MAX.to_string().as_bytes())
+        .await
+        .unwrap();
+    assert!(db.increment(b"max", 1).await.is_err());
+
+    // Merging needs a registered operator
+    assert!(db.merge(b"tags", b"a").await.is_err());
+    db.set_merge_operator(|_key, existing, operand| {
+        let mut value = existing.map(|v| v.to_vec()).unwrap_or_default();
+        if !value.is_empty() {
+            value.push(b',');
+        }
+        value.extend_from_slice(operand);
+        value
+    });
+    db.merge(b"tags", b"a").await.unwrap();
+    db.merge(b"tags", b"b").await.unwrap();
+    assert_eq!(db.select(b"tags").await.unwrap(), Some(b"a,b".to_vec()));
+}
+
+pub async fn ttl<D: Database>(mut db: D) {
+    db.add_with_ttl(b"session", b"abc", Duration::from_millis(50))
+        .await
+        .unwrap();
+    db.add_with_ttl(b"token", b"xyz", Duration::from_millis(50))
+        .await
+        .unwrap();
+    db.add(b"user", b"alice").await.unwrap();
+
+    let remaining = db.ttl(b"session").await.unwrap().unwrap();
+    assert!(remaining <= Duration::from_millis(50));
+    assert_eq!(db.ttl(b"user").await.unwrap(), None);
+    assert_eq!(db.select(b"session").await.unwrap(), Some(b"abc".to_vec()));
+
+    // Writing the key again makes it permanent
+    db.add(b"token", b"xyz2").await.unwrap();
+    assert_eq!(db.ttl(b"token").await.unwrap(), None);
+
+    tokio::time::sleep(Duration::from_millis(80)).await;
+    assert_eq!(db.select(b"session").await.unwrap(), None);
+    assert_eq!(db.ttl(b"session").await.unwrap(), None);
+    assert_eq!(db.select(b"token").await.unwrap(), Some(b"xyz2".to_vec()));
+    assert_eq!(db.select_range(b"a", b"z").await.unwrap().len(), 2);
+    assert_eq!(db.purge_expired().await.unwrap(), 0);
+}
+
+pub async fn watch_prefix<D: Database>(mut db: D) {
+    let events = db.watch_prefix(b"user:");
+    // A remote database sends its calls in order, so the subscription is
+    // in place once this round trip is answered
+    db.select(b"user:1").await.unwrap();
+
+    db.add(b"user:1", b"alice").await.unwrap();
+    db.add(b"order:1", b"book").await.unwrap();
+    db.increment(b"user:visits", 1).await.unwrap();
+    db.remove(b"user:1").await.unwrap();
+    let mut batch = WriteBatch::new();
+    batch.put(b"user:2", b"bob").delete(b"user:visits");
+    db.write_batch(batch).await.unwrap();
+
+    let events: Vec<WatchEvent> =
+        tokio::time::timeout(Duration::from_secs(5), events.take(5).collect())
+            .await
+            .unwrap();
+    assert_eq!(
+        events,
+        vec![
+            WatchEvent::Put {
+                key: b"user:1".to_vec(),
+                value: b"alice".to_vec()
+            },
+            WatchEvent::Put {
+                key: b"user:visits".to_vec(),
+                value: b"1".to_vec()
+            },
+            WatchEvent::Delete {
+                key: b"user:1".to_vec()
+            },
+            WatchEvent::Put {
+                key: b"user:2".to_vec(),
+                value: b"bob".to_vec()
+            },
+            WatchEvent::Delete {
+                key: b"user:visits".to_vec()
+            },
+        ]
+    );
+
+    // Writes carry on once the stream is dropped
+    db.add(b"user:3", b"carol").await.unwrap();
+}
+
+pub async fn trees<D: Database>(mut db: D) {
+    db.add(b"key", b"root").await.unwrap();
+
+    let mut users = db.open_tree("users").await.unwrap();
+    assert_eq!(users.select(b"key").await.unwrap(), None);
+    users.add(b"key", b"alice").await.unwrap();
+    assert_eq!(db.select(b"key").await.unwrap(), Some(b"root".to_vec()));
+
+    // Handles on the same tree share its entries
+    let again = db.open_tree("users").await.unwrap();
+    assert_eq!(again.select(b"key").await.unwrap(), Some(b"alice".to_vec()));
+
+    db.open_tree("orders").await.unwrap();
+    assert_eq!(db.list_trees().await.unwrap(), vec!["orders", "users"]);
+    assert!(db.open_tree("").await.is_err());
+    assert!(db.open_tree("default").await.is_err());
+
+    assert!(db.drop_tree("users").await.unwrap());
+    assert!(!db.drop_tree("users").await.unwrap());
+    assert_eq!(db.list_trees().await.unwrap(), vec!["orders"]);
+    let users = db.open_tree("users").await.unwrap();
+    assert_eq!(users.select(b"key").await.unwrap(), None);
+}
+
+pub async fn snapshot<D: Database>(mut db: D) {
+    db.add(b"a", b"1").await.unwrap();
+    db.add(b"b", b"2").await.unwrap();
+    let snapshot = db.snapshot().await.unwrap();
+
+    db.add(b"a", b"changed").await.unwrap();
+    db.remove(b"b").await.unwrap();
+    db.add(b"c", b"3").await.unwrap();
+
+    assert_eq!(snapshot.select(b"a").await.unwrap(), Some(b"1".to_vec()));
+    assert_eq!(snapshot.select(b"c").await.unwrap(), None);
+    assert_eq!(
+        snapshot.select_range(b"a", b"z").await.unwrap(),
+        vec![
+            (b"a".to_vec(), b"1".to_vec()),
+            (b"b".to_vec(), b"2".to_vec())
+        ]
+    );
+    assert_eq!(db.select_range(b"a", b"z").await.unwrap().len(), 2);
+}
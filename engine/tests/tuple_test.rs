@@ -0,0 +1,160 @@
+use chrono::{DateTime, Utc};
+use versedb::collection::Collection;
+use versedb::database::Database;
+use versedb::memory::MemoryDatabase;
+use versedb::tuple::{Element, Tuple};
+
+fn timestamp(seconds: i64, nanos: u32) -> DateTime<Utc> {
+    DateTime::from_timestamp(seconds, nanos).unwrap()
+}
+
+fn assert_sorted(tuples: &[Tuple]) {
+    let packed: Vec<Vec<u8>> = tuples.iter().map(Tuple::pack).collect();
+    for (pair, bytes) in tuples.windows(2).zip(packed.windows(2)) {
+        assert!(
+            bytes[0] < bytes[1],
+            "{} should sort before {}",
+            pair[0],
+            pair[1]
+        );
+    }
+    for (tuple, bytes) in tuples.iter().zip(&packed) {
+        assert_eq!(&Tuple::unpack(bytes).unwrap(), tuple);
+    }
+}
+
+#[test]
+fn test_tuple_order_and_round_trip() {
+    let integers: Vec<Tuple> = [
+        i128::MIN,
+        i64::MIN as i128 - 1,
+        i64::MIN as i128,
+        -65536,
+        -256,
+        -255,
+        -1,
+        0,
+        1,
+        255,
+        256,
+        u64::MAX as i128,
+        u64::MAX as i128 + 1,
+        i128::MAX,
+    ]
+    .into_iter()
+    .map(|int| Tuple::new().push(int))
+    .collect();
+    assert_sorted(&integers);
+
+    let doubles: Vec<Tuple> = [
+        f64::NEG_INFINITY,
+        -1.5,
+        -0.0,
+        0.0,
+        1e-300,
+        2.5,
+        f64::INFINITY,
+    ]
+    .into_iter()
+    .map(|double| Tuple::new().push(double))
+    .collect();
+    assert_sorted(&doubles);
+    assert_sorted(&[Tuple::new().push(-2.0f32), Tuple::new().push(3.0f32)]);
+
+    let strings: Vec<Tuple> = ["", "a", "a\0", "a\0b", "a:b", "ab", "b"]
+        .into_iter()
+        .map(|string| Tuple::new().push(string))
+        .collect();
+    assert_sorted(&strings);
+
+    assert_sorted(&[
+        Tuple::new().push(timestamp(-10, 0)),
+        Tuple::new().push(timestamp(0, 0)),
+        Tuple::new().push(timestamp(0, 1)),
+        Tuple::new().push(timestamp(1_700_000_000, 0)),
+    ]);
+
+    // Shorter tuples sort first, and a nested tuple sorts like its elements
+    assert_sorted(&[
+        Tuple::new().push("users"),
+        Tuple::new().push("users").push(Element::Null),
+        Tuple::new().push("users").push(-5),
+        Tuple::new().push("users").push(7),
+        Tuple::new().push("users").push(7).push(Tuple::new()),
+        Tuple::new()
+            .push("users")
+            .push(7)
+            .push(Tuple::new().push(Element::Null).push(b"\0".as_slice())),
+        Tuple::new()
+            .push("users")
+            .push(7)
+            .push(Tuple::new().push(1)),
+        Tuple::new().push("users").push(7).push(false),
+        Tuple::new().push("users").push(7).push(true),
+    ]);
+
+    assert_eq!(
+        Tuple::new().push("users").push(42).push(true).to_string(),
+        "(\"users\", 42, true)"
+    );
+    assert!(Tuple::unpack(&[0x02, b'a']).is_err());
+    assert!(Tuple::unpack(&[0xfe]).is_err());
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[tokio::test]
+async fn test_tuple_prefix_range() {
+    let mut db = MemoryDatabase::open("").await.unwrap();
+    for (category, number) in [("books", -20), ("books", 3), ("books", 12), ("booksx", 1)] {
+        let key = Tuple::new().push(category).push(number);
+        db.add(&key.pack(), key.to_string().as_bytes())
+            .await
+            .unwrap();
+    }
+
+    let (start, end) = Tuple::new().push("books").range();
+    let keys: Vec<Tuple> = db
+        .select_range(&start, &end)
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|(key, _)| Tuple::unpack(&key).unwrap())
+        .collect();
+    assert_eq!(
+        keys,
+        vec![
+            Tuple::new().push("books").push(-20),
+            Tuple::new().push("books").push(3),
+            Tuple::new().push("books").push(12),
+        ]
+    );
+
+    let mut collection: Collection<_, Tuple, String> = Collection::new(db, "items").unwrap();
+    collection
+        .insert(&Tuple::new().push("books").push(2), &"two".to_string())
+        .await
+        .unwrap();
+    collection
+        .insert(
+            &Tuple::new().push("books").push(-1),
+            &"minus one".to_string(),
+        )
+        .await
+        .unwrap();
+    collection
+        .insert(&Tuple::new().push("films").push(1), &"one".to_string())
+        .await
+        .unwrap();
+
+    let books = collection
+        .scan_prefix(&Tuple::new().push("books"))
+        .await
+        .unwrap();
+    assert_eq!(
+        books,
+        vec![
+            (Tuple::new().push("books").push(-1), "minus one".to_string()),
+            (Tuple::new().push("books").push(2), "two".to_string()),
+        ]
+    );
+}
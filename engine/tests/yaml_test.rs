@@ -1,9 +1,14 @@
 #[cfg(not(target_arch = "wasm32"))]
 use std::fs;
 #[cfg(not(target_arch = "wasm32"))]
+use std::io::Write;
+#[cfg(not(target_arch = "wasm32"))]
+use std::path::Path;
+#[cfg(not(target_arch = "wasm32"))]
 use tempfile::NamedTempFile;
-use versedb::database::Database;
-use versedb::yaml::YamlDatabase;
+use versedb::database::{Database, DatabaseError, WriteBatch};
+use versedb::wal::WalOptions;
+use versedb::yaml::{YamlDatabase, YamlOptions};
 
 #[cfg(not(target_arch = "wasm32"))]
 #[tokio::test]
@@ -141,6 +146,113 @@ async fn test_yaml_database_flush() {
 }
 
 // Additional test for YAML-specific functionality
+#[cfg(not(target_arch = "wasm32"))]
+#[tokio::test]
+async fn test_yaml_database_transaction() {
+    let temp_file = NamedTempFile::new().unwrap();
+    let path = temp_file.path().to_str().unwrap();
+
+    let mut db = YamlDatabase::open(path).await.unwrap();
+    db.add(b"old", b"record").await.unwrap();
+
+    db.transaction(|tx| {
+        let value = tx.get(b"old")?.unwrap();
+        tx.delete(b"old")?;
+        tx.put(b"new", &value)?;
+        Ok(())
+    })
+    .await
+    .unwrap();
+    assert_eq!(db.select(b"old").await.unwrap(), None);
+    assert_eq!(db.select(b"new").await.unwrap(), Some(b"record".to_vec()));
+
+    // A failing closure leaves the database untouched
+    let result: versedb::database::Result<()> = db
+        .transaction(|tx| {
+            tx.delete(b"new")?;
+            tx.put(b"other", b"value")?;
+            Err(anyhow::anyhow!("abort"))
+        })
+        .await;
+    assert!(result.is_err());
+    assert_eq!(db.select(b"new").await.unwrap(), Some(b"record".to_vec()));
+    assert_eq!(db.select(b"other").await.unwrap(), None);
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[tokio::test]
+async fn test_yaml_database_write_batch() {
+    let temp_file = NamedTempFile::new().unwrap();
+    let path = temp_file.path().to_str().unwrap();
+
+    let mut db = YamlDatabase::open(path).await.unwrap();
+    db.add(b"key0", b"value0").await.unwrap();
+
+    // Operations apply in order: the range delete also drops earlier puts
+    let mut batch = WriteBatch::new();
+    batch
+        .put(b"key1", b"value1")
+        .put(b"key2", b"value2")
+        .delete(b"key0")
+        .delete_range(b"key2", b"key3");
+    db.write_batch(batch).await.unwrap();
+    db.flush().await.unwrap();
+    drop(db);
+
+    let db = YamlDatabase::open(path).await.unwrap();
+    assert_eq!(
+        db.select_range(b"key0", b"key9").await.unwrap(),
+        vec![(b"key1".to_vec(), b"value1".to_vec())]
+    );
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[tokio::test]
+async fn test_yaml_database_increment() {
+    let temp_file = NamedTempFile::new().unwrap();
+    let path = temp_file.path().to_str().unwrap();
+
+    let mut db = YamlDatabase::open(path).await.unwrap();
+    assert_eq!(db.increment(b"hits", 2).await.unwrap(), 2);
+    assert_eq!(db.increment(b"hits", 40).await.unwrap(), 42);
+
+    // Counters are stored as text, so they survive a round trip through the file
+    db.flush().await.unwrap();
+    drop(db);
+    let mut reopened = YamlDatabase::open(path).await.unwrap();
+    assert_eq!(reopened.increment(b"hits", 1).await.unwrap(), 43);
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[tokio::test]
+async fn test_yaml_database_trees() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let path = temp_dir.path().join("data.yaml");
+    let path = path.to_str().unwrap();
+
+    let mut db = YamlDatabase::open(path).await.unwrap();
+    db.add(b"key", b"root").await.unwrap();
+    let mut users = db.open_tree("users").await.unwrap();
+    users.add(b"key", b"alice").await.unwrap();
+    assert_eq!(db.select(b"key").await.unwrap(), Some(b"root".to_vec()));
+    assert_eq!(db.list_trees().await.unwrap(), vec!["users"]);
+
+    // Each tree is written to its own file next to the database
+    users.flush().await.unwrap();
+    db.flush().await.unwrap();
+    assert!(temp_dir.path().join("data.users.yaml").exists());
+    drop((db, users));
+
+    let db = YamlDatabase::open(path).await.unwrap();
+    assert_eq!(db.list_trees().await.unwrap(), vec!["users"]);
+    let users = db.open_tree("users").await.unwrap();
+    assert_eq!(users.select(b"key").await.unwrap(), Some(b"alice".to_vec()));
+
+    assert!(db.drop_tree("users").await.unwrap());
+    assert!(!temp_dir.path().join("data.users.yaml").exists());
+    assert!(db.list_trees().await.unwrap().is_empty());
+}
+
 #[cfg(not(target_arch = "wasm32"))]
 #[tokio::test]
 async fn test_yaml_database_persistence_format() {
@@ -179,3 +291,156 @@ async fn test_yaml_database_persistence_format() {
     // Clean up
     fs::remove_file(path).unwrap();
 }
+
+#[cfg(not(target_arch = "wasm32"))]
+#[tokio::test]
+async fn test_yaml_database_crash_recovery() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let path = temp_dir.path().join("data.yaml");
+    let backup = temp_dir.path().join("data.yaml.bak");
+    let temp = temp_dir.path().join("data.yaml.tmp");
+    let path = path.to_str().unwrap();
+
+    let mut db = YamlDatabase::open(path).await.unwrap();
+    db.add(b"key", b"first").await.unwrap();
+    db.flush().await.unwrap();
+    let first = fs::read(path).unwrap();
+    db.add(b"key", b"second").await.unwrap();
+    db.flush().await.unwrap();
+    let second = fs::read(path).unwrap();
+
+    // The previous generation is kept as a backup, and no temp file is left
+    assert_eq!(fs::read(&backup).unwrap(), first);
+    assert!(!temp.exists());
+
+    // A temp file next to the database may be partial, so it is dropped
+    fs::write(&temp, &second[..second.len() / 2]).unwrap();
+    let db = YamlDatabase::open(path).await.unwrap();
+    assert_eq!(db.select(b"key").await.unwrap(), Some(b"second".to_vec()));
+    assert!(!temp.exists());
+
+    // Without the database, a crash came after the temp file was complete
+    fs::rename(path, &backup).unwrap();
+    fs::write(&temp, &second).unwrap();
+    let db = YamlDatabase::open(path).await.unwrap();
+    assert_eq!(db.select(b"key").await.unwrap(), Some(b"second".to_vec()));
+    assert_eq!(fs::read(path).unwrap(), second);
+    assert!(!temp.exists());
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[tokio::test]
+async fn test_yaml_database_wal() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let path = temp_dir.path().join("data.yaml");
+    let log = temp_dir.path().join("data.yaml.wal");
+    let path = path.to_str().unwrap();
+    let options = YamlOptions {
+        wal: Some(WalOptions { compact_after: 3 }),
+        ..YamlOptions::default()
+    };
+
+    // Writes reach the log right away and are replayed without a flush
+    let mut db = YamlDatabase::open_with(path, options).await.unwrap();
+    db.add(b"a", b"1").await.unwrap();
+    db.add(b"b", b"2").await.unwrap();
+    let mut tree = db.open_tree("orders").await.unwrap();
+    let mut batch = WriteBatch::new();
+    batch.put(b"order:1", b"pending").put(b"order:2", b"paid");
+    tree.write_batch(batch).await.unwrap();
+    tree.increment(b"count", 2).await.unwrap();
+    drop((db, tree));
+    assert!(!Path::new(path).exists());
+    let db = YamlDatabase::open_with(path, options).await.unwrap();
+    assert_eq!(db.select(b"b").await.unwrap(), Some(b"2".to_vec()));
+    // Trees keep logs of their own, replayed when they are opened
+    let tree = db.open_tree("orders").await.unwrap();
+    assert_eq!(
+        tree.select_range(b"a", b"z").await.unwrap(),
+        vec![
+            (b"count".to_vec(), b"2".to_vec()),
+            (b"order:1".to_vec(), b"pending".to_vec()),
+            (b"order:2".to_vec(), b"paid".to_vec())
+        ]
+    );
+    drop(tree);
+
+    // The third write compacts the log into the file
+    db.remove_range(b"b", b"c").await.unwrap();
+    assert_eq!(fs::metadata(&log).unwrap().len(), 0);
+    assert!(fs::read_to_string(path).unwrap().contains("a: '1'"));
+
+    // A frame cut short by a crash is dropped along with its write
+    let mut db = YamlDatabase::open_with(path, options).await.unwrap();
+    db.add(b"c", b"3").await.unwrap();
+    let logged = fs::metadata(&log).unwrap().len();
+    let mut file = fs::OpenOptions::new().append(true).open(&log).unwrap();
+    file.write_all(&[9, 0, 0, 0, 1, 2]).unwrap();
+    drop(db);
+    let db = YamlDatabase::open_with(path, options).await.unwrap();
+    assert_eq!(fs::metadata(&log).unwrap().len(), logged);
+    assert_eq!(
+        db.select_range(b"a", b"z").await.unwrap(),
+        vec![
+            (b"a".to_vec(), b"1".to_vec()),
+            (b"c".to_vec(), b"3".to_vec())
+        ]
+    );
+
+    // A clone writes through the same data and log, so flushing it keeps
+    // what the original logs afterwards
+    let mut db = db;
+    let mut clone = db.clone();
+    clone.add(b"d", b"4").await.unwrap();
+    assert_eq!(db.select(b"d").await.unwrap(), Some(b"4".to_vec()));
+    clone.flush().await.unwrap();
+    db.add(b"e", b"5").await.unwrap();
+    drop((db, clone));
+    let db = YamlDatabase::open_with(path, options).await.unwrap();
+    assert_eq!(db.select(b"d").await.unwrap(), Some(b"4".to_vec()));
+    assert_eq!(db.select(b"e").await.unwrap(), Some(b"5".to_vec()));
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[tokio::test]
+async fn test_yaml_database_locking() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let path = temp_dir.path().join("data.yaml");
+    let path = path.to_str().unwrap();
+    let read_only = YamlOptions {
+        read_only: true,
+        ..YamlOptions::default()
+    };
+
+    let mut db = YamlDatabase::open(path).await.unwrap();
+    db.add(b"key", b"value").await.unwrap();
+    db.flush().await.unwrap();
+    // Another process sees the lock held by this one,
+    let lock = fs::File::open(temp_dir.path().join("data.yaml.lock")).unwrap();
+    assert!(lock.try_lock_shared().is_err());
+    // while a read-only handle from this one shares it. Opening it for
+    // writing again takes the lock over, so the earlier handle can't write.
+    let reader = YamlDatabase::open_with(path, read_only).await.unwrap();
+    let mut again = YamlDatabase::open(path).await.unwrap();
+    let error = db.add(b"key", b"stale").await.unwrap_err();
+    assert!(matches!(
+        DatabaseError::of(&error),
+        DatabaseError::Conflict(_)
+    ));
+    assert_eq!(again.select(b"key").await.unwrap(), Some(b"value".to_vec()));
+    // Closing the writer gives the lock up
+    again.close().await.unwrap();
+    assert!(lock.try_lock_shared().is_ok());
+    drop((db, reader, again));
+
+    let mut reader = YamlDatabase::open_with(path, read_only).await.unwrap();
+    assert_eq!(
+        reader.select(b"key").await.unwrap(),
+        Some(b"value".to_vec())
+    );
+    assert!(reader.add(b"key", b"changed").await.is_err());
+    reader.close().await.unwrap();
+    drop(reader);
+    let error = YamlDatabase::open(path).await.err().unwrap();
+    assert!(error.to_string().contains("read-only in another process"));
+}